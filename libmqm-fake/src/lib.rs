@@ -0,0 +1,89 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::missing_safety_doc)]
+
+/*!
+ * Stand-in for the IBM® MQ library that does not require IBM MQ to be installed
+ *
+ * The crate builds a `libmqm_fake` dynamic library exporting the same MQI and MQAI C functions as the IBM MQ
+ * library. It is given its own name so it never replaces IBM MQ by accident, and is copied or linked as `libmqm_r`
+ * into an installation folder where it should stand in. The MQI and exit functions are implemented by a process wide in-memory [`MockMq`] queue manager. The MQAI
+ * functions complete with `MQRC_FUNCTION_NOT_SUPPORTED`. A panic is caught at the C boundary and completes the
+ * function call with `MQRC_UNEXPECTED_ERROR`.
+ *
+ * The queue manager is configured from environment variables when the first function is called:
+ *
+ * | Variable            | Description |
+ * |---------------------|-------------|
+ * | `LIBMQM_FAKE_QMGR`  | Name of the queue manager, defaults to `QM1` |
+ * | `LIBMQM_FAKE_QUEUES`| Comma separated list of the local queues to define |
+ *
+ * Example
+ * -------
+ *
+ *  Load the library with `dlopen2` in place of the IBM MQ library, from the search path or an `MQ_HOME` installation
+ *
+ * ```sh
+ * cargo build -p libmqm-fake
+ * mkdir -p fake-mq/lib64 && cp target/debug/libmqm_fake.so fake-mq/lib64/libmqm_r.so
+ * LD_LIBRARY_PATH=fake-mq/lib64 LIBMQM_FAKE_QUEUES=DEV.QUEUE.1 ./my-mq-application
+ * MQ_HOME=fake-mq LIBMQM_FAKE_QUEUES=DEV.QUEUE.1 ./my-mq-application
+ * ```
+ */
+
+use std::{
+    env,
+    panic::{self, AssertUnwindSafe},
+    sync::OnceLock,
+};
+
+use libmqm_sys::{fail_call, lib as mqsys, mock::MockMq, Mqi as _, MqiExit as _};
+
+/// Environment variable holding the name of the queue manager
+pub const QMGR_ENV: &str = "LIBMQM_FAKE_QMGR";
+
+/// Environment variable holding the comma separated local queues
+pub const QUEUES_ENV: &str = "LIBMQM_FAKE_QUEUES";
+
+/// The process wide queue manager
+fn qmgr() -> &'static MockMq {
+    static QMGR: OnceLock<MockMq> = OnceLock::new();
+    QMGR.get_or_init(|| {
+        let name = env::var(QMGR_ENV).unwrap_or_else(|_| "QM1".to_string());
+        env::var(QUEUES_ENV)
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|queue| !queue.is_empty())
+            .fold(MockMq::new(&name), MockMq::with_queue)
+    })
+}
+
+macro_rules! export_mqi {
+    ({ $($(#[$verb_attr:meta])* fn $verb:ident($($arg:ident: $type:ty),* $(,)?) $(= $field:ident)?;)* }) => {
+        $(
+            #[no_mangle]
+            pub unsafe extern "C" fn $verb($($arg: $type),*) {
+                if panic::catch_unwind(AssertUnwindSafe(|| unsafe { qmgr().$verb($($arg),*) })).is_err() {
+                    fail_call!(mqsys::MQRC_UNEXPECTED_ERROR; $($arg),*);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! export_not_supported {
+    ({ $($(#[$verb_attr:meta])* fn $verb:ident($($arg:ident: $type:ty),* $(,)?);)* }) => {
+        $(
+            #[no_mangle]
+            #[allow(unused_variables)]
+            pub unsafe extern "C" fn $verb($($arg: $type),*) {
+                fail_call!(mqsys::MQRC_FUNCTION_NOT_SUPPORTED; $($arg),*);
+            }
+        )*
+    };
+}
+
+libmqm_sys::mqi_verbs!(export_mqi!());
+libmqm_sys::mqi_exit_verbs!(export_mqi!());
+libmqm_sys::mqai_verbs!(export_not_supported!());
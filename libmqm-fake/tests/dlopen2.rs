@@ -0,0 +1,175 @@
+use std::{
+    env, fs, mem,
+    path::{Path, PathBuf},
+    process::{self, Command},
+    ptr::{addr_of_mut, null_mut},
+    sync::OnceLock,
+};
+
+use libmqm_sys::{
+    dlopen2::{LoadMqm as _, MqmContainer, MqmLibrary, TolerantMqmContainer},
+    lib,
+    libloading::LibloadingMq,
+    Mqi as _,
+};
+
+/// Environment variable holding the local queues of the fake queue manager
+const QUEUES_ENV: &str = "LIBMQM_FAKE_QUEUES";
+
+/// The `libmqm_fake` library of this crate, which `cargo test` builds into the folder of the test executable
+fn built_library() -> PathBuf {
+    let exe = env::current_exe().expect("test executable path");
+    exe.with_file_name(format!("{}mqm_fake{}", env::consts::DLL_PREFIX, env::consts::DLL_SUFFIX))
+}
+
+/// A temporary MQ installation holding the fake as the server library
+fn installation() -> &'static Path {
+    static INSTALLATION: OnceLock<PathBuf> = OnceLock::new();
+    INSTALLATION.get_or_init(|| {
+        let installation = Path::new(env!("CARGO_TARGET_TMPDIR")).join("fake-mq-installation");
+        let path = MqmLibrary::Server.path(&installation);
+        fs::create_dir_all(path.parent().expect("library folder")).expect("installation created");
+        // Copy then rename, so a library already loaded by another test process is replaced rather than overwritten
+        let copy = path.with_extension(format!("{}", process::id()));
+        fs::copy(built_library(), &copy).expect("library copied");
+        fs::rename(&copy, &path).expect("library renamed");
+        installation
+    })
+}
+
+/// The fake as the `libmqm_r` library of the [`installation`]
+fn library() -> PathBuf {
+    MqmLibrary::Server.path(installation())
+}
+
+fn fill(field: &mut [lib::MQCHAR], value: &str) {
+    for (c, b) in field.iter_mut().zip(value.bytes().chain(std::iter::repeat(b' '))) {
+        *c = lib::MQCHAR::from_ne_bytes([b]);
+    }
+}
+
+#[test]
+fn load_and_call() {
+    // The fake reads its queues from the environment when the first function is called in the process, so the
+    // calls run in a child test process with the queues set
+    let status = Command::new(env::current_exe().expect("test executable path"))
+        .env(QUEUES_ENV, "DEV.QUEUE.1")
+        .args(["--exact", "call_with_queues", "--include-ignored", "--test-threads=1"])
+        .status()
+        .expect("child test to run");
+    assert!(status.success(), "call_with_queues to pass");
+}
+
+#[test]
+#[ignore = "run by load_and_call in a child process with the queues set"]
+fn call_with_queues() {
+    assert!(env::var_os(QUEUES_ENV).is_some(), "{QUEUES_ENV} to be set by load_and_call");
+    let mq = unsafe { MqmContainer::load(library()) }.expect("fake MQ library to be loaded");
+    assert_eq!(
+        mq.library_path().ok().and_then(|path| path.canonicalize().ok()),
+        library().canonicalize().ok()
+    );
+
+    let mut hconn = lib::MQHC_DEF_HCONN;
+    let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+    unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+    assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+
+    let mut od: lib::MQOD = unsafe { mem::zeroed() };
+    od.Version = lib::MQOD_VERSION_1;
+    od.ObjectType = lib::MQOT_Q;
+    fill(&mut od.ObjectName, "DEV.QUEUE.1");
+    fill(&mut od.ObjectQMgrName, "");
+    let mut md: lib::MQMD = unsafe { mem::zeroed() };
+    md.Version = lib::MQMD_VERSION_1;
+    md.Priority = lib::MQPRI_PRIORITY_AS_Q_DEF;
+    let mut pmo: lib::MQPMO = unsafe { mem::zeroed() };
+    pmo.Version = lib::MQPMO_VERSION_1;
+    pmo.Options = lib::MQPMO_NO_SYNCPOINT;
+    let mut message = *b"Hello";
+    unsafe {
+        mq.MQPUT1(
+            hconn,
+            addr_of_mut!(od).cast(),
+            addr_of_mut!(md).cast(),
+            addr_of_mut!(pmo).cast(),
+            5,
+            addr_of_mut!(message).cast(),
+            addr_of_mut!(cc),
+            addr_of_mut!(rc),
+        );
+    }
+    assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+
+    let mut hobj = lib::MQHO_NONE;
+    unsafe {
+        mq.MQOPEN(
+            hconn,
+            addr_of_mut!(od).cast(),
+            lib::MQOO_INPUT_AS_Q_DEF,
+            addr_of_mut!(hobj),
+            addr_of_mut!(cc),
+            addr_of_mut!(rc),
+        );
+    }
+    assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+
+    let mut md: lib::MQMD = unsafe { mem::zeroed() };
+    md.Version = lib::MQMD_VERSION_1;
+    let mut gmo: lib::MQGMO = unsafe { mem::zeroed() };
+    gmo.Version = lib::MQGMO_VERSION_1;
+    gmo.Options = lib::MQGMO_NO_SYNCPOINT;
+    let mut buffer = [0u8; 16];
+    let mut length = 0;
+    unsafe {
+        mq.MQGET(
+            hconn,
+            hobj,
+            addr_of_mut!(md).cast(),
+            addr_of_mut!(gmo).cast(),
+            16,
+            addr_of_mut!(buffer).cast(),
+            addr_of_mut!(length),
+            addr_of_mut!(cc),
+            addr_of_mut!(rc),
+        );
+    }
+    assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+    assert_eq!(&buffer[..5], b"Hello");
+    assert_eq!(length, 5);
+
+    let mut bag = lib::MQHB_UNUSABLE_HBAG;
+    unsafe { mq.mqCreateBag(lib::MQCBO_ADMIN_BAG, addr_of_mut!(bag), addr_of_mut!(cc), addr_of_mut!(rc)) };
+    assert_eq!((cc, rc), (lib::MQCC_FAILED, lib::MQRC_FUNCTION_NOT_SUPPORTED));
+
+    unsafe { mq.MQDISC(addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+    assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+}
+
+#[test]
+fn load_installation() {
+    let mq = unsafe {
+        MqmContainer::load_mqm_installation(
+            &[Path::new("no_such_mq"), installation()],
+            &[MqmLibrary::Client, MqmLibrary::Server],
+        )
+    };
+    assert!(mq.is_ok(), "fake MQ library to be loaded from the installation");
+}
+
+#[test]
+fn load_tolerant() {
+    let mq = unsafe { TolerantMqmContainer::load(library()) }.expect("fake MQ library to be loaded");
+    let capabilities = mq.capabilities();
+    assert!(capabilities.is_complete(), "missing {:?}", capabilities.missing);
+    assert!(capabilities.supports("MQCONNX") && capabilities.supports("mqCreateBag"));
+}
+
+#[test]
+fn load_libloading() {
+    let mq = unsafe { LibloadingMq::load(library()) }.expect("fake MQ library to be loaded");
+    let mut hconn = lib::MQHC_DEF_HCONN;
+    let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+    unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+    assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+}
@@ -3,6 +3,8 @@ use std::io;
 #[cfg(feature = "bindgen")]
 mod mqi_bindgen;
 
+mod names;
+
 #[cfg(any(feature = "mqi_helpers", feature = "bindgen"))]
 mod features {
     use std::env;
@@ -60,12 +62,6 @@ mod versions {
         Ok((a.parse::<u32>()? << 24) | (b.parse::<u32>()? << 16) | (c.parse::<u32>()? << 8) | (d.parse::<u32>()?))
     }
 
-    pub fn parse_mqc_feature(feature: &str) -> Option<u32> {
-        let mqc_regex = Regex::new(r"(?i)mqc_(\d+)_(\d+)_(\d+)_(\d+)").ok()?;
-        let (_, version) = mqc_regex.captures(feature).map(|captures| captures.extract())?;
-        ver_into_u32(version.into()).ok()
-    }
-
     pub fn parse_version(version: &str) -> Option<u32> {
         const VERSION_PATTERN: &str = r"(\d+)\.(\d+)\.(\d+)\.(\d+)";
         let version_check = Regex::new(VERSION_PATTERN).expect("valid regex");
@@ -85,12 +81,62 @@ mod versions {
     }
 }
 
-#[cfg(feature = "link_mqm")]
+mod mqc_features {
+    use std::{
+        env, fs,
+        io::{self, Write},
+        path::Path,
+    };
+
+    use regex_lite::Regex;
+
+    /// The highest enabled `mqc_*` feature and its version, release, modification and fix pack levels
+    pub fn minimum() -> Option<([u8; 4], String)> {
+        let mqc_env = Regex::new(r"^CARGO_FEATURE_(MQC_(\d+)_(\d+)_(\d+)_(\d+))$").expect("valid regex");
+        env::vars()
+            .filter_map(|(name, _)| {
+                let (_, [feature, version, release, modification, fix]) = mqc_env.captures(&name)?.extract();
+                let levels = [
+                    version.parse().ok()?,
+                    release.parse().ok()?,
+                    modification.parse().ok()?,
+                    fix.parse().ok()?,
+                ];
+                Some((levels, feature.to_lowercase()))
+            })
+            .max_by_key(|&(levels, _)| levels)
+    }
+
+    /// Write the `FEATURE_MINIMUM` constant of the `runtime_version` module
+    pub fn write_minimum(out_path: &Path) -> Result<(), io::Error> {
+        let mut writer = io::BufWriter::new(fs::File::create(out_path.join("feature_minimum.rs"))?);
+        writeln!(writer, "// Generated from the enabled mqc features. Do not edit.")?;
+        writeln!(
+            writer,
+            "/// The highest enabled `mqc_*` feature and its minimum client version"
+        )?;
+        match minimum() {
+            Some(([version, release, modification, fix], feature)) => writeln!(
+                writer,
+                "pub const FEATURE_MINIMUM: Option<(&str, MqVersion)> = Some((\"{feature}\", MqVersion::new({version}, {release}, {modification}, {fix})));"
+            ),
+            None => writeln!(writer, "pub const FEATURE_MINIMUM: Option<(&str, MqVersion)> = None;"),
+        }
+    }
+}
+
+#[cfg(any(feature = "link_mqm", feature = "link_mqic"))]
 mod link_mqm {
     use std::env;
 
+    /// The client only library when the `link_mqic` feature is enabled, otherwise the server bindings library
     pub fn link_lib() -> &'static str {
-        env::var("CARGO_CFG_WINDOWS").map(|_| "mqm").unwrap_or("mqm_r")
+        match (cfg!(feature = "link_mqic"), env::var("CARGO_CFG_WINDOWS").is_ok()) {
+            (true, true) => "mqic",
+            (true, false) => "mqic_r",
+            (false, true) => "mqm",
+            (false, false) => "mqm_r",
+        }
     }
 
     pub fn lib_path() -> &'static str {
@@ -98,7 +144,7 @@ mod link_mqm {
     }
 }
 
-#[cfg(any(feature = "link_mqm", feature = "mqi_helpers", feature = "bindgen"))]
+#[cfg(any(feature = "link_mqm", feature = "link_mqic", feature = "mqi_helpers", feature = "bindgen"))]
 mod mq_path {
     use std::{env, path::PathBuf};
 
@@ -135,11 +181,12 @@ fn dspmqver() -> std::path::PathBuf {
 fn main() -> Result<(), io::Error> {
     println!("cargo:rerun-if-env-changed=MQ_HOME");
 
-    #[cfg(feature = "link_mqm")]
+    #[cfg(any(feature = "link_mqm", feature = "link_mqic"))]
     {
         let mq_lib_path = mq_path::home_path().join(link_mqm::lib_path());
         println!("cargo:rustc-link-search={}", mq_lib_path.display());
         println!("cargo:rustc-link-lib=dylib={}", link_mqm::link_lib());
+        println!("cargo:lib_path={}", mq_lib_path.display()); // DEP_MQM_LIB_PATH for dependent build scripts
     }
 
     #[cfg(feature = "mqi_helpers")]
@@ -165,21 +212,9 @@ fn main() -> Result<(), io::Error> {
             .map(|m| m["version"].to_owned())
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "could not extract version from dspmqver"))?;
 
-        let mqc_env = regex_lite::Regex::new(r"CARGO_FEATURE_(MQC_\d+_\d+_\d+_\d+)").expect("valid regex");
-        let min_mqc_version = std::env::vars()
-            .filter_map(|(name, _)| {
-                mqc_env.captures(&name).and_then(|captures| {
-                    captures.get(1).and_then(|matched| {
-                        let feature = matched.as_str().to_lowercase();
-                        versions::parse_mqc_feature(&feature).map(|ver| (ver, feature))
-                    })
-                })
-            })
-            .max_by_key(|(ver, _)| *ver);
-
-        if let Some(((min_mqc, feature), current_mqc)) = min_mqc_version.zip(versions::parse_version(&mqc_version)) {
+        if let Some(((min_mqc, feature), current_mqc)) = mqc_features::minimum().zip(versions::parse_version(&mqc_version)) {
             assert!(
-                min_mqc <= current_mqc,
+                u32::from_be_bytes(min_mqc) <= current_mqc,
                 "MQC version {} does not meet the minimum requirement for feature {}",
                 &mqc_version,
                 &feature
@@ -229,5 +264,13 @@ fn main() -> Result<(), io::Error> {
         }
     }
 
+    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?);
+
+    // Minimum client version of the enabled mqc features
+    mqc_features::write_minimum(&out_dir)?;
+
+    // Constant name tables from the bindings the crate is compiled with
+    names::write_names(&out_dir)?;
+
     Ok(())
 }
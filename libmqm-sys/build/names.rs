@@ -0,0 +1,582 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use regex_lite::Regex;
+
+/// Bindings file that the crate is compiled with, either generated by bindgen or pregenerated for the target
+pub fn bindings_path(out_path: &Path) -> Result<PathBuf, io::Error> {
+    if cfg!(feature = "bindgen") {
+        return Ok(out_path.join("bindings.rs"));
+    }
+
+    let os = env::var("CARGO_CFG_TARGET_OS").map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let pregen = PathBuf::from("src/lib/pregen").join(format!("{}-{os}-bindings.rs", if os == "macos" { "any" } else { &arch }));
+    println!("cargo:rerun-if-changed={}", pregen.display());
+    Ok(pregen)
+}
+
+/// Constants such as `MQCACF_FIRST` that mark the range of a group rather than name a value
+fn is_range_marker(name: &str) -> bool {
+    name.ends_with("_FIRST") || name.ends_with("_LAST")
+}
+
+/// Preference of a constant as the name of its value. `*_NONE` constants are preferred over any other, such as
+/// `MQGMO_NONE` over `MQGMO_NO_WAIT`, and `*_FIRST` or `*_LAST` range markers are only used when no other constant
+/// has the value.
+fn name_priority(name: &str) -> u8 {
+    if is_range_marker(name) {
+        0
+    } else if name.ends_with("_NONE") {
+        2
+    } else {
+        1
+    }
+}
+
+/// Groups whose constants do not start with the name of the group. These constants are also in the group of the
+/// longest prefix they start with.
+const GROUP_MEMBERS: &[(&str, &[&str])] = &[
+    (
+        "MQIAMO_MONITOR_DATATYPE",
+        &[
+            "MQIAMO_MONITOR_UNIT",
+            "MQIAMO_MONITOR_DELTA",
+            "MQIAMO_MONITOR_HUNDREDTHS",
+            "MQIAMO_MONITOR_KB",
+            "MQIAMO_MONITOR_PERCENT",
+            "MQIAMO_MONITOR_MICROSEC",
+            "MQIAMO_MONITOR_MB",
+            "MQIAMO_MONITOR_GB",
+        ],
+    ),
+    ("MQMON_AVAILABILITY", &["MQMON_NOT_AVAILABLE"]),
+    ("MQMON_OVERRIDE", &["MQMON_DISABLED", "MQMON_ENABLED"]),
+    ("MQQA_SHAREABLE", &["MQQA_SHAREABLE", "MQQA_NOT_SHAREABLE"]),
+    (
+        "MQSUB_DURABILITY",
+        &[
+            "MQSUB_DURABLE_AS_PARENT",
+            "MQSUB_DURABLE_ALL",
+            "MQSUB_DURABLE_YES",
+            "MQSUB_DURABLE_NO",
+        ],
+    ),
+    (
+        "MQZID_AUTHORITY",
+        &[
+            "MQZID_INIT_AUTHORITY",
+            "MQZID_TERM_AUTHORITY",
+            "MQZID_CHECK_AUTHORITY",
+            "MQZID_COPY_ALL_AUTHORITY",
+            "MQZID_DELETE_AUTHORITY",
+            "MQZID_SET_AUTHORITY",
+            "MQZID_GET_AUTHORITY",
+            "MQZID_GET_EXPLICIT_AUTHORITY",
+            "MQZID_REFRESH_CACHE",
+            "MQZID_ENUMERATE_AUTHORITY_DATA",
+            "MQZID_AUTHENTICATE_USER",
+            "MQZID_FREE_USER",
+            "MQZID_INQUIRE",
+            "MQZID_CHECK_PRIVILEGED",
+        ],
+    ),
+    (
+        "MQZID_NAME",
+        &[
+            "MQZID_INIT_NAME",
+            "MQZID_TERM_NAME",
+            "MQZID_LOOKUP_NAME",
+            "MQZID_INSERT_NAME",
+            "MQZID_DELETE_NAME",
+        ],
+    ),
+    (
+        "MQZID_USERID",
+        &["MQZID_INIT_USERID", "MQZID_TERM_USERID", "MQZID_FIND_USERID"],
+    ),
+];
+
+/// Value of a constant parsed from the bindings. Constants that bindgen types as `u32`, or as `usize` because their
+/// name contains `LENGTH`, keep their bit pattern, so `MQCBD_FULL_MSG_LENGTH` is -1 as in C.
+fn parse_value(value: &str) -> Result<i32, io::Error> {
+    let value = value
+        .parse::<i64>()
+        .or_else(|_| value.parse::<u64>().map(|value| i64::from_ne_bytes(value.to_ne_bytes())))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    i32::try_from(value)
+        .or_else(|_| u32::try_from(value).map(|value| i32::from_ne_bytes(value.to_ne_bytes())))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Constants of a group keyed by value and by name
+type Group<'a> = (BTreeMap<i32, &'a str>, BTreeMap<&'a str, i32>);
+
+/// Parse the constants of every `*_STR` group found in the bindings.
+///
+/// A constant belongs to the group with the longest prefix it starts with, so `MQCACF_*` constants are in the
+/// `MQCACF` group and not the `MQCA` group. When several constants of a group share a value, the first one
+/// defined with the highest [`name_priority`] is used. The version and length constants of structures, such as
+/// `MQGMO_VERSION_1`, and the lengths of fields, such as `MQ_CERT_LABEL_LENGTH`, do not name a value. Every
+/// constant of the group is written to the table of values sorted by name.
+///
+/// Constants are read whether bindgen types them as `MQLONG`, a handle type, `i32`, `u32` or `usize`. The `*_MASK`
+/// constants select bits of a field rather than name a value and are skipped. The groups in [`GROUP_MEMBERS`]
+/// also hold their listed constants.
+fn parse_groups(bindings: &str) -> Result<BTreeMap<&str, Group<'_>>, io::Error> {
+    let str_fn = Regex::new(r"(?m)^\s*pub fn (MQ\w+)_STR\(").expect("valid regex");
+    let constant =
+        Regex::new(r"(?m)^pub const (MQ\w+): (?:MQLONG|MQHCONN|MQHOBJ|MQHMSG|i32|u32|usize) = (-?\d+);").expect("valid regex");
+    let structure =
+        Regex::new(r"(_(VERSION_\d+|LENGTH_\d+|CURRENT_VERSION|CURRENT_LENGTH|STRUC_LENGTH_FIXED(_\d+)?)|^MQ_\w+_LENGTH)$")
+            .expect("valid regex");
+
+    let mut groups: BTreeMap<&str, Group> = str_fn
+        .captures_iter(bindings)
+        .map(|captures| (captures.get(1).expect("group is captured").as_str(), Group::default()))
+        .collect();
+
+    for captures in constant.captures_iter(bindings) {
+        let name = captures.get(1).expect("name is captured").as_str();
+        if name.ends_with("_MASK") {
+            continue;
+        }
+        let value = parse_value(&captures[2])?;
+        let prefixed = groups
+            .keys()
+            .copied()
+            .filter(|prefix| name.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('_')))
+            .max_by_key(|prefix| prefix.len());
+        let listed = GROUP_MEMBERS
+            .iter()
+            .filter(|(_, members)| members.contains(&name))
+            .map(|&(group, _)| group);
+        for group in prefixed.into_iter().chain(listed) {
+            let Some((names, values)) = groups.get_mut(group) else {
+                continue;
+            };
+            match names.get(&value) {
+                _ if structure.is_match(name) => (),
+                Some(existing) if name_priority(existing) >= name_priority(name) => (),
+                _ => {
+                    names.insert(value, name);
+                }
+            }
+            values.insert(name, value);
+        }
+    }
+
+    Ok(groups)
+}
+
+/// Write the constant name tables of the groups
+fn generate_names(groups: &BTreeMap<&str, Group>, writer: &mut impl Write) -> Result<(), io::Error> {
+    writeln!(writer, "// Generated from the constants of the MQ bindings. Do not edit.")?;
+    writeln!(writer, "#[allow(clippy::unreadable_literal)]")?;
+    writeln!(writer, "pub(super) static GROUPS: &[(&str, &[(MQLONG, &str)])] = &[")?;
+    for (group, (names, _)) in groups {
+        write!(writer, "    (\"{group}\", &[")?;
+        for (value, name) in names {
+            write!(writer, "({value}, \"{name}\"), ")?;
+        }
+        writeln!(writer, "]),")?;
+    }
+    writeln!(writer, "];")?;
+
+    writeln!(writer, "#[allow(clippy::unreadable_literal)]")?;
+    writeln!(writer, "pub(super) static VALUES: &[(&str, &[(&str, MQLONG)])] = &[")?;
+    for (group, (_, values)) in groups {
+        write!(writer, "    (\"{group}\", &[")?;
+        for (name, value) in values {
+            write!(writer, "(\"{name}\", {value}), ")?;
+        }
+        writeln!(writer, "]),")?;
+    }
+    writeln!(writer, "];")?;
+
+    Ok(())
+}
+
+// The reason lists below override the category derived from the name of the constant by `derived_category`, for
+// reasons that the name patterns classify incorrectly or not at all.
+
+/// Reasons that are returned with `MQCC_WARNING` when the call completed
+const WARNING_REASONS: &[&str] = &[
+    "MQRC_ALREADY_CONNECTED",
+    "MQRC_PRIORITY_EXCEEDS_MAXIMUM",
+    "MQRC_SIGNAL_REQUEST_ACCEPTED",
+    "MQRC_TRUNCATED_MSG_ACCEPTED",
+    "MQRC_UNKNOWN_REPORT_OPTION",
+    "MQRC_FORMAT_ERROR",
+    "MQRC_NOT_CONVERTED",
+    "MQRC_CONVERTED_MSG_TOO_BIG",
+    "MQRC_PARTIALLY_CONVERTED",
+    "MQRC_NO_EXTERNAL_PARTICIPANTS",
+    "MQRC_PARTICIPANT_NOT_AVAILABLE",
+    "MQRC_OUTCOME_PENDING",
+    "MQRC_MULTIPLE_REASONS",
+    "MQRC_OPTIONS_CHANGED",
+    "MQRC_SSL_ALREADY_INITIALIZED",
+    "MQRC_RECONNECTING",
+    "MQRC_RECONNECTED",
+];
+
+/// Reasons of a temporary condition that clears without changes to the application
+const TRANSIENT_REASONS: &[&str] = &[
+    "MQRC_BACKED_OUT",
+    "MQRC_CLUSTER_RESOLUTION_ERROR",
+    "MQRC_MAX_CONNS_LIMIT_REACHED",
+    "MQRC_OBJECT_IN_USE",
+    "MQRC_PAGESET_FULL",
+    "MQRC_Q_FULL",
+    "MQRC_Q_SPACE_NOT_AVAILABLE",
+    "MQRC_RESOURCE_PROBLEM",
+    "MQRC_STORAGE_MEDIUM_FULL",
+    "MQRC_STORAGE_NOT_AVAILABLE",
+];
+
+/// Reasons of a connection to the queue manager that is lost or is ending
+const CONNECTION_REASONS: &[&str] = &[
+    "MQRC_CALL_INTERRUPTED",
+    "MQRC_CHANNEL_NOT_AVAILABLE",
+    "MQRC_CONNECTION_BROKEN",
+    "MQRC_CONNECTION_QUIESCING",
+    "MQRC_CONNECTION_STOPPING",
+    "MQRC_HOST_NOT_AVAILABLE",
+    "MQRC_Q_MGR_NOT_AVAILABLE",
+    "MQRC_Q_MGR_QUIESCING",
+    "MQRC_Q_MGR_STOPPING",
+    "MQRC_RECONNECT_FAILED",
+    "MQRC_RECONNECT_TIMED_OUT",
+];
+
+/// Reasons of an authority, authentication or TLS failure
+const SECURITY_REASONS: &[&str] = &[
+    "MQRC_CERT_VAL_POLICY_ERROR",
+    "MQRC_CHANNEL_SSL_ERROR",
+    "MQRC_CONNECTION_NOT_AUTHORIZED",
+    "MQRC_CRYPTO_HARDWARE_ERROR",
+    "MQRC_HTTPS_KEYSTORE_ERROR",
+    "MQRC_JSSE_ERROR",
+    "MQRC_KEY_REPOSITORY_ERROR",
+    "MQRC_NOT_AUTHORIZED",
+    "MQRC_OCSP_URL_ERROR",
+    "MQRC_PASSWORD_PROTECTION_ERROR",
+    "MQRC_SECURITY_ERROR",
+    "MQRC_SSL_ALT_PROVIDER_REQUIRED",
+    "MQRC_SSL_CERT_STORE_ERROR",
+    "MQRC_SSL_CERTIFICATE_REVOKED",
+    "MQRC_SSL_CONFIG_ERROR",
+    "MQRC_SSL_INITIALIZATION_ERROR",
+    "MQRC_SSL_KEY_RESET_ERROR",
+    "MQRC_SSL_NOT_ALLOWED",
+    "MQRC_SSL_PEER_NAME_ERROR",
+    "MQRC_SSL_PEER_NAME_MISMATCH",
+    "MQRC_SUITE_B_ERROR",
+];
+
+/// Reasons of a call that is not valid, such as a structure, handle or option error. Errors of the queue manager,
+/// its exits and its environment, such as `MQRC_UNEXPECTED_ERROR`, are not usage errors.
+const USAGE_REASONS: &[&str] = &[
+    "MQRC_ACTION_ERROR",
+    "MQRC_ADAPTER_DEFS_ERROR",
+    "MQRC_ADMIN_TOPIC_STRING_ERROR",
+    "MQRC_AIR_ERROR",
+    "MQRC_ALIAS_BASE_Q_TYPE_ERROR",
+    "MQRC_ALTER_SUB_ERROR",
+    "MQRC_AUTH_INFO_CONN_NAME_ERROR",
+    "MQRC_AUTH_INFO_REC_COUNT_ERROR",
+    "MQRC_AUTH_INFO_REC_ERROR",
+    "MQRC_AUTH_INFO_TYPE_ERROR",
+    "MQRC_BAG_CONVERSION_ERROR",
+    "MQRC_BINARY_DATA_LENGTH_ERROR",
+    "MQRC_BMHO_ERROR",
+    "MQRC_BNO_ERROR",
+    "MQRC_BO_ERROR",
+    "MQRC_BUFFER_ERROR",
+    "MQRC_BUFFER_LENGTH_ERROR",
+    "MQRC_CALLBACK_LINK_ERROR",
+    "MQRC_CALLBACK_ROUTINE_ERROR",
+    "MQRC_CALLBACK_TYPE_ERROR",
+    "MQRC_CBD_ERROR",
+    "MQRC_CBD_OPTIONS_ERROR",
+    "MQRC_CCDT_URL_ERROR",
+    "MQRC_CD_ARRAY_ERROR",
+    "MQRC_CD_ERROR",
+    "MQRC_CFBF_ERROR",
+    "MQRC_CFBS_ERROR",
+    "MQRC_CFGR_ERROR",
+    "MQRC_CFH_ERROR",
+    "MQRC_CFIF_ERROR",
+    "MQRC_CFIL_ERROR",
+    "MQRC_CFIN_ERROR",
+    "MQRC_CFSF_ERROR",
+    "MQRC_CFSL_ERROR",
+    "MQRC_CFST_ERROR",
+    "MQRC_CF_STRUC_ERROR",
+    "MQRC_CHANNEL_CONFIG_ERROR",
+    "MQRC_CHAR_ATTRS_ERROR",
+    "MQRC_CHAR_ATTR_LENGTH_ERROR",
+    "MQRC_CLIENT_CONN_ERROR",
+    "MQRC_CMHO_ERROR",
+    "MQRC_CNO_ERROR",
+    "MQRC_CODED_CHAR_SET_ID_ERROR",
+    "MQRC_COMMAND_TYPE_ERROR",
+    "MQRC_CONTENT_ERROR",
+    "MQRC_CONTEXT_HANDLE_ERROR",
+    "MQRC_CONTEXT_OPEN_ERROR",
+    "MQRC_CORREL_ID_ERROR",
+    "MQRC_CSP_ERROR",
+    "MQRC_CTLO_ERROR",
+    "MQRC_CURRENT_RECORD_ERROR",
+    "MQRC_DATA_LENGTH_ERROR",
+    "MQRC_DEF_XMIT_Q_TYPE_ERROR",
+    "MQRC_DEF_XMIT_Q_USAGE_ERROR",
+    "MQRC_DEST_ENV_ERROR",
+    "MQRC_DEST_NAME_ERROR",
+    "MQRC_DH_ERROR",
+    "MQRC_DLH_ERROR",
+    "MQRC_DMHO_ERROR",
+    "MQRC_DMPO_ERROR",
+    "MQRC_DYNAMIC_Q_NAME_ERROR",
+    "MQRC_ENCODING_ERROR",
+    "MQRC_ENVIRONMENT_ERROR",
+    "MQRC_EPH_ERROR",
+    "MQRC_EXIT_REASON_ERROR",
+    "MQRC_EXPIRY_ERROR",
+    "MQRC_FEEDBACK_ERROR",
+    "MQRC_FILTER_OPERATOR_ERROR",
+    "MQRC_FUNCTION_ERROR",
+    "MQRC_GMO_ERROR",
+    "MQRC_GROUP_ID_ERROR",
+    "MQRC_HBAG_ERROR",
+    "MQRC_HCONFIG_ERROR",
+    "MQRC_HCONN_ERROR",
+    "MQRC_HEADER_ERROR",
+    "MQRC_HMSG_ERROR",
+    "MQRC_HOBJ_ERROR",
+    "MQRC_IIH_ERROR",
+    "MQRC_IMPO_ERROR",
+    "MQRC_INDEX_ERROR",
+    "MQRC_INHIBIT_VALUE_ERROR",
+    "MQRC_INQUIRY_COMMAND_ERROR",
+    "MQRC_INT_ATTRS_ARRAY_ERROR",
+    "MQRC_INT_ATTR_COUNT_ERROR",
+    "MQRC_ITEM_COUNT_ERROR",
+    "MQRC_ITEM_TYPE_ERROR",
+    "MQRC_ITEM_VALUE_ERROR",
+    "MQRC_JMS_FORMAT_ERROR",
+    "MQRC_LDAP_PASSWORD_ERROR",
+    "MQRC_LDAP_USER_NAME_ERROR",
+    "MQRC_MATCH_OPTIONS_ERROR",
+    "MQRC_MAX_MSG_LENGTH_ERROR",
+    "MQRC_MDE_ERROR",
+    "MQRC_MD_ERROR",
+    "MQRC_MHBO_ERROR",
+    "MQRC_MSG_FLAGS_ERROR",
+    "MQRC_MSG_ID_ERROR",
+    "MQRC_MSG_LENGTH_ERROR",
+    "MQRC_MSG_SEQ_NUMBER_ERROR",
+    "MQRC_MSG_TOKEN_ERROR",
+    "MQRC_MSG_TYPE_ERROR",
+    "MQRC_MULTIPLE_INSTANCE_ERROR",
+    "MQRC_NESTED_SELECTOR_ERROR",
+    "MQRC_NEXT_OFFSET_ERROR",
+    "MQRC_NEXT_RECORD_ERROR",
+    "MQRC_OBJECT_NAME_ERROR",
+    "MQRC_OBJECT_Q_MGR_NAME_ERROR",
+    "MQRC_OBJECT_RECORDS_ERROR",
+    "MQRC_OBJECT_STRING_ERROR",
+    "MQRC_OBJECT_TYPE_ERROR",
+    "MQRC_OD_ERROR",
+    "MQRC_OFFSET_ERROR",
+    "MQRC_OPERATION_ERROR",
+    "MQRC_OPTIONS_ERROR",
+    "MQRC_OPTION_ENVIRONMENT_ERROR",
+    "MQRC_ORIGINAL_LENGTH_ERROR",
+    "MQRC_OUT_SELECTOR_ERROR",
+    "MQRC_PCF_ERROR",
+    "MQRC_PD_ERROR",
+    "MQRC_PERSISTENCE_ERROR",
+    "MQRC_PMO_ERROR",
+    "MQRC_PMO_RECORD_FLAGS_ERROR",
+    "MQRC_PRIORITY_ERROR",
+    "MQRC_PROPERTY_NAME_ERROR",
+    "MQRC_PROPERTY_TYPE_ERROR",
+    "MQRC_PROP_NUMBER_FORMAT_ERROR",
+    "MQRC_PUT_MSG_RECORDS_ERROR",
+    "MQRC_Q_INDEX_TYPE_ERROR",
+    "MQRC_Q_MGR_NAME_ERROR",
+    "MQRC_Q_TYPE_ERROR",
+    "MQRC_RECONNECT_INCOMPATIBLE",
+    "MQRC_RECONNECT_QMID_MISMATCH",
+    "MQRC_RECONNECT_Q_MGR_REQD",
+    "MQRC_RECS_PRESENT_ERROR",
+    "MQRC_REFERENCE_ERROR",
+    "MQRC_REMOTE_Q_NAME_ERROR",
+    "MQRC_REOPEN_EXCL_INPUT_ERROR",
+    "MQRC_REOPEN_INQUIRE_ERROR",
+    "MQRC_REOPEN_TEMPORARY_Q_ERROR",
+    "MQRC_REPORT_OPTIONS_ERROR",
+    "MQRC_RESERVED_VALUE_ERROR",
+    "MQRC_RESPONSE_RECORDS_ERROR",
+    "MQRC_RES_OBJECT_STRING_ERROR",
+    "MQRC_RFH_COMMAND_ERROR",
+    "MQRC_RFH_ERROR",
+    "MQRC_RFH_FORMAT_ERROR",
+    "MQRC_RFH_HEADER_FIELD_ERROR",
+    "MQRC_RFH_PARM_ERROR",
+    "MQRC_RFH_STRING_ERROR",
+    "MQRC_RMH_ERROR",
+    "MQRC_SCO_ERROR",
+    "MQRC_SD_ERROR",
+    "MQRC_SELECTION_STRING_ERROR",
+    "MQRC_SELECTOR_COUNT_ERROR",
+    "MQRC_SELECTOR_ERROR",
+    "MQRC_SELECTOR_SYNTAX_ERROR",
+    "MQRC_SELECTOR_TYPE_ERROR",
+    "MQRC_SIGNAL1_ERROR",
+    "MQRC_SMPO_ERROR",
+    "MQRC_SOURCE_BUFFER_ERROR",
+    "MQRC_SOURCE_CCSID_ERROR",
+    "MQRC_SOURCE_DECIMAL_ENC_ERROR",
+    "MQRC_SOURCE_FLOAT_ENC_ERROR",
+    "MQRC_SOURCE_INTEGER_ENC_ERROR",
+    "MQRC_SOURCE_LENGTH_ERROR",
+    "MQRC_SRC_ENV_ERROR",
+    "MQRC_SRC_NAME_ERROR",
+    "MQRC_SRO_ERROR",
+    "MQRC_STAT_TYPE_ERROR",
+    "MQRC_STORAGE_CLASS_ERROR",
+    "MQRC_STRING_ERROR",
+    "MQRC_STRING_LENGTH_ERROR",
+    "MQRC_STRUC_ID_ERROR",
+    "MQRC_STRUC_LENGTH_ERROR",
+    "MQRC_STS_ERROR",
+    "MQRC_SUB_NAME_ERROR",
+    "MQRC_SUB_USER_DATA_ERROR",
+    "MQRC_TARGET_BUFFER_ERROR",
+    "MQRC_TARGET_CCSID_ERROR",
+    "MQRC_TARGET_DECIMAL_ENC_ERROR",
+    "MQRC_TARGET_FLOAT_ENC_ERROR",
+    "MQRC_TARGET_INTEGER_ENC_ERROR",
+    "MQRC_TARGET_LENGTH_ERROR",
+    "MQRC_TMC_ERROR",
+    "MQRC_TM_ERROR",
+    "MQRC_TOPIC_STRING_ERROR",
+    "MQRC_TRIGGER_CONTROL_ERROR",
+    "MQRC_TRIGGER_DEPTH_ERROR",
+    "MQRC_TRIGGER_TYPE_ERROR",
+    "MQRC_WAIT_INTERVAL_ERROR",
+    "MQRC_WIH_ERROR",
+    "MQRC_WXP_ERROR",
+    "MQRC_XEPO_ERROR",
+    "MQRC_XMIT_Q_TYPE_ERROR",
+    "MQRC_XMIT_Q_USAGE_ERROR",
+    "MQRC_XQH_ERROR",
+    "MQRC_XWAIT_ERROR",
+];
+
+/// Errors of the queue manager, its exits and its environment, whose names match the usage error patterns
+const UNKNOWN_REASONS: &[&str] = &[
+    "MQRC_CLUSTER_RESOURCE_ERROR",
+    "MQRC_FILE_SYSTEM_ERROR",
+    "MQRC_INSTALLATION_MISMATCH",
+    "MQRC_INSTALLATION_MISSING",
+    "MQRC_MULTICAST_INTERNAL_ERROR",
+    "MQRC_PAGESET_ERROR",
+    "MQRC_RETAINED_MSG_Q_ERROR",
+    "MQRC_SERVICE_ERROR",
+    "MQRC_UNEXPECTED_ERROR",
+];
+
+/// Category variant of an `MQRC_*` constant from the lists above, falling back to the [`derived_category`]
+fn reason_category(name: &str) -> &'static str {
+    [
+        (WARNING_REASONS, "Warning"),
+        (TRANSIENT_REASONS, "Transient"),
+        (CONNECTION_REASONS, "ConnectionLost"),
+        (SECURITY_REASONS, "Security"),
+        (USAGE_REASONS, "Usage"),
+        (UNKNOWN_REASONS, "Unknown"),
+    ]
+    .into_iter()
+    .find(|(reasons, _)| reasons.contains(&name))
+    .map_or_else(|| derived_category(name), |(_, category)| category)
+}
+
+/// Category variant of an `MQRC_*` constant from the patterns of its name, or `Unknown` when no pattern matches.
+///
+/// Reasons named `*_WARNING` or `*_ACCEPTED` are the only ones completing with `MQCC_WARNING`, every other reason
+/// fails the call. Errors raised by exits or while loading a module are never classified as usage errors.
+fn derived_category(name: &str) -> &'static str {
+    let reason = name.trim_start_matches("MQRC_");
+    let contains = |patterns: &[&str]| patterns.iter().any(|pattern| reason.contains(pattern));
+    if reason.ends_with("_WARNING") || reason.ends_with("_ACCEPTED") {
+        "Warning"
+    } else if reason.starts_with("SSL_") || contains(&["NOT_AUTHORIZED", "AUTH_FAILED", "CIPHER", "CERT_", "NOT_PRIVILEGED"]) {
+        "Security"
+    } else if reason.starts_with("CONNECTION_") || contains(&["QUIESC", "STOPPING", "NOT_CONNECTED", "Q_MGR_NOT_ACTIVE"]) {
+        "ConnectionLost"
+    } else if contains(&["_IN_USE", "_FULL", "_AVAILABLE", "_SHORTAGE", "LIMIT_REACHED", "_INHIBITED"]) {
+        "Transient"
+    } else if contains(&["EXIT", "LOAD_ERROR"]) {
+        "Unknown"
+    } else if reason.ends_with("_ERROR")
+        || reason.ends_with("_ERR")
+        || reason.starts_with("WRONG_")
+        || reason.starts_with("UNKNOWN_")
+        || reason.starts_with("INCONSISTENT_")
+        || contains(&[
+            "NOT_VALID",
+            "NOT_OPEN",
+            "NOT_ALLOWED",
+            "NOT_SUPPORTED",
+            "NOT_ALTERABLE",
+            "_TOO_",
+            "MISSING",
+        ])
+    {
+        "Usage"
+    } else {
+        "Unknown"
+    }
+}
+
+/// Write the table of `MQRC_*` values and their category, sorted by value
+fn generate_reasons(groups: &BTreeMap<&str, Group>, writer: &mut impl Write) -> Result<(), io::Error> {
+    let categories: BTreeMap<_, _> = groups
+        .get("MQRC")
+        .into_iter()
+        .flat_map(|(_, values)| values)
+        .filter(|(name, value)| !is_range_marker(name) && **value != 0)
+        .map(|(name, value)| (*value, reason_category(name)))
+        .collect();
+
+    writeln!(writer, "// Generated from the constants of the MQ bindings. Do not edit.")?;
+    writeln!(writer, "static REASON_CATEGORIES: &[(MQLONG, ReasonCategory)] = &[")?;
+    for (value, category) in categories {
+        writeln!(writer, "    ({value}, ReasonCategory::{category}),")?;
+    }
+    writeln!(writer, "];")?;
+
+    Ok(())
+}
+
+/// Read the bindings and write the `names.rs` and `reasons.rs` tables into the output directory
+pub fn write_names(out_path: &Path) -> Result<(), io::Error> {
+    let bindings = fs::read_to_string(bindings_path(out_path)?)?;
+    let groups = parse_groups(&bindings)?;
+
+    let mut writer = io::BufWriter::new(fs::File::create(out_path.join("names.rs"))?);
+    generate_names(&groups, &mut writer)?;
+    writer.flush()?;
+
+    let mut writer = io::BufWriter::new(fs::File::create(out_path.join("reasons.rs"))?);
+    generate_reasons(&groups, &mut writer)?;
+    writer.flush()
+}
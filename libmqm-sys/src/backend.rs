@@ -0,0 +1,330 @@
+/*!
+ * Run-time selection between the compile time linked and the dynamically loaded MQI library
+ *
+ * [`AnyMq`] is either the compile time linked [`LinkedMq`](crate::link::LinkedMq) or a dynamically loaded
+ * [`MqmContainer`](crate::dlopen2::MqmContainer) or [`LibloadingMq`](crate::libloading::LibloadingMq), depending
+ * on which of the `link_api`, `dlopen2` and `libloading` features are enabled and what is available at run-time.
+ * The [`Backend`]s are tried in order until one succeeds. The dynamically loaded backends find the server or client
+ * library in the MQ installations of the [`discovery`](crate::discovery) module, falling back to the platform search
+ * path.
+ *
+ * The order can be configured with the `LIBMQM_SYS_BACKEND` environment variable as a comma separated list of
+ * `dlopen2`, `libloading` and `link`.
+ *
+ * Example
+ * -------
+ *
+ *  Dynamically load the MQ library, falling back to the compile time linked library
+ *
+ * ```no_run
+ * use libmqm_sys::backend::{AnyMq, Backend};
+ *
+ * # fn main() -> Result<(), libmqm_sys::backend::LoadError> {
+ * let mq = unsafe { AnyMq::load(&[Backend::Dlopen2, Backend::Link]) }?;
+ *
+ * // Or use the order from the LIBMQM_SYS_BACKEND environment variable
+ * let mq = unsafe { AnyMq::load_from_env() }?;
+ * # Ok(())
+ * # }
+ * ```
+ */
+
+use std::{env, error::Error, fmt, str::FromStr};
+
+use crate::function;
+
+#[cfg(any(feature = "dlopen2", feature = "libloading"))]
+use crate::discovery::MqmLibrary;
+#[cfg(feature = "dlopen2")]
+use crate::dlopen2::{self as dl, LoadMqm as _, MqmContainer};
+#[cfg(feature = "libloading")]
+use crate::libloading::{self as ll, LibloadingMq};
+#[cfg(feature = "link_api")]
+use crate::link::LinkedMq;
+
+/// Environment variable holding the comma separated order of the backends
+pub const BACKEND_ENV: &str = "LIBMQM_SYS_BACKEND";
+
+/// The MQ libraries tried by the dynamically loaded backends, in order of preference
+#[cfg(any(feature = "dlopen2", feature = "libloading"))]
+const LIBRARIES: &[MqmLibrary] = &[MqmLibrary::Server, MqmLibrary::Client];
+
+/// A source of the MQI library
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// The MQ library loaded at run-time using `dlopen2`
+    Dlopen2,
+    /// The MQ library loaded at run-time using `libloading`
+    Libloading,
+    /// The MQ library linked at compile time
+    Link,
+}
+
+impl Backend {
+    /// The default order of the backends, preferring the dynamically loaded MQ library
+    pub const DEFAULT_ORDER: &'static [Self] = &[Self::Dlopen2, Self::Libloading, Self::Link];
+
+    /// Whether the backend is enabled by the crate features
+    #[must_use]
+    pub const fn is_enabled(self) -> bool {
+        match self {
+            Self::Dlopen2 => cfg!(feature = "dlopen2"),
+            Self::Libloading => cfg!(feature = "libloading"),
+            Self::Link => cfg!(feature = "link_api"),
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = LoadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "dlopen2" => Ok(Self::Dlopen2),
+            "libloading" => Ok(Self::Libloading),
+            "link" => Ok(Self::Link),
+            other => Err(LoadError::UnknownBackend(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Dlopen2 => "dlopen2",
+            Self::Libloading => "libloading",
+            Self::Link => "link",
+        })
+    }
+}
+
+/// Failure to select a [`Backend`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LoadError {
+    /// None of the requested backends are enabled by the crate features
+    Unavailable,
+    /// The backend name is not `dlopen2`, `libloading` or `link`
+    UnknownBackend(String),
+    /// The MQ library could not be loaded using `dlopen2` from any of the discovered paths
+    #[cfg(feature = "dlopen2")]
+    Dlopen2(dl::DiscoveryError),
+    /// The MQ library could not be loaded using `libloading` from any of the discovered paths
+    #[cfg(feature = "libloading")]
+    Libloading(ll::DiscoveryError),
+    /// Every enabled backend failed to load, with the error of each in the order they were tried
+    Backends(Vec<(Backend, Self)>),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => f.write_str("none of the requested MQ library backends are enabled"),
+            Self::UnknownBackend(name) => write!(f, "unknown MQ library backend \"{name}\""),
+            #[cfg(feature = "dlopen2")]
+            Self::Dlopen2(error) => error.fmt(f),
+            #[cfg(feature = "libloading")]
+            Self::Libloading(error) => error.fmt(f),
+            Self::Backends(errors) => {
+                f.write_str("failed to load the MQ library from any backend")?;
+                for (backend, error) in errors {
+                    write!(f, "\n  {backend}: {error}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            #[cfg(feature = "dlopen2")]
+            Self::Dlopen2(error) => Some(error),
+            #[cfg(feature = "libloading")]
+            Self::Libloading(error) => Some(error),
+            Self::Backends(errors) => errors.last().map(|(_, error)| error as &(dyn Error + 'static)),
+            _ => None,
+        }
+    }
+}
+
+/// The MQI library from the first available [`Backend`]
+#[non_exhaustive]
+pub enum AnyMq {
+    /// The MQ library linked at compile time
+    #[cfg(feature = "link_api")]
+    Linked(LinkedMq),
+    /// The MQ library loaded at run-time
+    #[cfg(feature = "dlopen2")]
+    Loaded(Box<MqmContainer>),
+    /// The MQ library loaded at run-time using `libloading`
+    #[cfg(feature = "libloading")]
+    Libloading(Box<LibloadingMq>),
+}
+
+impl AnyMq {
+    /// Select the first of the `backends` that is enabled and can be loaded
+    ///
+    /// # Safety
+    /// Loading the dynamic library is inherently unsafe
+    ///
+    /// # Errors
+    /// Returns [`LoadError::Backends`] holding the error of every backend that failed to load, or
+    /// [`LoadError::Unavailable`] when none of the `backends` are enabled
+    pub unsafe fn load(backends: &[Backend]) -> Result<Self, LoadError> {
+        let mut errors = Vec::new();
+        for &backend in backends.iter().filter(|backend| backend.is_enabled()) {
+            match unsafe { Self::load_backend(backend) } {
+                Ok(mq) => return Ok(mq),
+                Err(error) => errors.push((backend, error)),
+            }
+        }
+        if errors.is_empty() {
+            Err(LoadError::Unavailable)
+        } else {
+            Err(LoadError::Backends(errors))
+        }
+    }
+
+    /// Select the backend using the order in the `LIBMQM_SYS_BACKEND` environment variable, or
+    /// [`Backend::DEFAULT_ORDER`] when it is not set
+    ///
+    /// # Safety
+    /// Loading the dynamic library is inherently unsafe
+    ///
+    /// # Errors
+    /// Returns [`LoadError::UnknownBackend`] when the environment variable is invalid, otherwise as [`AnyMq::load`]
+    pub unsafe fn load_from_env() -> Result<Self, LoadError> {
+        match env::var(BACKEND_ENV) {
+            Ok(order) => {
+                let backends = order.split(',').map(str::parse).collect::<Result<Vec<_>, _>>()?;
+                unsafe { Self::load(&backends) }
+            }
+            Err(_) => unsafe { Self::load(Backend::DEFAULT_ORDER) },
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps, clippy::missing_const_for_fn)] // Only fails when a dynamic loading feature is enabled
+    unsafe fn load_backend(backend: Backend) -> Result<Self, LoadError> {
+        match backend {
+            #[cfg(feature = "dlopen2")]
+            Backend::Dlopen2 => unsafe { MqmContainer::load_mqm_discover(LIBRARIES) }
+                .map(|mq| Self::Loaded(Box::new(mq)))
+                .map_err(LoadError::Dlopen2),
+            #[cfg(feature = "libloading")]
+            Backend::Libloading => unsafe { LibloadingMq::load_mqm_discover(LIBRARIES) }
+                .map(|mq| Self::Libloading(Box::new(mq)))
+                .map_err(LoadError::Libloading),
+            #[cfg(feature = "link_api")]
+            Backend::Link => Ok(Self::Linked(LinkedMq)),
+            #[allow(unreachable_patterns)]
+            _ => Err(LoadError::Unavailable),
+        }
+    }
+
+    /// The [`Backend`] that was selected
+    #[must_use]
+    pub const fn backend(&self) -> Backend {
+        match self {
+            #[cfg(feature = "link_api")]
+            Self::Linked(_) => Backend::Link,
+            #[cfg(feature = "dlopen2")]
+            Self::Loaded(_) => Backend::Dlopen2,
+            #[cfg(feature = "libloading")]
+            Self::Libloading(_) => Backend::Libloading,
+        }
+    }
+
+    fn as_mqi(&self) -> &dyn function::Mqi {
+        match self {
+            #[cfg(feature = "link_api")]
+            Self::Linked(mq) => mq,
+            #[cfg(feature = "dlopen2")]
+            Self::Loaded(mq) => mq,
+            #[cfg(feature = "libloading")]
+            Self::Libloading(mq) => mq,
+        }
+    }
+
+    #[cfg(feature = "exits")]
+    fn as_mqi_exit(&self) -> &dyn function::MqiExit {
+        match self {
+            #[cfg(feature = "link_api")]
+            Self::Linked(mq) => mq,
+            #[cfg(feature = "dlopen2")]
+            Self::Loaded(mq) => mq,
+            #[cfg(feature = "libloading")]
+            Self::Libloading(mq) => mq,
+        }
+    }
+
+    #[cfg(feature = "mqai")]
+    fn as_mqai(&self) -> &dyn function::Mqai {
+        match self {
+            #[cfg(feature = "link_api")]
+            Self::Linked(mq) => mq,
+            #[cfg(feature = "dlopen2")]
+            Self::Loaded(mq) => mq,
+            #[cfg(feature = "libloading")]
+            Self::Libloading(mq) => mq,
+        }
+    }
+}
+
+impl fmt::Debug for AnyMq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyMq").field(&self.backend()).finish()
+    }
+}
+
+mqi_verbs!(forward_impl!([] function::Mqi, AnyMq, |this| this.as_mqi(),));
+
+#[cfg(feature = "exits")]
+mqi_exit_verbs!(forward_impl!([] function::MqiExit, AnyMq, |this| this.as_mqi_exit(),));
+
+#[cfg(feature = "mqai")]
+mqai_verbs!(forward_impl!([] function::Mqai, AnyMq, |this| this.as_mqai(),));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_backend() {
+        assert_eq!("dlopen2".parse::<Backend>().ok(), Some(Backend::Dlopen2));
+        assert_eq!(" link".parse::<Backend>().ok(), Some(Backend::Link));
+        assert!(matches!("static".parse::<Backend>(), Err(LoadError::UnknownBackend(name)) if name == "static"));
+    }
+
+    #[cfg(feature = "link_api")]
+    #[test]
+    fn link_fallback() {
+        let mq = unsafe { AnyMq::load(&[Backend::Link]) }.expect("linked backend");
+        assert_eq!(mq.backend(), Backend::Link);
+        assert!(matches!(unsafe { AnyMq::load(&[]) }, Err(LoadError::Unavailable)));
+    }
+
+    #[cfg(all(feature = "dlopen2", feature = "libloading"))]
+    #[test]
+    fn every_error() {
+        match unsafe { AnyMq::load(&[Backend::Dlopen2, Backend::Libloading]) } {
+            // The MQ library is installed
+            Ok(mq) => assert_eq!(mq.backend(), Backend::Dlopen2),
+            Err(LoadError::Backends(errors)) => {
+                let backends: Vec<_> = errors.iter().map(|&(backend, _)| backend).collect();
+                assert_eq!(backends, [Backend::Dlopen2, Backend::Libloading]);
+                let LoadError::Dlopen2(dlopen2) = &errors[0].1 else {
+                    panic!("dlopen2 error expected");
+                };
+                let LoadError::Libloading(libloading) = &errors[1].1 else {
+                    panic!("libloading error expected");
+                };
+                // Every installation and the platform search path are attempted for each library
+                assert_eq!(dlopen2.attempts.len(), libloading.attempts.len());
+                assert!(dlopen2.attempts.len() > LIBRARIES.len());
+            }
+            Err(error) => panic!("unexpected error: {error}"),
+        }
+    }
+}
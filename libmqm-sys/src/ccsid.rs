@@ -0,0 +1,211 @@
+/*!
+ * Conversion of single byte and UTF-8 coded character sets to and from UTF-8 in pure Rust
+ *
+ * Strings in MQ structures and messages are in the coded character set of their `CodedCharSetId`. These functions
+ * convert the CCSIDs that are common on distributed and z/OS queue managers without the MQ library or iconv:
+ *
+ * | CCSID             | Character set                    |
+ * |-------------------|----------------------------------|
+ * | 1208              | UTF-8                            |
+ * | 367               | ASCII                            |
+ * | 819               | ISO 8859-1                       |
+ * | 1252, 5348        | Windows Latin 1                  |
+ * | 37, 1140          | EBCDIC US and Canada             |
+ * | 500, 1148         | EBCDIC International             |
+ * | 1047              | EBCDIC Latin 1 Open Systems      |
+ *
+ * Example
+ * -------
+ *
+ * ```
+ * use libmqm_sys::ccsid;
+ *
+ * assert_eq!(ccsid::to_utf8(500, b"\xC1\xD7\xD7").as_deref(), Some("APP"));
+ * assert_eq!(ccsid::from_utf8(500, "APP"), Some(b"\xC1\xD7\xD7".to_vec()));
+ * ```
+ */
+
+use crate::lib::MQLONG;
+
+/// Coded character sets that can be converted
+#[derive(Debug, Clone, Copy)]
+enum Charset {
+    Utf8,
+    Ascii,
+    Latin1,
+    Table(&'static [u16; 256]),
+}
+
+const IBM037: [u16; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x009C, 0x0009, 0x0086, 0x007F, 0x0097, 0x008D, 0x008E, 0x000B, 0x000C, 0x000D, 0x000E,
+    0x000F, 0x0010, 0x0011, 0x0012, 0x0013, 0x009D, 0x0085, 0x0008, 0x0087, 0x0018, 0x0019, 0x0092, 0x008F, 0x001C, 0x001D,
+    0x001E, 0x001F, 0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x000A, 0x0017, 0x001B, 0x0088, 0x0089, 0x008A, 0x008B, 0x008C,
+    0x0005, 0x0006, 0x0007, 0x0090, 0x0091, 0x0016, 0x0093, 0x0094, 0x0095, 0x0096, 0x0004, 0x0098, 0x0099, 0x009A, 0x009B,
+    0x0014, 0x0015, 0x009E, 0x001A, 0x0020, 0x00A0, 0x00E2, 0x00E4, 0x00E0, 0x00E1, 0x00E3, 0x00E5, 0x00E7, 0x00F1, 0x00A2,
+    0x002E, 0x003C, 0x0028, 0x002B, 0x007C, 0x0026, 0x00E9, 0x00EA, 0x00EB, 0x00E8, 0x00ED, 0x00EE, 0x00EF, 0x00EC, 0x00DF,
+    0x0021, 0x0024, 0x002A, 0x0029, 0x003B, 0x00AC, 0x002D, 0x002F, 0x00C2, 0x00C4, 0x00C0, 0x00C1, 0x00C3, 0x00C5, 0x00C7,
+    0x00D1, 0x00A6, 0x002C, 0x0025, 0x005F, 0x003E, 0x003F, 0x00F8, 0x00C9, 0x00CA, 0x00CB, 0x00C8, 0x00CD, 0x00CE, 0x00CF,
+    0x00CC, 0x0060, 0x003A, 0x0023, 0x0040, 0x0027, 0x003D, 0x0022, 0x00D8, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066,
+    0x0067, 0x0068, 0x0069, 0x00AB, 0x00BB, 0x00F0, 0x00FD, 0x00FE, 0x00B1, 0x00B0, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E,
+    0x006F, 0x0070, 0x0071, 0x0072, 0x00AA, 0x00BA, 0x00E6, 0x00B8, 0x00C6, 0x00A4, 0x00B5, 0x007E, 0x0073, 0x0074, 0x0075,
+    0x0076, 0x0077, 0x0078, 0x0079, 0x007A, 0x00A1, 0x00BF, 0x00D0, 0x00DD, 0x00DE, 0x00AE, 0x005E, 0x00A3, 0x00A5, 0x00B7,
+    0x00A9, 0x00A7, 0x00B6, 0x00BC, 0x00BD, 0x00BE, 0x005B, 0x005D, 0x00AF, 0x00A8, 0x00B4, 0x00D7, 0x007B, 0x0041, 0x0042,
+    0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x00AD, 0x00F4, 0x00F6, 0x00F2, 0x00F3, 0x00F5, 0x007D, 0x004A,
+    0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052, 0x00B9, 0x00FB, 0x00FC, 0x00F9, 0x00FA, 0x00FF, 0x005C,
+    0x00F7, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A, 0x00B2, 0x00D4, 0x00D6, 0x00D2, 0x00D3, 0x00D5,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x00B3, 0x00DB, 0x00DC, 0x00D9, 0x00DA,
+    0x009F,
+];
+
+const IBM500: [u16; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x009C, 0x0009, 0x0086, 0x007F, 0x0097, 0x008D, 0x008E, 0x000B, 0x000C, 0x000D, 0x000E,
+    0x000F, 0x0010, 0x0011, 0x0012, 0x0013, 0x009D, 0x0085, 0x0008, 0x0087, 0x0018, 0x0019, 0x0092, 0x008F, 0x001C, 0x001D,
+    0x001E, 0x001F, 0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x000A, 0x0017, 0x001B, 0x0088, 0x0089, 0x008A, 0x008B, 0x008C,
+    0x0005, 0x0006, 0x0007, 0x0090, 0x0091, 0x0016, 0x0093, 0x0094, 0x0095, 0x0096, 0x0004, 0x0098, 0x0099, 0x009A, 0x009B,
+    0x0014, 0x0015, 0x009E, 0x001A, 0x0020, 0x00A0, 0x00E2, 0x00E4, 0x00E0, 0x00E1, 0x00E3, 0x00E5, 0x00E7, 0x00F1, 0x005B,
+    0x002E, 0x003C, 0x0028, 0x002B, 0x0021, 0x0026, 0x00E9, 0x00EA, 0x00EB, 0x00E8, 0x00ED, 0x00EE, 0x00EF, 0x00EC, 0x00DF,
+    0x005D, 0x0024, 0x002A, 0x0029, 0x003B, 0x005E, 0x002D, 0x002F, 0x00C2, 0x00C4, 0x00C0, 0x00C1, 0x00C3, 0x00C5, 0x00C7,
+    0x00D1, 0x00A6, 0x002C, 0x0025, 0x005F, 0x003E, 0x003F, 0x00F8, 0x00C9, 0x00CA, 0x00CB, 0x00C8, 0x00CD, 0x00CE, 0x00CF,
+    0x00CC, 0x0060, 0x003A, 0x0023, 0x0040, 0x0027, 0x003D, 0x0022, 0x00D8, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066,
+    0x0067, 0x0068, 0x0069, 0x00AB, 0x00BB, 0x00F0, 0x00FD, 0x00FE, 0x00B1, 0x00B0, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E,
+    0x006F, 0x0070, 0x0071, 0x0072, 0x00AA, 0x00BA, 0x00E6, 0x00B8, 0x00C6, 0x00A4, 0x00B5, 0x007E, 0x0073, 0x0074, 0x0075,
+    0x0076, 0x0077, 0x0078, 0x0079, 0x007A, 0x00A1, 0x00BF, 0x00D0, 0x00DD, 0x00DE, 0x00AE, 0x00A2, 0x00A3, 0x00A5, 0x00B7,
+    0x00A9, 0x00A7, 0x00B6, 0x00BC, 0x00BD, 0x00BE, 0x00AC, 0x007C, 0x00AF, 0x00A8, 0x00B4, 0x00D7, 0x007B, 0x0041, 0x0042,
+    0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x00AD, 0x00F4, 0x00F6, 0x00F2, 0x00F3, 0x00F5, 0x007D, 0x004A,
+    0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052, 0x00B9, 0x00FB, 0x00FC, 0x00F9, 0x00FA, 0x00FF, 0x005C,
+    0x00F7, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A, 0x00B2, 0x00D4, 0x00D6, 0x00D2, 0x00D3, 0x00D5,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x00B3, 0x00DB, 0x00DC, 0x00D9, 0x00DA,
+    0x009F,
+];
+
+const WINDOWS1252: [u16; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0006, 0x0007, 0x0008, 0x0009, 0x000A, 0x000B, 0x000C, 0x000D, 0x000E,
+    0x000F, 0x0010, 0x0011, 0x0012, 0x0013, 0x0014, 0x0015, 0x0016, 0x0017, 0x0018, 0x0019, 0x001A, 0x001B, 0x001C, 0x001D,
+    0x001E, 0x001F, 0x0020, 0x0021, 0x0022, 0x0023, 0x0024, 0x0025, 0x0026, 0x0027, 0x0028, 0x0029, 0x002A, 0x002B, 0x002C,
+    0x002D, 0x002E, 0x002F, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x003A, 0x003B,
+    0x003C, 0x003D, 0x003E, 0x003F, 0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004A,
+    0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059,
+    0x005A, 0x005B, 0x005C, 0x005D, 0x005E, 0x005F, 0x0060, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068,
+    0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007A, 0x007B, 0x007C, 0x007D, 0x007E, 0x007F, 0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020,
+    0x2021, 0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0x008D, 0x017D, 0x008F, 0x0090, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022,
+    0x2013, 0x2014, 0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178, 0x00A0, 0x00A1, 0x00A2, 0x00A3, 0x00A4,
+    0x00A5, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x00AA, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF, 0x00B0, 0x00B1, 0x00B2, 0x00B3,
+    0x00B4, 0x00B5, 0x00B6, 0x00B7, 0x00B8, 0x00B9, 0x00BA, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x00BF, 0x00C0, 0x00C1, 0x00C2,
+    0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x00C7, 0x00C8, 0x00C9, 0x00CA, 0x00CB, 0x00CC, 0x00CD, 0x00CE, 0x00CF, 0x00D0, 0x00D1,
+    0x00D2, 0x00D3, 0x00D4, 0x00D5, 0x00D6, 0x00D7, 0x00D8, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x00DD, 0x00DE, 0x00DF, 0x00E0,
+    0x00E1, 0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x00E7, 0x00E8, 0x00E9, 0x00EA, 0x00EB, 0x00EC, 0x00ED, 0x00EE, 0x00EF,
+    0x00F0, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x00F7, 0x00F8, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x00FD, 0x00FE,
+    0x00FF,
+];
+
+/// IBM-1047 is IBM-037 with the brackets, circumflex, not sign, Y acute and diaeresis in other positions
+const IBM1047: [u16; 256] = {
+    let mut table = IBM037;
+    table[0x5F] = 0x005E;
+    table[0xAD] = 0x005B;
+    table[0xB0] = 0x00AC;
+    table[0xBA] = 0x00DD;
+    table[0xBB] = 0x00A8;
+    table[0xBD] = 0x005D;
+    table
+};
+
+/// Replace the currency sign with the euro sign, as in IBM-1140 and IBM-1148
+const fn with_euro(mut table: [u16; 256]) -> [u16; 256] {
+    table[0x9F] = 0x20AC;
+    table
+}
+
+const IBM1140: [u16; 256] = with_euro(IBM037);
+const IBM1148: [u16; 256] = with_euro(IBM500);
+
+const fn charset(ccsid: MQLONG) -> Option<Charset> {
+    Some(match ccsid {
+        1208 => Charset::Utf8,
+        367 => Charset::Ascii,
+        819 => Charset::Latin1,
+        1252 | 5348 => Charset::Table(&WINDOWS1252),
+        37 => Charset::Table(&IBM037),
+        500 => Charset::Table(&IBM500),
+        1047 => Charset::Table(&IBM1047),
+        1140 => Charset::Table(&IBM1140),
+        1148 => Charset::Table(&IBM1148),
+        _ => return None,
+    })
+}
+
+/// Whether strings in the `ccsid` can be converted
+#[must_use]
+pub const fn is_supported(ccsid: MQLONG) -> bool {
+    charset(ccsid).is_some()
+}
+
+/// Convert the `bytes` in the `ccsid` to UTF-8. Returns `None` when the CCSID is not supported or the bytes are not
+/// valid in the CCSID.
+#[must_use]
+pub fn to_utf8(ccsid: MQLONG, bytes: &[u8]) -> Option<String> {
+    match charset(ccsid)? {
+        Charset::Utf8 => String::from_utf8(bytes.to_vec()).ok(),
+        Charset::Ascii => bytes.is_ascii().then(|| bytes.iter().copied().map(char::from).collect()),
+        Charset::Latin1 => Some(bytes.iter().copied().map(char::from).collect()),
+        Charset::Table(table) => bytes
+            .iter()
+            .map(|&byte| char::from_u32(u32::from(table[usize::from(byte)])))
+            .collect(),
+    }
+}
+
+/// Convert the UTF-8 `text` to the `ccsid`. Returns `None` when the CCSID is not supported or a character of the
+/// text is not in the CCSID.
+#[must_use]
+pub fn from_utf8(ccsid: MQLONG, text: &str) -> Option<Vec<u8>> {
+    match charset(ccsid)? {
+        Charset::Utf8 => Some(text.as_bytes().to_vec()),
+        Charset::Ascii => text.is_ascii().then(|| text.as_bytes().to_vec()),
+        Charset::Latin1 => text.chars().map(|c| u8::try_from(c).ok()).collect(),
+        Charset::Table(table) => text
+            .chars()
+            .map(|c| {
+                let code = u16::try_from(u32::from(c)).ok()?;
+                table
+                    .iter()
+                    .position(|&entry| entry == code)
+                    .and_then(|byte| u8::try_from(byte).ok())
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let text = "SYSTEM.ADMIN.COMMAND.QUEUE [a-z] ^~¬ ¢ 0123456789";
+        for ccsid in [37, 500, 1047, 1140, 1148, 819, 1252, 1208] {
+            let encoded = from_utf8(ccsid, text).unwrap_or_else(|| panic!("{text} in CCSID {ccsid}"));
+            assert_eq!(to_utf8(ccsid, &encoded).as_deref(), Some(text), "CCSID {ccsid}");
+        }
+    }
+
+    #[test]
+    fn ebcdic() {
+        assert_eq!(from_utf8(37, " AZaz09"), Some(b"\x40\xC1\xE9\x81\xA9\xF0\xF9".to_vec()));
+        assert_eq!(from_utf8(37, "[]"), Some(b"\xBA\xBB".to_vec()));
+        assert_eq!(from_utf8(1047, "[]"), Some(b"\xAD\xBD".to_vec()));
+        assert_eq!(from_utf8(500, "[]"), Some(b"\x4A\x5A".to_vec()));
+        assert_eq!(to_utf8(1140, b"\x9F").as_deref(), Some("€"));
+        assert_eq!(from_utf8(37, "€"), None);
+    }
+
+    #[test]
+    fn unsupported() {
+        assert!(!is_supported(1200));
+        assert_eq!(to_utf8(1200, b"A"), None);
+        assert_eq!(to_utf8(367, b"\x80"), None);
+        assert_eq!(to_utf8(1208, b"\xFF"), None);
+        assert_eq!(from_utf8(819, "€"), None);
+    }
+}
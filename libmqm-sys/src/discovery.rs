@@ -0,0 +1,193 @@
+/*!
+ * Names and locations of the MQ dynamic libraries, shared by the run-time loaders
+ *
+ * Both the [`dlopen2`](crate::dlopen2) and [`libloading`](crate::libloading) loaders find the server (`libmqm_r`)
+ * or client (`libmqic_r`) library in an MQ installation, located using the [`INSTALLATION_ENV`] environment
+ * variables or the [`DEFAULT_INSTALLATION`], without relying on the platform search path. Every failed attempt is
+ * reported in the [`DiscoveryError`].
+ */
+
+use std::{
+    env,
+    error::Error,
+    ffi::OsString,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// Name of the platform dependent MQM dynamic library
+pub const MQM_LIB: &str = MqmLibrary::Server.file_name();
+
+/// Environment variables holding the location of an MQ installation, in order of precedence
+pub const INSTALLATION_ENV: &[&str] = &["MQ_HOME", "MQ_INSTALLATION_PATH"];
+
+/// Location of the default MQ installation
+pub const DEFAULT_INSTALLATION: &str = if cfg!(windows) {
+    "C:\\Program Files\\IBM\\MQ"
+} else {
+    "/opt/mqm"
+};
+
+/// Folder of an MQ installation holding the dynamic libraries
+pub const LIB_DIR: &str = if cfg!(windows) { "bin64" } else { "lib64" };
+
+/// The MQ dynamic library to load from an installation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MqmLibrary {
+    /// The server bindings library `libmqm_r`, which can connect using server or client bindings
+    Server,
+    /// The client library `libmqic_r`, which can only connect using client bindings
+    Client,
+}
+
+impl MqmLibrary {
+    /// Platform dependent file name of the library
+    #[must_use]
+    pub const fn file_name(self) -> &'static str {
+        match self {
+            Self::Server if cfg!(windows) => "mqm.dll",
+            Self::Client if cfg!(windows) => "mqic.dll",
+            Self::Server if cfg!(target_os = "macos") => "libmqm_r.dylib",
+            Self::Client if cfg!(target_os = "macos") => "libmqic_r.dylib",
+            Self::Server => "libmqm_r.so",
+            Self::Client => "libmqic_r.so",
+        }
+    }
+
+    /// Path of the library within the MQ `installation`
+    #[must_use]
+    pub fn path(self, installation: impl AsRef<Path>) -> PathBuf {
+        installation.as_ref().join(LIB_DIR).join(self.file_name())
+    }
+}
+
+/// A failed attempt to load the MQ library from a path
+#[derive(Debug)]
+pub struct LoadAttempt<E> {
+    /// Path of the library. A bare file name is resolved using the platform search path.
+    pub path: PathBuf,
+    /// Reason the library could not be loaded
+    pub error: E,
+}
+
+/// Failure to load the MQ library from any of the attempted paths
+#[derive(Debug)]
+pub struct DiscoveryError<E> {
+    /// Each attempt, in the order they were made
+    pub attempts: Vec<LoadAttempt<E>>,
+}
+
+impl<E: fmt::Display> fmt::Display for DiscoveryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.attempts.is_empty() {
+            return f.write_str("no MQ library paths to load");
+        }
+        f.write_str("failed to load the MQ library")?;
+        for LoadAttempt { path, error } in &self.attempts {
+            write!(f, "\n  {}: {error}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Error + 'static> Error for DiscoveryError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.attempts.last().map(|attempt| &attempt.error as &(dyn Error + 'static))
+    }
+}
+
+/// MQ installations from the [`INSTALLATION_ENV`] environment variables followed by the [`DEFAULT_INSTALLATION`]
+#[must_use]
+pub fn installations() -> Vec<PathBuf> {
+    installations_from(|name| env::var_os(name))
+}
+
+fn installations_from(var: impl Fn(&str) -> Option<OsString>) -> Vec<PathBuf> {
+    let mut installations: Vec<PathBuf> = Vec::new();
+    for installation in INSTALLATION_ENV
+        .iter()
+        .filter_map(|&name| var(name))
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .chain([PathBuf::from(DEFAULT_INSTALLATION)])
+    {
+        if !installations.contains(&installation) {
+            installations.push(installation);
+        }
+    }
+    installations
+}
+
+/// Paths of the `libraries` in the `installations`, each library in an installation before the next installation
+pub(crate) fn installation_paths<'a, P: AsRef<Path>>(
+    installations: &'a [P],
+    libraries: &'a [MqmLibrary],
+) -> impl Iterator<Item = PathBuf> + 'a {
+    installations
+        .iter()
+        .flat_map(|installation| libraries.iter().map(move |library| library.path(installation)))
+}
+
+/// Paths of the `libraries` in the [`installations`], followed by the bare file names for the platform search path
+pub(crate) fn discovery_paths(libraries: &[MqmLibrary]) -> Vec<PathBuf> {
+    installation_paths(&installations(), libraries)
+        .chain(libraries.iter().map(|library| PathBuf::from(library.file_name())))
+        .collect()
+}
+
+/// Load the first of the `paths` that succeeds, recording each failed attempt
+pub(crate) fn load_first<T, E>(
+    paths: impl IntoIterator<Item = PathBuf>,
+    mut load: impl FnMut(&Path) -> Result<T, E>,
+) -> Result<T, DiscoveryError<E>> {
+    let mut attempts = Vec::new();
+    for path in paths {
+        match load(&path) {
+            Ok(library) => return Ok(library),
+            Err(error) => attempts.push(LoadAttempt { path, error }),
+        }
+    }
+    Err(DiscoveryError { attempts })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+
+    #[test]
+    fn installation_order() {
+        let installations = installations_from(|name| match name {
+            "MQ_HOME" => Some("mq_home".into()),
+            "MQ_INSTALLATION_PATH" => Some(DEFAULT_INSTALLATION.into()),
+            _ => None,
+        });
+        assert_eq!(installations, [PathBuf::from("mq_home"), PathBuf::from(DEFAULT_INSTALLATION)]);
+        assert_eq!(installations_from(|_| None), [PathBuf::from(DEFAULT_INSTALLATION)]);
+    }
+
+    #[test]
+    fn library_names() {
+        assert_eq!(MQM_LIB, MqmLibrary::Server.file_name());
+        #[cfg(target_os = "macos")]
+        assert_eq!(MQM_LIB, "libmqm_r.dylib");
+        #[cfg(target_os = "linux")]
+        assert_eq!(MQM_LIB, "libmqm_r.so");
+        #[cfg(windows)]
+        assert_eq!(MQM_LIB, "mqm.dll");
+    }
+
+    #[test]
+    fn first_loaded() {
+        let paths = installation_paths(&["a", "b"], &[MqmLibrary::Server, MqmLibrary::Client]);
+        let loaded = load_first(paths, |path| {
+            if path == MqmLibrary::Client.path("b") {
+                Ok(path.to_path_buf())
+            } else {
+                Err(io::Error::from(io::ErrorKind::NotFound))
+            }
+        });
+        assert_eq!(loaded.ok(), Some(MqmLibrary::Client.path("b")));
+    }
+}
@@ -0,0 +1,362 @@
+/*!
+ * Typed completion codes and reasons of MQI function calls
+ *
+ * [`MqCompCode`] and [`MqReason`] wrap the `MQCC_*` and `MQRC_*` values returned by every function call, and
+ * display as the name of the constant rather than the number. [`MqError`] combines them with the name of the
+ * function call that failed.
+ *
+ * [`MqReason::category`] classifies a reason into a [`ReasonCategory`] with a recommended [`ReasonAction`], such as
+ * reconnecting when the connection to the queue manager is lost. The classification is generated by the build
+ * script from the `MQRC_*` constants of the bindings.
+ *
+ * Example
+ * -------
+ *
+ *  Convert the outcome of an `MQCONN` into a `Result`
+ *
+ * ```no_run
+ * use std::ptr::{addr_of_mut, null_mut};
+ * use libmqm_sys::{error::MqError, lib, Mqi};
+ *
+ * fn connect(mq: &impl Mqi) -> Result<lib::MQHCONN, MqError> {
+ *     let mut hconn = lib::MQHC_DEF_HCONN;
+ *     let mut comp_code = lib::MQCC_UNKNOWN;
+ *     let mut reason = lib::MQRC_NONE;
+ *     unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(comp_code), addr_of_mut!(reason)) };
+ *     MqError::check("MQCONN", comp_code, reason).map(|_warning| hconn)
+ * }
+ * ```
+ */
+
+use std::{error::Error, fmt};
+
+use crate::{
+    lib::{self as mqsys, MQLONG},
+    names,
+};
+
+/// The value is not a completion code or reason
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidCode(pub mqsys::MQLONG);
+
+impl fmt::Display for InvalidCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not an MQ completion code or reason", self.0)
+    }
+}
+
+impl Error for InvalidCode {}
+
+/// Completion code of an MQI function call
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MqCompCode {
+    /// `MQCC_OK`
+    Ok,
+    /// `MQCC_WARNING`
+    Warning,
+    /// `MQCC_FAILED`
+    Failed,
+}
+
+impl MqCompCode {
+    /// The `MQCC_*` value of the completion code
+    #[must_use]
+    pub const fn value(self) -> mqsys::MQLONG {
+        match self {
+            Self::Ok => mqsys::MQCC_OK,
+            Self::Warning => mqsys::MQCC_WARNING,
+            Self::Failed => mqsys::MQCC_FAILED,
+        }
+    }
+
+    /// Name of the `MQCC_*` constant
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Ok => "MQCC_OK",
+            Self::Warning => "MQCC_WARNING",
+            Self::Failed => "MQCC_FAILED",
+        }
+    }
+}
+
+impl TryFrom<mqsys::MQLONG> for MqCompCode {
+    type Error = InvalidCode;
+
+    fn try_from(value: mqsys::MQLONG) -> Result<Self, Self::Error> {
+        match value {
+            mqsys::MQCC_OK => Ok(Self::Ok),
+            mqsys::MQCC_WARNING => Ok(Self::Warning),
+            mqsys::MQCC_FAILED => Ok(Self::Failed),
+            _ => Err(InvalidCode(value)),
+        }
+    }
+}
+
+impl From<MqCompCode> for mqsys::MQLONG {
+    fn from(comp_code: MqCompCode) -> Self {
+        comp_code.value()
+    }
+}
+
+impl fmt::Display for MqCompCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Debug for MqCompCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MqCompCode({})", self.name())
+    }
+}
+
+impl Error for MqCompCode {}
+
+/// Reason of an MQI function call, including `MQRC_NONE`
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MqReason(mqsys::MQLONG);
+
+impl MqReason {
+    /// `MQRC_NONE`
+    pub const NONE: Self = Self(mqsys::MQRC_NONE);
+
+    /// The `MQRC_*` value of the reason
+    #[must_use]
+    pub const fn value(self) -> mqsys::MQLONG {
+        self.0
+    }
+
+    /// Name of the `MQRC_*` constant, when it is known
+    #[must_use]
+    pub fn name(self) -> Option<&'static str> {
+        names::name_of("MQRC", self.0)
+    }
+
+    /// Category of the reason, or `None` for `MQRC_NONE`. Reasons that are not known to the bindings are
+    /// [`ReasonCategory::Unknown`].
+    #[must_use]
+    pub fn category(self) -> Option<ReasonCategory> {
+        (self != Self::NONE).then(|| {
+            REASON_CATEGORIES
+                .binary_search_by_key(&self.0, |&(value, _)| value)
+                .map_or(ReasonCategory::Unknown, |index| REASON_CATEGORIES[index].1)
+        })
+    }
+}
+
+impl TryFrom<mqsys::MQLONG> for MqReason {
+    type Error = InvalidCode;
+
+    /// Reasons are never negative
+    fn try_from(value: mqsys::MQLONG) -> Result<Self, Self::Error> {
+        if value < 0 {
+            Err(InvalidCode(value))
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl From<MqReason> for mqsys::MQLONG {
+    fn from(reason: MqReason) -> Self {
+        reason.value()
+    }
+}
+
+impl fmt::Display for MqReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl fmt::Debug for MqReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MqReason({self})")
+    }
+}
+
+impl Error for MqReason {}
+
+/// Action recommended for a [`ReasonCategory`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReasonAction {
+    /// Retry the call after a delay
+    Retry,
+    /// Connect to the queue manager again and repeat the work that was not committed
+    Reconnect,
+    /// Do not retry until an administrator grants access, corrects the configuration or investigates the failure
+    Escalate,
+    /// Do not retry, the application made the call incorrectly
+    Fix,
+    /// The call completed, the reason can be logged and processing continues
+    Continue,
+}
+
+/// Category of an `MQRC_*` reason
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReasonCategory {
+    /// A temporary condition, such as `MQRC_Q_FULL` or `MQRC_RESOURCE_PROBLEM`
+    Transient,
+    /// The connection is lost or is ending, such as `MQRC_CONNECTION_BROKEN`, `MQRC_Q_MGR_QUIESCING` and
+    /// `MQRC_RECONNECT_FAILED`
+    ConnectionLost,
+    /// The call is not permitted, such as `MQRC_NOT_AUTHORIZED` and the TLS errors such as `MQRC_SSL_CONFIG_ERROR`
+    Security,
+    /// The call or its structures are not valid, such as `MQRC_OD_ERROR` or `MQRC_RECONNECT_INCOMPATIBLE`
+    Usage,
+    /// A reason returned with `MQCC_WARNING`, such as `MQRC_TRUNCATED_MSG_ACCEPTED`
+    Warning,
+    /// A failure of the queue manager or its environment, such as `MQRC_UNEXPECTED_ERROR`, or a reason that is not
+    /// known to the bindings
+    Unknown,
+}
+
+impl ReasonCategory {
+    /// Action recommended for reasons in the category
+    #[must_use]
+    pub const fn action(self) -> ReasonAction {
+        match self {
+            Self::Transient => ReasonAction::Retry,
+            Self::ConnectionLost => ReasonAction::Reconnect,
+            Self::Security | Self::Unknown => ReasonAction::Escalate,
+            Self::Usage => ReasonAction::Fix,
+            Self::Warning => ReasonAction::Continue,
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/reasons.rs"));
+
+/// An MQI function call that did not complete with `MQCC_OK`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MqError {
+    /// Name of the function call, such as `MQOPEN`
+    pub verb: &'static str,
+    /// Completion code of the function call
+    pub comp_code: MqCompCode,
+    /// Reason of the function call
+    pub reason: MqReason,
+}
+
+impl MqError {
+    /// Check the completion code and reason returned by the `verb`. Returns the reason of a warning, or `None`
+    /// when the call completed with `MQCC_OK`.
+    ///
+    /// # Errors
+    /// Returns the [`MqError`] when the call failed. A completion code or reason that is not valid, such as
+    /// `MQCC_UNKNOWN` when the call did not complete, is treated as `MQCC_FAILED`.
+    pub fn check(verb: &'static str, comp_code: mqsys::MQLONG, reason: mqsys::MQLONG) -> Result<Option<MqReason>, Self> {
+        match (MqCompCode::try_from(comp_code), MqReason::try_from(reason)) {
+            (Ok(MqCompCode::Ok), _) => Ok(None),
+            (Ok(MqCompCode::Warning), Ok(reason)) => Ok(Some(reason)),
+            (_, reason) => Err(Self {
+                verb,
+                comp_code: MqCompCode::Failed,
+                reason: reason.unwrap_or(MqReason::NONE),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for MqError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { verb, comp_code, reason } = self;
+        write!(f, "{verb}: {comp_code} {reason}")?;
+        if reason.name().is_some() {
+            write!(f, " ({})", reason.value())?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for MqError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comp_code() {
+        assert_eq!(MqCompCode::try_from(mqsys::MQCC_WARNING), Ok(MqCompCode::Warning));
+        assert_eq!(
+            MqCompCode::try_from(mqsys::MQCC_UNKNOWN),
+            Err(InvalidCode(mqsys::MQCC_UNKNOWN))
+        );
+        assert_eq!(mqsys::MQLONG::from(MqCompCode::Failed), mqsys::MQCC_FAILED);
+        assert_eq!(MqCompCode::Failed.to_string(), "MQCC_FAILED");
+        assert_eq!(format!("{:?}", MqCompCode::Ok), "MqCompCode(MQCC_OK)");
+    }
+
+    #[test]
+    fn reason() {
+        assert_eq!(MqReason::try_from(mqsys::MQRC_NONE), Ok(MqReason::NONE));
+        assert_eq!(MqReason::try_from(-1), Err(InvalidCode(-1)));
+        let reason = MqReason::try_from(mqsys::MQRC_NOT_AUTHORIZED).expect("valid reason");
+        assert_eq!(mqsys::MQLONG::from(reason), mqsys::MQRC_NOT_AUTHORIZED);
+        assert_eq!(reason.to_string(), "MQRC_NOT_AUTHORIZED");
+        assert_eq!(format!("{reason:?}"), "MqReason(MQRC_NOT_AUTHORIZED)");
+        assert_eq!(MqReason(999_999).to_string(), "999999");
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(MqError::check("MQCONN", mqsys::MQCC_OK, mqsys::MQRC_NONE), Ok(None));
+        assert_eq!(
+            MqError::check("MQGET", mqsys::MQCC_WARNING, mqsys::MQRC_TRUNCATED_MSG_ACCEPTED),
+            Ok(Some(MqReason(mqsys::MQRC_TRUNCATED_MSG_ACCEPTED)))
+        );
+
+        let Err(error) = MqError::check("MQOPEN", mqsys::MQCC_FAILED, mqsys::MQRC_NOT_AUTHORIZED) else {
+            panic!("MQCC_FAILED is an error");
+        };
+        assert_eq!(error.comp_code, MqCompCode::Failed);
+        assert_eq!(error.to_string(), "MQOPEN: MQCC_FAILED MQRC_NOT_AUTHORIZED (2035)");
+        assert_eq!(
+            error.source().map(ToString::to_string),
+            Some(MqReason(mqsys::MQRC_NOT_AUTHORIZED).to_string())
+        );
+
+        let Err(error) = MqError::check("MQCONN", mqsys::MQCC_UNKNOWN, mqsys::MQRC_NONE) else {
+            panic!("MQCC_UNKNOWN is an error");
+        };
+        assert_eq!((error.comp_code, error.reason), (MqCompCode::Failed, MqReason::NONE));
+    }
+
+    #[test]
+    fn category() {
+        let category = |reason| MqReason(reason).category();
+        assert_eq!(category(mqsys::MQRC_NONE), None);
+        assert_eq!(category(mqsys::MQRC_Q_FULL), Some(ReasonCategory::Transient));
+        assert_eq!(category(mqsys::MQRC_RESOURCE_PROBLEM), Some(ReasonCategory::Transient));
+        assert_eq!(category(mqsys::MQRC_CONNECTION_BROKEN), Some(ReasonCategory::ConnectionLost));
+        assert_eq!(category(mqsys::MQRC_Q_MGR_QUIESCING), Some(ReasonCategory::ConnectionLost));
+        assert_eq!(category(mqsys::MQRC_RECONNECT_FAILED), Some(ReasonCategory::ConnectionLost));
+        assert_eq!(category(mqsys::MQRC_NOT_AUTHORIZED), Some(ReasonCategory::Security));
+        assert_eq!(category(mqsys::MQRC_OD_ERROR), Some(ReasonCategory::Usage));
+        assert_eq!(category(mqsys::MQRC_FORMAT_ERROR), Some(ReasonCategory::Warning));
+        assert_eq!(category(mqsys::MQRC_TRUNCATED_MSG_ACCEPTED), Some(ReasonCategory::Warning));
+        assert_eq!(category(mqsys::MQRC_RECONNECT_INCOMPATIBLE), Some(ReasonCategory::Usage));
+        assert_eq!(category(mqsys::MQRC_RECONNECT_QMID_MISMATCH), Some(ReasonCategory::Usage));
+        assert_eq!(category(mqsys::MQRC_SSL_ALREADY_INITIALIZED), Some(ReasonCategory::Warning));
+        assert_eq!(category(mqsys::MQRC_SSL_CONFIG_ERROR), Some(ReasonCategory::Security));
+        assert_eq!(category(mqsys::MQRC_CONNECTION_ERROR), Some(ReasonCategory::ConnectionLost));
+        assert_eq!(category(mqsys::MQRC_UNEXPECTED_ERROR), Some(ReasonCategory::Unknown));
+        assert_eq!(category(mqsys::MQRC_NO_MSG_AVAILABLE), Some(ReasonCategory::Transient));
+        assert_eq!(category(mqsys::MQRC_CHANNEL_SSL_WARNING), Some(ReasonCategory::Warning));
+        assert_eq!(category(mqsys::MQRC_NOT_OPEN_FOR_OUTPUT), Some(ReasonCategory::Usage));
+        assert_eq!(category(mqsys::MQRC_API_EXIT_ERROR), Some(ReasonCategory::Unknown));
+        assert_eq!(category(99_999), Some(ReasonCategory::Unknown));
+
+        assert_eq!(ReasonCategory::ConnectionLost.action(), ReasonAction::Reconnect);
+        assert_eq!(ReasonCategory::Unknown.action(), ReasonAction::Escalate);
+        assert!(REASON_CATEGORIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
@@ -0,0 +1,969 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+/*!
+ * Fault injection for testing the error handling of code that is generic over [`Mqi`](crate::Mqi)
+ *
+ * The [`FaultyMq`] decorator forwards MQI function calls to an inner implementation, unless a [`Rule`]
+ * matches the call. A matching rule can fail the call with a reason code without calling the inner
+ * implementation, or delay the call. Rules can be limited to a queue or topic name, to the nth matching
+ * call, or to a probability of matching.
+ *
+ * Object names are tracked from the object descriptor passed to `MQOPEN` and `MQPUT1`. Function calls
+ * that take an object handle, such as `MQGET` and `MQPUT`, match the name of the object that was opened.
+ *
+ * Example
+ * -------
+ *
+ *  Break the connection on the 3rd `MQGET`, and fill up the `ORDERS` queue half of the time
+ *
+ * ```
+ * # #[cfg(feature = "mock")] {
+ * use std::time::Duration;
+ * use libmqm_sys::{fault::{FaultyMq, Rule}, lib, mock::MockMq};
+ *
+ * let mq = FaultyMq::new(MockMq::new("QM1"))
+ *     .with_rule(Rule::fail("MQGET", lib::MQRC_CONNECTION_BROKEN).nth(3))
+ *     .with_rule(Rule::fail("MQPUT", lib::MQRC_Q_FULL).object("ORDERS").probability(0.5))
+ *     .with_rule(Rule::delay("MQCMIT", Duration::from_millis(100)));
+ * # }
+ * ```
+ */
+
+use std::{
+    collections::HashMap,
+    ptr,
+    sync::{Mutex, MutexGuard, PoisonError},
+    thread,
+    time::Duration,
+};
+
+use crate::{function, lib as mqsys, mqchar};
+
+/// What a [`Rule`] does to a matching function call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Fail(mqsys::MQLONG),
+    Delay(Duration),
+}
+
+/// Which of the matching function calls a [`Rule`] applies to
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Trigger {
+    Always,
+    Nth(u64),
+    Probability(f64),
+}
+
+/// A rule that injects a fault into the matching MQI function calls
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    verb: &'static str,
+    object: Option<String>,
+    trigger: Trigger,
+    action: Action,
+    matched: u64,
+}
+
+impl Rule {
+    const fn new(verb: &'static str, action: Action) -> Self {
+        Self {
+            verb,
+            object: None,
+            trigger: Trigger::Always,
+            action,
+            matched: 0,
+        }
+    }
+
+    /// Fail calls to the MQI `verb` with `MQCC_FAILED` and the `reason`, without calling the inner implementation
+    #[must_use]
+    pub const fn fail(verb: &'static str, reason: mqsys::MQLONG) -> Self {
+        Self::new(verb, Action::Fail(reason))
+    }
+
+    /// Delay calls to the MQI `verb` by the `duration` before calling the inner implementation
+    #[must_use]
+    pub const fn delay(verb: &'static str, duration: Duration) -> Self {
+        Self::new(verb, Action::Delay(duration))
+    }
+
+    /// Only match calls on the queue or topic with the `name`
+    #[must_use]
+    pub fn object(mut self, name: impl Into<String>) -> Self {
+        self.object = Some(name.into());
+        self
+    }
+
+    /// Only apply to the nth matching call, counting from 1
+    #[must_use]
+    pub const fn nth(mut self, n: u64) -> Self {
+        self.trigger = Trigger::Nth(n);
+        self
+    }
+
+    /// Apply to each matching call with the probability `p`, between 0.0 and 1.0
+    #[must_use]
+    pub const fn probability(mut self, p: f64) -> Self {
+        self.trigger = Trigger::Probability(p);
+        self
+    }
+
+    /// Count a matching call and determine whether the rule applies to it
+    fn applies(&mut self, verb: &str, object: Option<&str>, random: &mut Random) -> bool {
+        if verb != self.verb || self.object.as_deref().is_some_and(|name| Some(name) != object) {
+            return false;
+        }
+        self.matched += 1;
+        match self.trigger {
+            Trigger::Always => true,
+            Trigger::Nth(n) => self.matched == n,
+            Trigger::Probability(p) => random.next_f64() < p,
+        }
+    }
+}
+
+/// Deterministic pseudo random numbers using xorshift64*
+#[derive(Debug)]
+struct Random(u64);
+
+impl Random {
+    #[allow(clippy::cast_precision_loss)] // Only the 53 most significant bits are used
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        (self.0.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    rules: Vec<Rule>,
+    objects: HashMap<(mqsys::MQHCONN, mqsys::MQHOBJ), String>,
+    random: Random,
+    injected: usize,
+}
+
+impl State {
+    /// Apply the rules to a call, returning the total delay and the reason of the first failure
+    fn faults(&mut self, verb: &str, object: Option<&str>) -> (Duration, Option<mqsys::MQLONG>) {
+        let Self {
+            rules, random, injected, ..
+        } = self;
+        let mut delay = Duration::ZERO;
+        let mut failure = None;
+        for rule in rules
+            .iter_mut()
+            .filter_map(|rule| rule.applies(verb, object, random).then_some(&*rule))
+        {
+            *injected += 1;
+            match rule.action {
+                Action::Delay(duration) => delay += duration,
+                Action::Fail(reason) => failure = failure.or(Some(reason)),
+            }
+        }
+        (delay, failure)
+    }
+}
+
+/// Decorator that injects faults into the MQI function calls made to an inner implementation
+#[derive(Debug)]
+pub struct FaultyMq<M> {
+    inner: M,
+    state: Mutex<State>,
+}
+
+impl<M> FaultyMq<M> {
+    const DEFAULT_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+    /// Inject faults into the function calls made to `inner`
+    pub fn new(inner: M) -> Self {
+        Self {
+            inner,
+            state: Mutex::new(State {
+                rules: Vec::new(),
+                objects: HashMap::new(),
+                random: Random(Self::DEFAULT_SEED),
+                injected: 0,
+            }),
+        }
+    }
+
+    /// Add a fault injection rule
+    #[must_use]
+    pub fn with_rule(self, rule: Rule) -> Self {
+        self.add_rule(rule);
+        self
+    }
+
+    /// Seed the pseudo random numbers used by [`Rule::probability`]. A fixed seed is used by default.
+    #[must_use]
+    pub fn with_seed(self, seed: u64) -> Self {
+        // xorshift requires a non-zero state
+        self.state().random = Random(seed.max(1));
+        self
+    }
+
+    /// Add a fault injection rule
+    pub fn add_rule(&self, rule: Rule) {
+        self.state().rules.push(rule);
+    }
+
+    /// Remove all the fault injection rules
+    pub fn clear_rules(&self) {
+        self.state().rules.clear();
+    }
+
+    /// Number of faults that have been injected
+    pub fn injected(&self) -> usize {
+        self.state().injected
+    }
+
+    /// The inner implementation
+    pub const fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Unwrap the inner implementation
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Name of the object opened with the object handle
+    fn object(&self, hconn: mqsys::MQHCONN, hobj: mqsys::MQHOBJ) -> Option<String> {
+        self.state().objects.get(&(hconn, hobj)).cloned()
+    }
+
+    /// Apply the rules to a call. The `call` is made unless a rule fails it.
+    unsafe fn intercept(
+        &self,
+        verb: &'static str,
+        object: Option<&str>,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+        call: impl FnOnce(),
+    ) {
+        let (delay, failure) = self.state().faults(verb, object);
+        if !delay.is_zero() {
+            thread::sleep(delay);
+        }
+        match failure {
+            Some(reason) => unsafe {
+                *pCompCode = mqsys::MQCC_FAILED;
+                *pReason = reason;
+            },
+            None => call(),
+        }
+    }
+}
+
+unsafe fn object_name(pObjDesc: mqsys::PMQVOID) -> Option<String> {
+    let od = pObjDesc.cast::<mqsys::MQOD>();
+    (!od.is_null()).then(|| mqchar::to_string(&unsafe { ptr::addr_of!((*od).ObjectName).read() }))
+}
+
+unsafe fn failed(pCompCode: mqsys::PMQLONG) -> bool {
+    unsafe { *pCompCode == mqsys::MQCC_FAILED }
+}
+
+impl<M: function::Mqi> function::Mqi for FaultyMq<M> {
+    unsafe fn MQCONNX(
+        &self,
+        pQMgrName: mqsys::PMQCHAR,
+        pConnectOpts: mqsys::PMQCNO,
+        pHconn: mqsys::PMQHCONN,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQCONNX", None, pCompCode, pReason, || {
+                self.inner.MQCONNX(pQMgrName, pConnectOpts, pHconn, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQCONN(
+        &self,
+        pQMgrName: mqsys::PMQCHAR,
+        pHconn: mqsys::PMQHCONN,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQCONN", None, pCompCode, pReason, || {
+                self.inner.MQCONN(pQMgrName, pHconn, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQDISC(&self, pHconn: mqsys::PMQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        let hconn = unsafe { pHconn.as_ref() }.copied();
+        unsafe {
+            self.intercept("MQDISC", None, pCompCode, pReason, || {
+                self.inner.MQDISC(pHconn, pCompCode, pReason);
+            });
+            if !failed(pCompCode) {
+                self.state()
+                    .objects
+                    .retain(|&(object_hconn, _), _| Some(object_hconn) != hconn);
+            }
+        }
+    }
+
+    unsafe fn MQOPEN(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pObjDesc: mqsys::PMQVOID,
+        Options: mqsys::MQLONG,
+        pHobj: mqsys::PMQHOBJ,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let object = unsafe { object_name(pObjDesc) };
+        unsafe {
+            self.intercept("MQOPEN", object.as_deref(), pCompCode, pReason, || {
+                self.inner.MQOPEN(Hconn, pObjDesc, Options, pHobj, pCompCode, pReason);
+            });
+            if let (false, Some(object), Some(&hobj)) = (failed(pCompCode), object, pHobj.as_ref()) {
+                self.state().objects.insert((Hconn, hobj), object);
+            }
+        }
+    }
+
+    unsafe fn MQPUT1(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pObjDesc: mqsys::PMQVOID,
+        pMsgDesc: mqsys::PMQVOID,
+        pPutMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let object = unsafe { object_name(pObjDesc) };
+        unsafe {
+            self.intercept("MQPUT1", object.as_deref(), pCompCode, pReason, || {
+                self.inner.MQPUT1(
+                    Hconn,
+                    pObjDesc,
+                    pMsgDesc,
+                    pPutMsgOpts,
+                    BufferLength,
+                    pBuffer,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+
+    unsafe fn MQCLOSE(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pHobj: mqsys::PMQHOBJ,
+        Options: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let hobj = unsafe { pHobj.as_ref() }.copied();
+        let object = hobj.and_then(|hobj| self.object(Hconn, hobj));
+        unsafe {
+            self.intercept("MQCLOSE", object.as_deref(), pCompCode, pReason, || {
+                self.inner.MQCLOSE(Hconn, pHobj, Options, pCompCode, pReason);
+            });
+            if let (false, Some(hobj)) = (failed(pCompCode), hobj) {
+                self.state().objects.remove(&(Hconn, hobj));
+            }
+        }
+    }
+
+    unsafe fn MQCMIT(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        unsafe {
+            self.intercept("MQCMIT", None, pCompCode, pReason, || {
+                self.inner.MQCMIT(Hconn, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQGET(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pGetMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let object = self.object(Hconn, Hobj);
+        unsafe {
+            self.intercept("MQGET", object.as_deref(), pCompCode, pReason, || {
+                self.inner.MQGET(
+                    Hconn,
+                    Hobj,
+                    pMsgDesc,
+                    pGetMsgOpts,
+                    BufferLength,
+                    pBuffer,
+                    pDataLength,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+
+    unsafe fn MQPUT(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pPutMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let object = self.object(Hconn, Hobj);
+        unsafe {
+            self.intercept("MQPUT", object.as_deref(), pCompCode, pReason, || {
+                self.inner
+                    .MQPUT(Hconn, Hobj, pMsgDesc, pPutMsgOpts, BufferLength, pBuffer, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQINQ(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        SelectorCount: mqsys::MQLONG,
+        pSelectors: mqsys::PMQLONG,
+        IntAttrCount: mqsys::MQLONG,
+        pIntAttrs: mqsys::PMQLONG,
+        CharAttrLength: mqsys::MQLONG,
+        pCharAttrs: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let object = self.object(Hconn, Hobj);
+        unsafe {
+            self.intercept("MQINQ", object.as_deref(), pCompCode, pReason, || {
+                self.inner.MQINQ(
+                    Hconn,
+                    Hobj,
+                    SelectorCount,
+                    pSelectors,
+                    IntAttrCount,
+                    pIntAttrs,
+                    CharAttrLength,
+                    pCharAttrs,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+
+    unsafe fn MQSUB(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pSubDesc: mqsys::PMQVOID,
+        pHobj: mqsys::PMQHOBJ,
+        pHsub: mqsys::PMQHOBJ,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQSUB", None, pCompCode, pReason, || {
+                self.inner.MQSUB(Hconn, pSubDesc, pHobj, pHsub, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQSUBRQ(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hsub: mqsys::MQHOBJ,
+        Action: mqsys::MQLONG,
+        pSubRqOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQSUBRQ", None, pCompCode, pReason, || {
+                self.inner.MQSUBRQ(Hconn, Hsub, Action, pSubRqOpts, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQBEGIN(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pBeginOptions: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQBEGIN", None, pCompCode, pReason, || {
+                self.inner.MQBEGIN(Hconn, pBeginOptions, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQBACK(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        unsafe {
+            self.intercept("MQBACK", None, pCompCode, pReason, || {
+                self.inner.MQBACK(Hconn, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQCRTMH(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pCrtMsgHOpts: mqsys::PMQVOID,
+        pHmsg: mqsys::PMQHMSG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQCRTMH", None, pCompCode, pReason, || {
+                self.inner.MQCRTMH(Hconn, pCrtMsgHOpts, pHmsg, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQDLTMH(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pHmsg: mqsys::PMQHMSG,
+        pDltMsgHOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQDLTMH", None, pCompCode, pReason, || {
+                self.inner.MQDLTMH(Hconn, pHmsg, pDltMsgHOpts, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQMHBUF(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pMsgHBufOpts: mqsys::PMQVOID,
+        pName: mqsys::PMQVOID,
+        pMsgDesc: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQMHBUF", None, pCompCode, pReason, || {
+                self.inner.MQMHBUF(
+                    Hconn,
+                    Hmsg,
+                    pMsgHBufOpts,
+                    pName,
+                    pMsgDesc,
+                    BufferLength,
+                    pBuffer,
+                    pDataLength,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+
+    unsafe fn MQBUFMH(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pBufMsgHOpts: mqsys::PMQVOID,
+        pMsgDesc: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQBUFMH", None, pCompCode, pReason, || {
+                self.inner.MQBUFMH(
+                    Hconn,
+                    Hmsg,
+                    pBufMsgHOpts,
+                    pMsgDesc,
+                    BufferLength,
+                    pBuffer,
+                    pDataLength,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+
+    unsafe fn MQCB(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Operation: mqsys::MQLONG,
+        pCallbackDesc: mqsys::PMQVOID,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pGetMsgOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let object = self.object(Hconn, Hobj);
+        unsafe {
+            self.intercept("MQCB", object.as_deref(), pCompCode, pReason, || {
+                self.inner.MQCB(
+                    Hconn,
+                    Operation,
+                    pCallbackDesc,
+                    Hobj,
+                    pMsgDesc,
+                    pGetMsgOpts,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+
+    unsafe fn MQCTL(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Operation: mqsys::MQLONG,
+        pControlOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQCTL", None, pCompCode, pReason, || {
+                self.inner.MQCTL(Hconn, Operation, pControlOpts, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQSET(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        SelectorCount: mqsys::MQLONG,
+        pSelectors: mqsys::PMQLONG,
+        IntAttrCount: mqsys::MQLONG,
+        pIntAttrs: mqsys::PMQLONG,
+        CharAttrLength: mqsys::MQLONG,
+        pCharAttrs: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let object = self.object(Hconn, Hobj);
+        unsafe {
+            self.intercept("MQSET", object.as_deref(), pCompCode, pReason, || {
+                self.inner.MQSET(
+                    Hconn,
+                    Hobj,
+                    SelectorCount,
+                    pSelectors,
+                    IntAttrCount,
+                    pIntAttrs,
+                    CharAttrLength,
+                    pCharAttrs,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+
+    unsafe fn MQSETMP(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pSetPropOpts: mqsys::PMQVOID,
+        pName: mqsys::PMQVOID,
+        pPropDesc: mqsys::PMQVOID,
+        Type: mqsys::MQLONG,
+        ValueLength: mqsys::MQLONG,
+        pValue: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQSETMP", None, pCompCode, pReason, || {
+                self.inner.MQSETMP(
+                    Hconn,
+                    Hmsg,
+                    pSetPropOpts,
+                    pName,
+                    pPropDesc,
+                    Type,
+                    ValueLength,
+                    pValue,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+
+    unsafe fn MQSTAT(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Type: mqsys::MQLONG,
+        pStatus: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQSTAT", None, pCompCode, pReason, || {
+                self.inner.MQSTAT(Hconn, Type, pStatus, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQINQMP(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pInqPropOpts: mqsys::PMQVOID,
+        pName: mqsys::PMQVOID,
+        pPropDesc: mqsys::PMQVOID,
+        pType: mqsys::PMQLONG,
+        ValueLength: mqsys::MQLONG,
+        pValue: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQINQMP", None, pCompCode, pReason, || {
+                self.inner.MQINQMP(
+                    Hconn,
+                    Hmsg,
+                    pInqPropOpts,
+                    pName,
+                    pPropDesc,
+                    pType,
+                    ValueLength,
+                    pValue,
+                    pDataLength,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+
+    unsafe fn MQDLTMP(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pDltPropOpts: mqsys::PMQVOID,
+        pName: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQDLTMP", None, pCompCode, pReason, || {
+                self.inner.MQDLTMP(Hconn, Hmsg, pDltPropOpts, pName, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQXCNVC(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Options: mqsys::MQLONG,
+        SourceCCSID: mqsys::MQLONG,
+        SourceLength: mqsys::MQLONG,
+        pSourceBuffer: mqsys::PMQCHAR,
+        TargetCCSID: mqsys::MQLONG,
+        TargetLength: mqsys::MQLONG,
+        pTargetBuffer: mqsys::PMQCHAR,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQXCNVC", None, pCompCode, pReason, || {
+                self.inner.MQXCNVC(
+                    Hconn,
+                    Options,
+                    SourceCCSID,
+                    SourceLength,
+                    pSourceBuffer,
+                    TargetCCSID,
+                    TargetLength,
+                    pTargetBuffer,
+                    pDataLength,
+                    pCompCode,
+                    pReason,
+                );
+            });
+        }
+    }
+}
+
+#[cfg(feature = "exits")]
+impl<M: function::MqiExit> function::MqiExit for FaultyMq<M> {
+    unsafe fn MQXEP(
+        &self,
+        Hconfig: mqsys::MQHCONFIG,
+        ExitReason: mqsys::MQLONG,
+        Function: mqsys::MQLONG,
+        pEntryPoint: mqsys::PMQFUNC,
+        pExitOpts: mqsys::PMQXEPO,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQXEP", None, pCompCode, pReason, || {
+                self.inner
+                    .MQXEP(Hconfig, ExitReason, Function, pEntryPoint, pExitOpts, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQZEP(
+        &self,
+        Hconfig: mqsys::MQHCONFIG,
+        Function: mqsys::MQLONG,
+        pEntryPoint: mqsys::PMQFUNC,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQZEP", None, pCompCode, pReason, || {
+                self.inner.MQZEP(Hconfig, Function, pEntryPoint, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQXCLWLN(
+        &self,
+        pExitParms: mqsys::PMQWXP,
+        CurrentRecord: mqsys::MQPTR,
+        NextOffset: mqsys::MQLONG,
+        pNextRecord: mqsys::PMQPTR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe {
+            self.intercept("MQXCLWLN", None, pCompCode, pReason, || {
+                self.inner
+                    .MQXCLWLN(pExitParms, CurrentRecord, NextOffset, pNextRecord, pCompCode, pReason);
+            });
+        }
+    }
+
+    unsafe fn MQXDX(
+        &self,
+        pDataConvExitParms: mqsys::PMQDXP,
+        pMsgDesc: mqsys::PMQMD,
+        InBufferLength: mqsys::MQLONG,
+        pInBuffer: mqsys::PMQVOID,
+        OutBufferLength: mqsys::MQLONG,
+        pOutBuffer: mqsys::PMQVOID,
+    ) {
+        unsafe {
+            self.intercept(
+                "MQXDX",
+                None,
+                ptr::addr_of_mut!((*pDataConvExitParms).CompCode),
+                ptr::addr_of_mut!((*pDataConvExitParms).Reason),
+                || {
+                    self.inner.MQXDX(
+                        pDataConvExitParms,
+                        pMsgDesc,
+                        InBufferLength,
+                        pInBuffer,
+                        OutBufferLength,
+                        pOutBuffer,
+                    );
+                },
+            );
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use std::{ptr::addr_of_mut, time::Instant};
+
+    use crate::{
+        fixture::{connect, get, open, put},
+        lib,
+        mock::MockMq,
+        Mqi,
+    };
+
+    use super::*;
+
+    #[test]
+    fn nth_call_fails() {
+        let mq =
+            FaultyMq::new(MockMq::new("QM1").with_queue("Q1")).with_rule(Rule::fail("MQGET", lib::MQRC_CONNECTION_BROKEN).nth(3));
+        let hconn = connect(&mq);
+        let (hobj, _) = open(&mq, hconn, "Q1", lib::MQOO_OUTPUT | lib::MQOO_INPUT_AS_Q_DEF);
+        for _ in 0..4 {
+            assert_eq!(put(&mq, hconn, hobj, b"message", 0, 0), lib::MQRC_NONE);
+        }
+        let reasons: Vec<_> = (0..4).map(|_| get(&mq, hconn, hobj, 16, 0).0).collect();
+        assert_eq!(
+            reasons,
+            [lib::MQRC_NONE, lib::MQRC_NONE, lib::MQRC_CONNECTION_BROKEN, lib::MQRC_NONE]
+        );
+        // The failed call did not reach the queue manager
+        assert_eq!(mq.inner().depth("Q1"), Some(1));
+        assert_eq!(mq.injected(), 1);
+    }
+
+    #[test]
+    fn object_and_probability() {
+        let mq = FaultyMq::new(MockMq::new("QM1").with_queue("Q1").with_queue("Q2"))
+            .with_rule(Rule::fail("MQPUT", lib::MQRC_Q_FULL).object("Q1").probability(1.0))
+            .with_rule(Rule::fail("MQPUT", lib::MQRC_Q_FULL).object("Q2").probability(0.0));
+        let hconn = connect(&mq);
+        let ((q1, _), (q2, _)) = (
+            open(&mq, hconn, "Q1", lib::MQOO_OUTPUT),
+            open(&mq, hconn, "Q2", lib::MQOO_OUTPUT),
+        );
+        assert_eq!(put(&mq, hconn, q1, b"message", 0, 0), lib::MQRC_Q_FULL);
+        assert_eq!(put(&mq, hconn, q2, b"message", 0, 0), lib::MQRC_NONE);
+
+        mq.clear_rules();
+        mq.add_rule(Rule::fail("MQPUT", lib::MQRC_Q_FULL).probability(0.5));
+        let failures = (0..100)
+            .filter(|_| put(&mq, hconn, q2, b"message", 0, 0) == lib::MQRC_Q_FULL)
+            .count();
+        assert!((25..75).contains(&failures), "{failures} failures");
+    }
+
+    #[test]
+    fn delay() {
+        let mq = FaultyMq::new(MockMq::new("QM1")).with_rule(Rule::delay("MQCMIT", Duration::from_millis(20)));
+        let hconn = connect(&mq);
+        let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+        let start = Instant::now();
+        unsafe { mq.MQCMIT(hconn, addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(rc, lib::MQRC_NONE);
+    }
+}
@@ -0,0 +1,133 @@
+//! MQI structures and calls shared by the unit tests
+
+use std::{
+    mem,
+    ptr::{addr_of_mut, null_mut},
+};
+
+use crate::{lib, mqchar::fill, Mqi};
+
+/// `MQOD` of the local queue `name`
+pub fn od(name: &str) -> lib::MQOD {
+    // SAFETY: the MQOD is plain old data so the all zero value is valid
+    let mut od: lib::MQOD = unsafe { mem::zeroed() };
+    od.Version = lib::MQOD_VERSION_1;
+    od.ObjectType = lib::MQOT_Q;
+    fill(&mut od.ObjectName, name.as_bytes());
+    fill(&mut od.ObjectQMgrName, b"");
+    od
+}
+
+/// `MQMD` with the priority of the queue
+pub const fn md() -> lib::MQMD {
+    // SAFETY: the MQMD is plain old data so the all zero value is valid
+    let mut md: lib::MQMD = unsafe { mem::zeroed() };
+    md.Version = lib::MQMD_VERSION_2;
+    md.Priority = lib::MQPRI_PRIORITY_AS_Q_DEF;
+    md
+}
+
+/// `MQGMO` with the `options`, matching on the message and correlation identifiers
+pub const fn gmo(options: lib::MQLONG) -> lib::MQGMO {
+    // SAFETY: the MQGMO is plain old data so the all zero value is valid
+    let mut gmo: lib::MQGMO = unsafe { mem::zeroed() };
+    gmo.Version = lib::MQGMO_VERSION_2;
+    gmo.Options = options;
+    gmo.MatchOptions = lib::MQMO_MATCH_MSG_ID | lib::MQMO_MATCH_CORREL_ID;
+    gmo
+}
+
+/// `MQPMO` with the `options`
+pub const fn pmo(options: lib::MQLONG) -> lib::MQPMO {
+    // SAFETY: the MQPMO is plain old data so the all zero value is valid
+    let mut pmo: lib::MQPMO = unsafe { mem::zeroed() };
+    pmo.Version = lib::MQPMO_VERSION_1;
+    pmo.Options = options;
+    pmo
+}
+
+/// Connect to the default queue manager, returning the connection handle and reason
+pub fn try_connect(mq: &impl Mqi) -> (lib::MQHCONN, lib::MQLONG) {
+    let mut hconn = lib::MQHC_DEF_HCONN;
+    let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+    unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+    (hconn, rc)
+}
+
+/// Connect to the default queue manager, asserting the connection succeeds
+pub fn connect(mq: &impl Mqi) -> lib::MQHCONN {
+    let (hconn, rc) = try_connect(mq);
+    assert_eq!(rc, lib::MQRC_NONE);
+    hconn
+}
+
+/// Open the queue `name`, returning the object handle and reason
+pub fn open(mq: &impl Mqi, hconn: lib::MQHCONN, name: &str, options: lib::MQLONG) -> (lib::MQHOBJ, lib::MQLONG) {
+    let mut od = od(name);
+    let mut hobj = lib::MQHO_NONE;
+    let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+    unsafe {
+        mq.MQOPEN(
+            hconn,
+            addr_of_mut!(od).cast(),
+            options,
+            addr_of_mut!(hobj),
+            addr_of_mut!(cc),
+            addr_of_mut!(rc),
+        );
+    };
+    (hobj, rc)
+}
+
+/// Put the `data` with the `priority`, returning the reason
+pub fn put(
+    mq: &impl Mqi,
+    hconn: lib::MQHCONN,
+    hobj: lib::MQHOBJ,
+    data: &[u8],
+    priority: lib::MQLONG,
+    options: lib::MQLONG,
+) -> lib::MQLONG {
+    let mut md = md();
+    md.Priority = priority;
+    let mut pmo = pmo(options);
+    let mut data = data.to_vec();
+    let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+    unsafe {
+        mq.MQPUT(
+            hconn,
+            hobj,
+            addr_of_mut!(md).cast(),
+            addr_of_mut!(pmo).cast(),
+            data.len().try_into().expect("length to fit MQLONG"),
+            data.as_mut_ptr().cast(),
+            addr_of_mut!(cc),
+            addr_of_mut!(rc),
+        );
+    };
+    rc
+}
+
+/// Get a message into a buffer of `length` bytes, returning the reason and message data
+pub fn get(mq: &impl Mqi, hconn: lib::MQHCONN, hobj: lib::MQHOBJ, length: usize, options: lib::MQLONG) -> (lib::MQLONG, Vec<u8>) {
+    let mut md = md();
+    let mut gmo = gmo(options);
+    let mut buffer = vec![0; length];
+    let mut data_length = 0;
+    let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+    unsafe {
+        mq.MQGET(
+            hconn,
+            hobj,
+            addr_of_mut!(md).cast(),
+            addr_of_mut!(gmo).cast(),
+            length.try_into().expect("length to fit MQLONG"),
+            buffer.as_mut_ptr().cast(),
+            addr_of_mut!(data_length),
+            addr_of_mut!(cc),
+            addr_of_mut!(rc),
+        );
+    };
+    buffer.truncate(usize::try_from(data_length).unwrap_or_default());
+    (rc, buffer)
+}
@@ -1,889 +1,1044 @@
-use crate::lib as mqsys;
+use std::sync::Arc;
 
-/// IBM® MQ Interface (MQI) function calls
-///
-/// References
-/// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=reference-function-calls)
-#[allow(clippy::missing_safety_doc, clippy::too_many_arguments, non_snake_case)]
-pub trait Mqi {
-    /// Connect with extended options to a queue manager
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqconnx-connect-queue-manager-extended)
-    unsafe fn MQCONNX(
-        &self,
-        pQMgrName: mqsys::PMQCHAR,
-        pConnectOpts: mqsys::PMQCNO,
-        pHconn: mqsys::PMQHCONN,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Connect to a queue manager
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqconn-connect-queue-manager)
-    unsafe fn MQCONN(
-        &self,
-        pQMgrName: mqsys::PMQCHAR,
-        pHconn: mqsys::PMQHCONN,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Disconnect from a queue manager
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqdisc-disconnect-queue-manager)
-    unsafe fn MQDISC(&self, pHconn: mqsys::PMQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG);
-
-    /// Opens a queue or topic for access
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqopen-open-object)
-    unsafe fn MQOPEN(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        pObjDesc: mqsys::PMQVOID,
-        Options: mqsys::MQLONG,
-        pHobj: mqsys::PMQHOBJ,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Put a single message on a queue (combines [`MQOPEN`](Mqi::MQOPEN), [`MQPUT`](Mqi::MQPUT), and [`MQCLOSE`](Mqi::MQCLOSE))
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqput1-put-one-message)
-    unsafe fn MQPUT1(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        pObjDesc: mqsys::PMQVOID,
-        pMsgDesc: mqsys::PMQVOID,
-        pPutMsgOpts: mqsys::PMQVOID,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Close a queue or topic
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqclose-close-object)
-    unsafe fn MQCLOSE(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        pHobj: mqsys::PMQHOBJ,
-        Options: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Commit changes
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqcmit-commit-changes)
-    unsafe fn MQCMIT(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG);
-
-    /// Get a message from a queue
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqget-get-message)
-    unsafe fn MQGET(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hobj: mqsys::MQHOBJ,
-        pMsgDesc: mqsys::PMQVOID,
-        pGetMsgOpts: mqsys::PMQVOID,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQVOID,
-        pDataLength: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Put a message on a queue
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqput-put-message)
-    unsafe fn MQPUT(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hobj: mqsys::MQHOBJ,
-        pMsgDesc: mqsys::PMQVOID,
-        pPutMsgOpts: mqsys::PMQVOID,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Inquire about object attributes
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqinq-inquire-object-attributes)
-    unsafe fn MQINQ(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hobj: mqsys::MQHOBJ,
-        SelectorCount: mqsys::MQLONG,
-        pSelectors: mqsys::PMQLONG,
-        IntAttrCount: mqsys::MQLONG,
-        pIntAttrs: mqsys::PMQLONG,
-        CharAttrLength: mqsys::MQLONG,
-        pCharAttrs: mqsys::PMQCHAR,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Create a subscription to a topic
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqsub-register-subscription)
-    unsafe fn MQSUB(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        pSubDesc: mqsys::PMQVOID,
-        pHobj: mqsys::PMQHOBJ,
-        pHsub: mqsys::PMQHOBJ,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Request a retained publication
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqsubrq-subscription-request)
-    unsafe fn MQSUBRQ(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hsub: mqsys::MQHOBJ,
-        Action: mqsys::MQLONG,
-        pSubRqOpts: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Begin a unit of work
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqbegin-begin-unit-work)
-    unsafe fn MQBEGIN(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        pBeginOptions: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Back out changes
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqback-back-out-changes)
-    unsafe fn MQBACK(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG);
-
-    /// Create a message handle
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqcrtmh-create-message-handle)
-    unsafe fn MQCRTMH(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        pCrtMsgHOpts: mqsys::PMQVOID,
-        pHmsg: mqsys::PMQHMSG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Delete a message handle and is the inverse of the [`MQCRTMH`](Mqi::MQCRTMH) call.
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqdltmh-delete-message-handle)
-    unsafe fn MQDLTMH(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        pHmsg: mqsys::PMQHMSG,
-        pDltMsgHOpts: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Convert a message handle into a buffer and is the inverse of the MQBUFMH call
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqmhbuf-convert-message-handle-into-buffer)
-    unsafe fn MQMHBUF(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hmsg: mqsys::MQHMSG,
-        pMsgHBufOpts: mqsys::PMQVOID,
-        pName: mqsys::PMQVOID,
-        pMsgDesc: mqsys::PMQVOID,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQVOID,
-        pDataLength: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Convert a buffer into a message handle and is the inverse of the MQMHBUF call
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqbufmh-convert-buffer-into-message-handle)
-    unsafe fn MQBUFMH(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hmsg: mqsys::MQHMSG,
-        pBufMsgHOpts: mqsys::PMQVOID,
-        pMsgDesc: mqsys::PMQVOID,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQVOID,
-        pDataLength: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Manage callback
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqcb-manage-callback)
-    unsafe fn MQCB(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Operation: mqsys::MQLONG,
-        pCallbackDesc: mqsys::PMQVOID,
-        Hobj: mqsys::MQHOBJ,
-        pMsgDesc: mqsys::PMQVOID,
-        pGetMsgOpts: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Perform controlling actions on callbacks and the object handles opened for a connection
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqctl-control-callbacks)
-    unsafe fn MQCTL(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Operation: mqsys::MQLONG,
-        pControlOpts: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Set object attributes
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqset-set-object-attributes)
-    unsafe fn MQSET(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hobj: mqsys::MQHOBJ,
-        SelectorCount: mqsys::MQLONG,
-        pSelectors: mqsys::PMQLONG,
-        IntAttrCount: mqsys::MQLONG,
-        pIntAttrs: mqsys::PMQLONG,
-        CharAttrLength: mqsys::MQLONG,
-        pCharAttrs: mqsys::PMQCHAR,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Set or modify a property of a message handle
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqsetmp-set-message-property)
-    unsafe fn MQSETMP(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hmsg: mqsys::MQHMSG,
-        pSetPropOpts: mqsys::PMQVOID,
-        pName: mqsys::PMQVOID,
-        pPropDesc: mqsys::PMQVOID,
-        Type: mqsys::MQLONG,
-        ValueLength: mqsys::MQLONG,
-        pValue: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Retrieve status information
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqstat-retrieve-status-information)
-    unsafe fn MQSTAT(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Type: mqsys::MQLONG,
-        pStatus: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Return the value of a property of a message
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqinqmp-inquire-message-property)
-    unsafe fn MQINQMP(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hmsg: mqsys::MQHMSG,
-        pInqPropOpts: mqsys::PMQVOID,
-        pName: mqsys::PMQVOID,
-        pPropDesc: mqsys::PMQVOID,
-        pType: mqsys::PMQLONG,
-        ValueLength: mqsys::MQLONG,
-        pValue: mqsys::PMQVOID,
-        pDataLength: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Delete a property from a message handle and is the inverse of the [`MQSETMP`](Mqi::MQSETMP) call
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqdltmp-delete-message-property)
-    unsafe fn MQDLTMP(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hmsg: mqsys::MQHMSG,
-        pDltPropOpts: mqsys::PMQVOID,
-        pName: mqsys::PMQVOID,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Convert characters from one character set to another
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=exit-mqxcnvc-convert-characters)
-    unsafe fn MQXCNVC(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Options: mqsys::MQLONG,
-        SourceCCSID: mqsys::MQLONG,
-        SourceLength: mqsys::MQLONG,
-        pSourceBuffer: mqsys::PMQCHAR,
-        TargetCCSID: mqsys::MQLONG,
-        TargetLength: mqsys::MQLONG,
-        pTargetBuffer: mqsys::PMQCHAR,
-        pDataLength: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+/// Invoke the macro `$m` with the signatures of the [`Mqi`] function calls appended to its arguments. Each signature
+/// is followed by the name of its function pointer field in the `MQIEP` entry point structure.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! mqi_verbs {
+    ($m:ident!($($args:tt)*)) => {
+        $m!($($args)* {
+            /// Connect with extended options to a queue manager
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqconnx-connect-queue-manager-extended)
+            fn MQCONNX(
+                pQMgrName: $crate::lib::PMQCHAR,
+                pConnectOpts: $crate::lib::PMQCNO,
+                pHconn: $crate::lib::PMQHCONN,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQCONNX_Call;
+            /// Connect to a queue manager
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqconn-connect-queue-manager)
+            fn MQCONN(pQMgrName: $crate::lib::PMQCHAR, pHconn: $crate::lib::PMQHCONN, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG) = MQCONN_Call;
+            /// Disconnect from a queue manager
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqdisc-disconnect-queue-manager)
+            fn MQDISC(pHconn: $crate::lib::PMQHCONN, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG) = MQDISC_Call;
+            /// Opens a queue or topic for access
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqopen-open-object)
+            fn MQOPEN(
+                Hconn: $crate::lib::MQHCONN,
+                pObjDesc: $crate::lib::PMQVOID,
+                Options: $crate::lib::MQLONG,
+                pHobj: $crate::lib::PMQHOBJ,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQOPEN_Call;
+            /// Put a single message on a queue (combines [`MQOPEN`](Mqi::MQOPEN), [`MQPUT`](Mqi::MQPUT), and [`MQCLOSE`](Mqi::MQCLOSE))
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqput1-put-one-message)
+            fn MQPUT1(
+                Hconn: $crate::lib::MQHCONN,
+                pObjDesc: $crate::lib::PMQVOID,
+                pMsgDesc: $crate::lib::PMQVOID,
+                pPutMsgOpts: $crate::lib::PMQVOID,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQVOID,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQPUT1_Call;
+            /// Close a queue or topic
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqclose-close-object)
+            fn MQCLOSE(
+                Hconn: $crate::lib::MQHCONN,
+                pHobj: $crate::lib::PMQHOBJ,
+                Options: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQCLOSE_Call;
+            /// Commit changes
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqcmit-commit-changes)
+            fn MQCMIT(Hconn: $crate::lib::MQHCONN, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG) = MQCMIT_Call;
+            /// Get a message from a queue
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqget-get-message)
+            fn MQGET(
+                Hconn: $crate::lib::MQHCONN,
+                Hobj: $crate::lib::MQHOBJ,
+                pMsgDesc: $crate::lib::PMQVOID,
+                pGetMsgOpts: $crate::lib::PMQVOID,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQVOID,
+                pDataLength: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQGET_Call;
+            /// Put a message on a queue
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqput-put-message)
+            fn MQPUT(
+                Hconn: $crate::lib::MQHCONN,
+                Hobj: $crate::lib::MQHOBJ,
+                pMsgDesc: $crate::lib::PMQVOID,
+                pPutMsgOpts: $crate::lib::PMQVOID,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQVOID,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQPUT_Call;
+            /// Inquire about object attributes
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqinq-inquire-object-attributes)
+            fn MQINQ(
+                Hconn: $crate::lib::MQHCONN,
+                Hobj: $crate::lib::MQHOBJ,
+                SelectorCount: $crate::lib::MQLONG,
+                pSelectors: $crate::lib::PMQLONG,
+                IntAttrCount: $crate::lib::MQLONG,
+                pIntAttrs: $crate::lib::PMQLONG,
+                CharAttrLength: $crate::lib::MQLONG,
+                pCharAttrs: $crate::lib::PMQCHAR,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQINQ_Call;
+            /// Create a subscription to a topic
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqsub-register-subscription)
+            fn MQSUB(
+                Hconn: $crate::lib::MQHCONN,
+                pSubDesc: $crate::lib::PMQVOID,
+                pHobj: $crate::lib::PMQHOBJ,
+                pHsub: $crate::lib::PMQHOBJ,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQSUB_Call;
+            /// Request a retained publication
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqsubrq-subscription-request)
+            fn MQSUBRQ(
+                Hconn: $crate::lib::MQHCONN,
+                Hsub: $crate::lib::MQHOBJ,
+                Action: $crate::lib::MQLONG,
+                pSubRqOpts: $crate::lib::PMQVOID,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQSUBRQ_Call;
+            /// Begin a unit of work
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqbegin-begin-unit-work)
+            fn MQBEGIN(Hconn: $crate::lib::MQHCONN, pBeginOptions: $crate::lib::PMQVOID, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG) = MQBEGIN_Call;
+            /// Back out changes
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqback-back-out-changes)
+            fn MQBACK(Hconn: $crate::lib::MQHCONN, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG) = MQBACK_Call;
+            /// Create a message handle
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqcrtmh-create-message-handle)
+            fn MQCRTMH(
+                Hconn: $crate::lib::MQHCONN,
+                pCrtMsgHOpts: $crate::lib::PMQVOID,
+                pHmsg: $crate::lib::PMQHMSG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQCRTMH_Call;
+            /// Delete a message handle and is the inverse of the [`MQCRTMH`](Mqi::MQCRTMH) call.
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqdltmh-delete-message-handle)
+            fn MQDLTMH(
+                Hconn: $crate::lib::MQHCONN,
+                pHmsg: $crate::lib::PMQHMSG,
+                pDltMsgHOpts: $crate::lib::PMQVOID,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQDLTMH_Call;
+            /// Convert a message handle into a buffer and is the inverse of the MQBUFMH call
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqmhbuf-convert-message-handle-into-buffer)
+            fn MQMHBUF(
+                Hconn: $crate::lib::MQHCONN,
+                Hmsg: $crate::lib::MQHMSG,
+                pMsgHBufOpts: $crate::lib::PMQVOID,
+                pName: $crate::lib::PMQVOID,
+                pMsgDesc: $crate::lib::PMQVOID,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQVOID,
+                pDataLength: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQMHBUF_Call;
+            /// Convert a buffer into a message handle and is the inverse of the MQMHBUF call
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqbufmh-convert-buffer-into-message-handle)
+            fn MQBUFMH(
+                Hconn: $crate::lib::MQHCONN,
+                Hmsg: $crate::lib::MQHMSG,
+                pBufMsgHOpts: $crate::lib::PMQVOID,
+                pMsgDesc: $crate::lib::PMQVOID,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQVOID,
+                pDataLength: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQBUFMH_Call;
+            /// Manage callback
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqcb-manage-callback)
+            fn MQCB(
+                Hconn: $crate::lib::MQHCONN,
+                Operation: $crate::lib::MQLONG,
+                pCallbackDesc: $crate::lib::PMQVOID,
+                Hobj: $crate::lib::MQHOBJ,
+                pMsgDesc: $crate::lib::PMQVOID,
+                pGetMsgOpts: $crate::lib::PMQVOID,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQCB_Call;
+            /// Perform controlling actions on callbacks and the object handles opened for a connection
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqctl-control-callbacks)
+            fn MQCTL(
+                Hconn: $crate::lib::MQHCONN,
+                Operation: $crate::lib::MQLONG,
+                pControlOpts: $crate::lib::PMQVOID,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQCTL_Call;
+            /// Set object attributes
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqset-set-object-attributes)
+            fn MQSET(
+                Hconn: $crate::lib::MQHCONN,
+                Hobj: $crate::lib::MQHOBJ,
+                SelectorCount: $crate::lib::MQLONG,
+                pSelectors: $crate::lib::PMQLONG,
+                IntAttrCount: $crate::lib::MQLONG,
+                pIntAttrs: $crate::lib::PMQLONG,
+                CharAttrLength: $crate::lib::MQLONG,
+                pCharAttrs: $crate::lib::PMQCHAR,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQSET_Call;
+            /// Set or modify a property of a message handle
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqsetmp-set-message-property)
+            fn MQSETMP(
+                Hconn: $crate::lib::MQHCONN,
+                Hmsg: $crate::lib::MQHMSG,
+                pSetPropOpts: $crate::lib::PMQVOID,
+                pName: $crate::lib::PMQVOID,
+                pPropDesc: $crate::lib::PMQVOID,
+                Type: $crate::lib::MQLONG,
+                ValueLength: $crate::lib::MQLONG,
+                pValue: $crate::lib::PMQVOID,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQSETMP_Call;
+            /// Retrieve status information
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqstat-retrieve-status-information)
+            fn MQSTAT(
+                Hconn: $crate::lib::MQHCONN,
+                Type: $crate::lib::MQLONG,
+                pStatus: $crate::lib::PMQVOID,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQSTAT_Call;
+            /// Return the value of a property of a message
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqinqmp-inquire-message-property)
+            fn MQINQMP(
+                Hconn: $crate::lib::MQHCONN,
+                Hmsg: $crate::lib::MQHMSG,
+                pInqPropOpts: $crate::lib::PMQVOID,
+                pName: $crate::lib::PMQVOID,
+                pPropDesc: $crate::lib::PMQVOID,
+                pType: $crate::lib::PMQLONG,
+                ValueLength: $crate::lib::MQLONG,
+                pValue: $crate::lib::PMQVOID,
+                pDataLength: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQINQMP_Call;
+            /// Delete a property from a message handle and is the inverse of the [`MQSETMP`](Mqi::MQSETMP) call
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=calls-mqdltmp-delete-message-property)
+            fn MQDLTMP(
+                Hconn: $crate::lib::MQHCONN,
+                Hmsg: $crate::lib::MQHMSG,
+                pDltPropOpts: $crate::lib::PMQVOID,
+                pName: $crate::lib::PMQVOID,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQDLTMP_Call;
+            /// Convert characters from one character set to another
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=exit-mqxcnvc-convert-characters)
+            fn MQXCNVC(
+                Hconn: $crate::lib::MQHCONN,
+                Options: $crate::lib::MQLONG,
+                SourceCCSID: $crate::lib::MQLONG,
+                SourceLength: $crate::lib::MQLONG,
+                pSourceBuffer: $crate::lib::PMQCHAR,
+                TargetCCSID: $crate::lib::MQLONG,
+                TargetLength: $crate::lib::MQLONG,
+                pTargetBuffer: $crate::lib::PMQCHAR,
+                pDataLength: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQXCNVC_Call;
+        });
+    };
 }
 
-/// IBM® MQ Administration Interface (MQAI) function calls
-///
-/// References
-/// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=reference-mqai-calls)
-#[allow(clippy::missing_safety_doc, clippy::too_many_arguments, non_snake_case)]
-#[cfg(feature = "mqai")]
-pub trait Mqai {
-    /// Create a new bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqcreatebag)
-    unsafe fn mqCreateBag(
-        &self,
-        Options: mqsys::MQLONG,
-        pBag: mqsys::PMQHBAG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Delete all user items from the bag, and resets system items to their initial values
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqclearbag)
-    unsafe fn mqClearBag(&self, Bag: mqsys::MQHBAG, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG);
-
-    /// Delete the specified bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqdeletebag)
-    unsafe fn mqDeleteBag(&self, pBag: mqsys::PMQHBAG, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG);
-
-    /// Remove a message from the specified queue and converts the message data into a data bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqgetbag)
-    unsafe fn mqGetBag(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hobj: mqsys::MQHOBJ,
-        pMsgDesc: mqsys::PMQVOID,
-        pGetMsgOpts: mqsys::PMQVOID,
-        Bag: mqsys::MQHBAG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Convert the contents of the specified bag into a PCF message and sends the message to the specified queue
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqputbag)
-    unsafe fn mqPutBag(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Hobj: mqsys::MQHOBJ,
-        pMsgDesc: mqsys::PMQVOID,
-        pPutMsgOpts: mqsys::PMQVOID,
-        Bag: mqsys::MQHBAG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Reduce the number of user items in a user bag to the specified value, by deleting user items from the end of the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqtruncatebag)
-    unsafe fn mqTruncateBag(
-        &self,
-        Bag: mqsys::MQHBAG,
-        ItemCount: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Add a selector to an administration bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddinquiry)
-    unsafe fn mqAddInquiry(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Remove one or more user items from a bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqdeleteitem)
-    unsafe fn mqDeleteItem(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Add an integer item identified by a user selector to the end of a specified bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddinteger)
-    unsafe fn mqAddInteger(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemValue: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Add an integer filter identified by a user selector to the end of a specified bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddintegerfilter)
-    unsafe fn mqAddIntegerFilter(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemValue: mqsys::MQLONG,
-        Operator: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Add a 64-bit integer item identified by a user selector to the end of a specified bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddinteger64)
-    unsafe fn mqAddInteger64(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemValue: mqsys::MQINT64,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Add a character data item identified by a user selector to the end of a specified bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddstring)
-    unsafe fn mqAddString(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQCHAR,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Add a string filter identified by a user selector to the end of a specified bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddstringfilter)
-    unsafe fn mqAddStringFilter(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQCHAR,
-        Operator: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Add a byte string identified by a user selector to the end of a specified bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddbytestring)
-    unsafe fn mqAddByteString(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQBYTE,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
-
-    /// Add a byte string filter identified by a user selector to the end of a specified bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddbytestringfilter)
-    unsafe fn mqAddByteStringFilter(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQBYTE,
-        Operator: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+/// Invoke the macro `$m` with the signatures of the [`MqiExit`] function calls appended to its arguments. Each
+/// signature is followed by the name of its function pointer field in the `MQIEP` entry point structure.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! mqi_exit_verbs {
+    ($m:ident!($($args:tt)*)) => {
+        $m!($($args)* {
+            /// Register the entry point of an API exit function
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=reference-mqxep-register-entry-point)
+            fn MQXEP(
+                Hconfig: $crate::lib::MQHCONFIG,
+                ExitReason: $crate::lib::MQLONG,
+                Function: $crate::lib::MQLONG,
+                pEntryPoint: $crate::lib::PMQFUNC,
+                pExitOpts: $crate::lib::PMQXEPO,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQXEP_Call;
+            /// Register the entry point of an installable service component function
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=reference-mqzep-add-component-entry-point)
+            fn MQZEP(
+                Hconfig: $crate::lib::MQHCONFIG,
+                Function: $crate::lib::MQLONG,
+                pEntryPoint: $crate::lib::PMQFUNC,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQZEP_Call;
+            /// Navigate the cluster workload records passed to a cluster workload exit
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=exit-mqxclwln-navigate-cluster-workload-records)
+            fn MQXCLWLN(
+                pExitParms: $crate::lib::PMQWXP,
+                CurrentRecord: $crate::lib::MQPTR,
+                NextOffset: $crate::lib::MQLONG,
+                pNextRecord: $crate::lib::PMQPTR,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            ) = MQXCLWLN_Call;
+            /// Convert the application message data of a message. The completion code and reason are returned in the
+            /// `CompCode` and `Reason` fields of the [`MQDXP`](crate::lib::MQDXP).
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/latest?topic=exit-mqconvx-data-conversion)
+            fn MQXDX(
+                pDataConvExitParms: $crate::lib::PMQDXP,
+                pMsgDesc: $crate::lib::PMQMD,
+                InBufferLength: $crate::lib::MQLONG,
+                pInBuffer: $crate::lib::PMQVOID,
+                OutBufferLength: $crate::lib::MQLONG,
+                pOutBuffer: $crate::lib::PMQVOID,
+            ) = MQXDX_Call;
+        });
+    };
+}
 
-    /// Either modify an integer item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetinteger)
-    unsafe fn mqSetInteger(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        ItemValue: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+/// Invoke the macro `$m` with the signatures of the [`Mqai`] function calls appended to its arguments
+#[macro_export]
+#[doc(hidden)]
+macro_rules! mqai_verbs {
+    ($m:ident!($($args:tt)*)) => {
+        $m!($($args)* {
+            /// Create a new bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqcreatebag)
+            fn mqCreateBag(Options: $crate::lib::MQLONG, pBag: $crate::lib::PMQHBAG, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG);
+            /// Delete all user items from the bag, and resets system items to their initial values
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqclearbag)
+            fn mqClearBag(Bag: $crate::lib::MQHBAG, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG);
+            /// Delete the specified bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqdeletebag)
+            fn mqDeleteBag(pBag: $crate::lib::PMQHBAG, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG);
+            /// Remove a message from the specified queue and converts the message data into a data bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqgetbag)
+            fn mqGetBag(
+                Hconn: $crate::lib::MQHCONN,
+                Hobj: $crate::lib::MQHOBJ,
+                pMsgDesc: $crate::lib::PMQVOID,
+                pGetMsgOpts: $crate::lib::PMQVOID,
+                Bag: $crate::lib::MQHBAG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Convert the contents of the specified bag into a PCF message and sends the message to the specified queue
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqputbag)
+            fn mqPutBag(
+                Hconn: $crate::lib::MQHCONN,
+                Hobj: $crate::lib::MQHOBJ,
+                pMsgDesc: $crate::lib::PMQVOID,
+                pPutMsgOpts: $crate::lib::PMQVOID,
+                Bag: $crate::lib::MQHBAG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Reduce the number of user items in a user bag to the specified value, by deleting user items from the end of the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqtruncatebag)
+            fn mqTruncateBag(Bag: $crate::lib::MQHBAG, ItemCount: $crate::lib::MQLONG, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG);
+            /// Add a selector to an administration bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddinquiry)
+            fn mqAddInquiry(Bag: $crate::lib::MQHBAG, Selector: $crate::lib::MQLONG, pCompCode: $crate::lib::PMQLONG, pReason: $crate::lib::PMQLONG);
+            /// Remove one or more user items from a bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqdeleteitem)
+            fn mqDeleteItem(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Add an integer item identified by a user selector to the end of a specified bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddinteger)
+            fn mqAddInteger(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemValue: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Add an integer filter identified by a user selector to the end of a specified bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddintegerfilter)
+            fn mqAddIntegerFilter(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemValue: $crate::lib::MQLONG,
+                Operator: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Add a 64-bit integer item identified by a user selector to the end of a specified bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddinteger64)
+            fn mqAddInteger64(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemValue: $crate::lib::MQINT64,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Add a character data item identified by a user selector to the end of a specified bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddstring)
+            fn mqAddString(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQCHAR,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Add a string filter identified by a user selector to the end of a specified bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddstringfilter)
+            fn mqAddStringFilter(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQCHAR,
+                Operator: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Add a byte string identified by a user selector to the end of a specified bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddbytestring)
+            fn mqAddByteString(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQBYTE,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Add a byte string filter identified by a user selector to the end of a specified bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddbytestringfilter)
+            fn mqAddByteStringFilter(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQBYTE,
+                Operator: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Either modify an integer item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetinteger)
+            fn mqSetInteger(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                ItemValue: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Either modify an integer filter item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetintegerfilter)
+            fn mqSetIntegerFilter(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                ItemValue: $crate::lib::MQLONG,
+                Operator: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Either modify a 64-bit integer item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetinteger64)
+            fn mqSetInteger64(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                ItemValue: $crate::lib::MQINT64,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Nest a bag in another bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddbag)
+            fn mqAddBag(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemValue: $crate::lib::MQHBAG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Either modify a character data item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetstring)
+            fn mqSetString(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQCHAR,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Either modify a string filter item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetstringfilter)
+            fn mqSetStringFilter(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQCHAR,
+                Operator: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Either modify a byte string data item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetbytestring)
+            fn mqSetByteString(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQBYTE,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Either modify a byte string filter item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetbytestringfilter)
+            fn mqSetByteStringFilter(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQBYTE,
+                Operator: $crate::lib::MQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Request the value of an integer data item that is present in the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquireinteger)
+            fn mqInquireInteger(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                pItemValue: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Request the value and operator of an integer filter item that is present in the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquireintegerfilter)
+            fn mqInquireIntegerFilter(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                pItemValue: $crate::lib::PMQLONG,
+                pOperator: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Request the value of a 64-bit integer data item that is present in the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquireinteger64)
+            fn mqInquireInteger64(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                pItemValue: $crate::lib::PMQINT64,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Requests the value of a byte string data item that is present in the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirebytestring)
+            fn mqInquireByteString(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQBYTE,
+                pByteStringLength: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Request the value of a character data item that is present in the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirestring)
+            fn mqInquireString(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQCHAR,
+                pStringLength: $crate::lib::PMQLONG,
+                pCodedCharSetId: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Request the value and operator of a string filter item that is present in the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirestringfilter)
+            fn mqInquireStringFilter(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQCHAR,
+                pStringLength: $crate::lib::PMQLONG,
+                pCodedCharSetId: $crate::lib::PMQLONG,
+                pOperator: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Request the value and operator of a byte string filter item that is present in the bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirebytestringfilter)
+            fn mqInquireByteStringFilter(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQBYTE,
+                pByteStringLength: $crate::lib::PMQLONG,
+                pOperator: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Inquire the value of a bag handle that is present in the bag. The data item can be a user item or a system item
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirebag)
+            fn mqInquireBag(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                pItemValue: $crate::lib::PMQHBAG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Return the number of occurrences of user items, system items, or both, that are stored in a bag with the same specific selector
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqcountitems)
+            fn mqCountItems(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                pItemCount: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Send an administration command message and wait for the reply (if expected)
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqexecute)
+            fn mqExecute(
+                Hconn: $crate::lib::MQHCONN,
+                Command: $crate::lib::MQLONG,
+                OptionsBag: $crate::lib::MQHBAG,
+                AdminBag: $crate::lib::MQHBAG,
+                ResponseBag: $crate::lib::MQHBAG,
+                AdminQ: $crate::lib::MQHOBJ,
+                ResponseQ: $crate::lib::MQHOBJ,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Convert the bag into a PCF message in the supplied buffer
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqbagtobuffer)
+            fn mqBagToBuffer(
+                OptionsBag: $crate::lib::MQHBAG,
+                DataBag: $crate::lib::MQHBAG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQVOID,
+                pDataLength: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Convert the supplied buffer into bag form
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqbuffertobag)
+            fn mqBufferToBag(
+                OptionsBag: $crate::lib::MQHBAG,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQVOID,
+                DataBag: $crate::lib::MQHBAG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Return information about a specified item in a bag
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquireiteminfo)
+            fn mqInquireItemInfo(
+                Bag: $crate::lib::MQHBAG,
+                Selector: $crate::lib::MQLONG,
+                ItemIndex: $crate::lib::MQLONG,
+                pOutSelector: $crate::lib::PMQLONG,
+                pItemType: $crate::lib::PMQLONG,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Pad a null-terminated string with blanks
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqpad)
+            fn mqPad(
+                pString: $crate::lib::PMQCHAR,
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQCHAR,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+            /// Replace the trailing blanks of a blank-padded string with a null
+            ///
+            /// References
+            /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqtrim)
+            fn mqTrim(
+                BufferLength: $crate::lib::MQLONG,
+                pBuffer: $crate::lib::PMQCHAR,
+                pString: $crate::lib::PMQCHAR,
+                pCompCode: $crate::lib::PMQLONG,
+                pReason: $crate::lib::PMQLONG,
+            );
+        });
+    };
+}
 
-    /// Either modify an integer filter item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetintegerfilter)
-    unsafe fn mqSetIntegerFilter(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        ItemValue: mqsys::MQLONG,
-        Operator: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+/// Invoke the macro `$m` with the signatures of the [`Mqi`] and then the [`Mqai`] function calls appended to its
+/// arguments. The [`Mqai`] signatures are empty without the `mqai` feature. The `@exits` form appends the
+/// [`MqiExit`] signatures between them, which are empty without the `exits` feature.
+#[allow(unused_macros)]
+macro_rules! all_verbs {
+    ($m:ident!($($args:tt)*)) => {
+        mqi_verbs!(all_verbs!(@mqai $m($($args)*)));
+    };
+    (@exits $m:ident!($($args:tt)*)) => {
+        mqi_verbs!(all_verbs!(@exit $m($($args)*)));
+    };
+    (@exit $m:ident($($args:tt)*) $mqi:tt) => {
+        #[cfg(feature = "exits")]
+        mqi_exit_verbs!(all_verbs!(@mqai $m($($args)*) $mqi));
+        #[cfg(not(feature = "exits"))]
+        all_verbs!(@mqai $m($($args)*) $mqi {});
+    };
+    (@mqai $m:ident($($args:tt)*) $($verbs:tt)+) => {
+        #[cfg(feature = "mqai")]
+        mqai_verbs!($m!($($args)* $($verbs)+));
+        #[cfg(not(feature = "mqai"))]
+        $m!($($args)* $($verbs)+ {});
+    };
+}
 
-    /// Either modify a 64-bit integer item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetinteger64)
-    unsafe fn mqSetInteger64(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        ItemValue: mqsys::MQINT64,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+/// Declare a function call trait with an `unsafe` method for each signature, documented by the attributes of the
+/// signature
+macro_rules! verb_trait {
+    ($(#[$trait_attr:meta])* $trait:ident, { $($(#[$verb_attr:meta])* fn $verb:ident($($arg:ident: $type:ty),* $(,)?) $(= $field:ident)?;)* }) => {
+        $(#[$trait_attr])*
+        #[allow(clippy::missing_safety_doc, clippy::too_many_arguments, non_snake_case)]
+        pub trait $trait {
+            $(
+                $(#[$verb_attr])*
+                unsafe fn $verb(&self, $($arg: $type),*);
+            )*
+        }
+    };
+}
 
-    /// Nest a bag in another bag
+mqi_verbs!(verb_trait!(
+    /// IBM® MQ Interface (MQI) function calls
     ///
     /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqaddbag)
-    unsafe fn mqAddBag(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemValue: mqsys::MQHBAG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=reference-function-calls)
+    Mqi,
+));
 
-    /// Either modify a character data item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
+#[cfg(feature = "exits")]
+mqi_exit_verbs!(verb_trait!(
+    /// IBM® MQ Interface (MQI) function calls made by exits. These are provided to an exit through its `MQIEP`, or by the
+    /// exit libraries (`mqmzf` and `mqutl`) rather than the MQI libraries.
     ///
     /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetstring)
-    unsafe fn mqSetString(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQCHAR,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=reference-user-exits-api-exits-installable-services)
+    MqiExit,
+));
 
-    /// Either modify a string filter item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
+#[cfg(feature = "mqai")]
+mqai_verbs!(verb_trait!(
+    /// IBM® MQ Administration Interface (MQAI) function calls
     ///
     /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetstringfilter)
-    unsafe fn mqSetStringFilter(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQCHAR,
-        Operator: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=reference-mqai-calls)
+    Mqai,
+));
 
-    /// Either modify a byte string data item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetbytestring)
-    unsafe fn mqSetByteString(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQBYTE,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+/// Implement a function call trait by forwarding each function call to the `$via` expression
+macro_rules! forward_impl {
+    ([$($generics:tt)*] $trait:path, $target:ty, |$this:ident| $via:expr, { $($(#[$verb_attr:meta])* fn $verb:ident($($arg:ident: $type:ty),* $(,)?) $(= $field:ident)?;)* }) => {
+        #[allow(non_snake_case)]
+        impl<$($generics)*> $trait for $target {
+            $(
+                unsafe fn $verb(&self, $($arg: $type),*) {
+                    let $this = self;
+                    unsafe { $via.$verb($($arg),*) }
+                }
+            )*
+        }
+    };
+}
 
-    /// Either modify a byte string filter item that is already present in the bag, or delete all existing occurrences of the specified selector and adds a new occurrence at the end of the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqsetbytestringfilter)
-    unsafe fn mqSetByteStringFilter(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQBYTE,
-        Operator: mqsys::MQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+/// Fail the function call with the `$reason` using the last two arguments of the call. `MQXDX` fails using the
+/// `CompCode` and `Reason` fields of its `MQDXP` argument.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! fail_call {
+    ($reason:expr; $pDataConvExitParms:ident, pMsgDesc, InBufferLength, pInBuffer, OutBufferLength, pOutBuffer) => {
+        unsafe {
+            (*$pDataConvExitParms).CompCode = $crate::lib::MQCC_FAILED;
+            (*$pDataConvExitParms).Reason = $reason;
+        }
+    };
+    ($reason:expr; $pCompCode:ident, $pReason:ident) => {
+        unsafe {
+            *$pCompCode = $crate::lib::MQCC_FAILED;
+            *$pReason = $reason;
+        }
+    };
+    ($reason:expr; $arg:ident, $($args:ident),+) => {
+        $crate::fail_call!($reason; $($args),+)
+    };
+}
 
-    /// Request the value of an integer data item that is present in the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquireinteger)
-    unsafe fn mqInquireInteger(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        pItemValue: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+mqi_verbs!(forward_impl!([T: Mqi + ?Sized] Mqi, &T, |this| (**this),));
+mqi_verbs!(forward_impl!([T: Mqi + ?Sized] Mqi, Box<T>, |this| (**this),));
+mqi_verbs!(forward_impl!([T: Mqi + ?Sized] Mqi, Arc<T>, |this| (**this),));
 
-    /// Request the value and operator of an integer filter item that is present in the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquireintegerfilter)
-    unsafe fn mqInquireIntegerFilter(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        pItemValue: mqsys::PMQLONG,
-        pOperator: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+#[cfg(feature = "exits")]
+mqi_exit_verbs!(forward_impl!([T: MqiExit + ?Sized] MqiExit, &T, |this| (**this),));
+#[cfg(feature = "exits")]
+mqi_exit_verbs!(forward_impl!([T: MqiExit + ?Sized] MqiExit, Box<T>, |this| (**this),));
+#[cfg(feature = "exits")]
+mqi_exit_verbs!(forward_impl!([T: MqiExit + ?Sized] MqiExit, Arc<T>, |this| (**this),));
 
-    /// Request the value of a 64-bit integer data item that is present in the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquireinteger64)
-    unsafe fn mqInquireInteger64(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        pItemValue: mqsys::PMQINT64,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+#[cfg(feature = "mqai")]
+mqai_verbs!(forward_impl!([T: Mqai + ?Sized] Mqai, &T, |this| (**this),));
+#[cfg(feature = "mqai")]
+mqai_verbs!(forward_impl!([T: Mqai + ?Sized] Mqai, Box<T>, |this| (**this),));
+#[cfg(feature = "mqai")]
+mqai_verbs!(forward_impl!([T: Mqai + ?Sized] Mqai, Arc<T>, |this| (**this),));
 
-    /// Requests the value of a byte string data item that is present in the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirebytestring)
-    unsafe fn mqInquireByteString(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQBYTE,
-        pByteStringLength: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
 
-    /// Request the value of a character data item that is present in the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirestring)
-    unsafe fn mqInquireString(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQCHAR,
-        pStringLength: mqsys::PMQLONG,
-        pCodedCharSetId: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+    use super::*;
 
-    /// Request the value and operator of a string filter item that is present in the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirestringfilter)
-    unsafe fn mqInquireStringFilter(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQCHAR,
-        pStringLength: mqsys::PMQLONG,
-        pCodedCharSetId: mqsys::PMQLONG,
-        pOperator: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+    #[cfg(any(
+        feature = "bindgen",
+        all(target_os = "windows", target_arch = "x86_64"),
+        all(target_os = "linux", target_arch = "x86_64"),
+        target_os = "macos"
+    ))]
+    macro_rules! verb_names {
+        ($name:ident, { $($(#[$verb_attr:meta])* fn $verb:ident($($arg:ident: $type:ty),* $(,)?) $(= $field:ident)?;)* }) => {
+            const $name: &[&str] = &[$(stringify!($verb)),*];
+        };
+    }
 
-    /// Request the value and operator of a byte string filter item that is present in the bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirebytestringfilter)
-    unsafe fn mqInquireByteStringFilter(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQBYTE,
-        pByteStringLength: mqsys::PMQLONG,
-        pOperator: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+    /// The bindings the `lib` module is generated from
+    #[cfg(feature = "bindgen")]
+    const BINDINGS: &str = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+    #[cfg(all(not(feature = "bindgen"), target_os = "windows", target_arch = "x86_64"))]
+    const BINDINGS: &str = include_str!("lib/pregen/x86_64-windows-bindings.rs");
+    #[cfg(all(not(feature = "bindgen"), target_os = "linux", target_arch = "x86_64"))]
+    const BINDINGS: &str = include_str!("lib/pregen/x86_64-linux-bindings.rs");
+    #[cfg(all(not(feature = "bindgen"), target_os = "macos"))]
+    const BINDINGS: &str = include_str!("lib/pregen/any-macos-bindings.rs");
 
-    /// Inquire the value of a bag handle that is present in the bag. The data item can be a user item or a system item
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquirebag)
-    unsafe fn mqInquireBag(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        pItemValue: mqsys::PMQHBAG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+    /// Fails if an MQI or MQAI function declared in the `lib` bindings is not a function call of the [`Mqi`],
+    /// [`MqiExit`] or [`Mqai`] traits. The traits are declared from the same signatures as the verb lists, which are
+    /// available whatever features are enabled.
+    #[cfg(any(
+        feature = "bindgen",
+        all(target_os = "windows", target_arch = "x86_64"),
+        all(target_os = "linux", target_arch = "x86_64"),
+        target_os = "macos"
+    ))]
+    #[test]
+    fn traits_cover_bindings() {
+        mqi_verbs!(verb_names!(VERBS,));
+        mqi_exit_verbs!(verb_names!(EXIT_VERBS,));
+        mqai_verbs!(verb_names!(MQAI_VERBS,));
+        let missing: Vec<_> = BINDINGS
+            .lines()
+            .filter_map(|line| line.trim_start().strip_prefix("pub fn "))
+            .filter_map(|declaration| declaration.split_once('('))
+            .map(|(name, _)| name)
+            .filter(|name| (name.starts_with("MQ") && !name.ends_with("_STR")) || name.starts_with("mq"))
+            .filter(|name| !VERBS.contains(name) && !EXIT_VERBS.contains(name) && !MQAI_VERBS.contains(name))
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "{missing:?} missing from the Mqi, MqiExit and Mqai traits"
+        );
+        assert!(BINDINGS.contains("pub fn MQCONNX("), "bindings declare the MQI functions");
+    }
 
-    /// Return the number of occurrences of user items, system items, or both, that are stored in a bag with the same specific selector
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqcountitems)
-    unsafe fn mqCountItems(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        pItemCount: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+    /// Fails to compile if the function call traits are not object safe
+    #[test]
+    fn object_safe() {
+        let _: Option<Arc<dyn Mqi + Send + Sync>> = None;
+        #[cfg(feature = "exits")]
+        let _: Option<Arc<dyn MqiExit + Send + Sync>> = None;
+        #[cfg(feature = "mqai")]
+        let _: Option<Arc<dyn Mqai + Send + Sync>> = None;
+    }
 
-    /// Send an administration command message and wait for the reply (if expected)
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqexecute)
-    unsafe fn mqExecute(
-        &self,
-        Hconn: mqsys::MQHCONN,
-        Command: mqsys::MQLONG,
-        OptionsBag: mqsys::MQHBAG,
-        AdminBag: mqsys::MQHBAG,
-        ResponseBag: mqsys::MQHBAG,
-        AdminQ: mqsys::MQHOBJ,
-        ResponseQ: mqsys::MQHOBJ,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+    #[cfg(feature = "mock")]
+    #[test]
+    fn forward_to_pointee() {
+        use std::ptr::{addr_of_mut, null_mut};
 
-    /// Convert the bag into a PCF message in the supplied buffer
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqbagtobuffer)
-    unsafe fn mqBagToBuffer(
-        &self,
-        OptionsBag: mqsys::MQHBAG,
-        DataBag: mqsys::MQHBAG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQVOID,
-        pDataLength: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+        use crate::{lib as mqsys, mock::MockMq};
 
-    /// Convert the supplied buffer into bag form
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqbuffertobag)
-    unsafe fn mqBufferToBag(
-        &self,
-        OptionsBag: mqsys::MQHBAG,
-        BufferLength: mqsys::MQLONG,
-        pBuffer: mqsys::PMQVOID,
-        DataBag: mqsys::MQHBAG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+        fn connect(mq: &impl Mqi) -> mqsys::MQLONG {
+            let mut hconn = mqsys::MQHC_DEF_HCONN;
+            let (mut cc, mut rc) = (mqsys::MQCC_UNKNOWN, mqsys::MQRC_NONE);
+            unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+            hconn
+        }
 
-    /// Return information about a specified item in a bag
-    ///
-    /// References
-    /// * [IBM documentation](https://www.ibm.com/docs/en/ibm-mq/9.4?topic=calls-mqinquireiteminfo)
-    unsafe fn mqInquireItemInfo(
-        &self,
-        Bag: mqsys::MQHBAG,
-        Selector: mqsys::MQLONG,
-        ItemIndex: mqsys::MQLONG,
-        pOutSelector: mqsys::PMQLONG,
-        pItemType: mqsys::PMQLONG,
-        pCompCode: mqsys::PMQLONG,
-        pReason: mqsys::PMQLONG,
-    );
+        let mq: Arc<dyn Mqi + Send + Sync> = Arc::new(MockMq::new("QM1"));
+        let boxed: Box<dyn Mqi> = Box::new(Arc::clone(&mq));
+        assert_eq!(connect(&mq), 1);
+        assert_eq!(connect(&&*mq), 2);
+        assert_eq!(connect(&boxed), 3);
+    }
 }
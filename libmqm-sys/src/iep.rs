@@ -0,0 +1,430 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+/*!
+ * MQI function calls through an `MQIEP` entry point structure
+ *
+ * Exits and pre-connect exits are passed an `MQIEP` structure by the queue manager, holding function
+ * pointers for the MQI calls they are permitted to make. [`IepMq`] validates the structure and implements
+ * [`Mqi`](crate::Mqi) and [`MqiExit`](crate::MqiExit) by calling through those function pointers, so exits can
+ * use the same code as applications. Calls to a function that is missing from the structure fail with `MQRC_FUNCTION_NOT_SUPPORTED`.
+ *
+ * In the other direction, [`ExportedIep`] populates an `MQIEP` structure with function pointers that call any
+ * [`Mqi`](crate::Mqi) implementation, such as a mock or a decorator, so it can be handed to C code that accepts
+ * an `MQIEP`. A panic in the implementation is caught at the C boundary and fails the call with
+ * `MQRC_UNEXPECTED_ERROR`. The function pointers carry no context, so each export takes one of [`EXPORT_SLOTS`]
+ * slots. Dropping the export waits for any call in flight through its function pointers, then frees the slot for
+ * the next export.
+ *
+ * Example
+ * -------
+ *
+ *  Commit the unit of work from an exit
+ *
+ * ```no_run
+ * use std::ptr::addr_of_mut;
+ * use libmqm_sys::{iep::IepMq, lib, Mqi as _};
+ *
+ * # fn exit(hconn: lib::MQHCONN, piep: lib::PMQIEP) -> Result<(), libmqm_sys::iep::InvalidIep> {
+ * let mq = unsafe { IepMq::from_ptr(piep) }?;
+ * let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+ * unsafe { mq.MQCMIT(hconn, addr_of_mut!(cc), addr_of_mut!(rc)) };
+ * # Ok(())
+ * # }
+ * ```
+ */
+
+use std::{
+    error::Error,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{PoisonError, RwLock, TryLockError},
+};
+
+use crate::{function, lib as mqsys, mqchar};
+
+/// An `MQIEP` entry point structure that is not valid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidIep {
+    /// The pointer to the structure is null
+    Null,
+    /// The `StrucId` is not `MQIEP_STRUC_ID`
+    StrucId([u8; 4]),
+    /// The `Version` is not supported
+    Version(mqsys::MQLONG),
+    /// The `StrucLength` is shorter than the structure version
+    StrucLength(mqsys::MQLONG),
+}
+
+impl fmt::Display for InvalidIep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("MQIEP pointer is null"),
+            Self::StrucId(id) => write!(f, "MQIEP StrucId \"{}\" is not valid", id.escape_ascii()),
+            Self::Version(version) => write!(f, "MQIEP Version {version} is not supported"),
+            Self::StrucLength(length) => write!(f, "MQIEP StrucLength {length} is too short"),
+        }
+    }
+}
+
+impl Error for InvalidIep {}
+
+/// Provides access to the MQI function calls of an `MQIEP` entry point structure
+#[derive(Debug, Clone, Copy)]
+pub struct IepMq<'iep> {
+    iep: &'iep mqsys::MQIEP,
+}
+
+impl<'iep> IepMq<'iep> {
+    /// Validate the `StrucId`, `Version` and `StrucLength` of the entry point structure
+    ///
+    /// # Errors
+    /// Returns [`InvalidIep`] when the structure is not a supported `MQIEP`
+    pub fn new(iep: &'iep mqsys::MQIEP) -> Result<Self, InvalidIep> {
+        let id = mqchar::as_bytes(&iep.StrucId);
+        if id != mqsys::MQIEP_STRUC_ID.to_bytes() {
+            let mut struc_id = [0; 4];
+            struc_id.copy_from_slice(id);
+            return Err(InvalidIep::StrucId(struc_id));
+        }
+        if iep.Version < mqsys::MQIEP_VERSION_1 {
+            return Err(InvalidIep::Version(iep.Version));
+        }
+        if usize::try_from(iep.StrucLength).map_or(true, |length| length < mqsys::MQIEP_LENGTH_1) {
+            return Err(InvalidIep::StrucLength(iep.StrucLength));
+        }
+        Ok(Self { iep })
+    }
+
+    /// Validate the entry point structure passed to an exit
+    ///
+    /// # Safety
+    /// A non-null `iep` must point to an `MQIEP` structure that is valid for the `'iep` lifetime
+    ///
+    /// # Errors
+    /// Returns [`InvalidIep`] when the pointer is null or the structure is not a supported `MQIEP`
+    pub unsafe fn from_ptr(iep: *const mqsys::MQIEP) -> Result<Self, InvalidIep> {
+        unsafe { iep.as_ref() }.ok_or(InvalidIep::Null).and_then(Self::new)
+    }
+
+    /// The entry point structure
+    #[must_use]
+    pub const fn iep(&self) -> &'iep mqsys::MQIEP {
+        self.iep
+    }
+}
+
+macro_rules! iep_impl {
+    ($trait:ident, { $($(#[$verb_attr:meta])* fn $verb:ident($($arg:ident: $type:ty),* $(,)?) = $field:ident;)* }) => {
+        impl function::$trait for IepMq<'_> {
+            $(
+                unsafe fn $verb(&self, $($arg: $type),*) {
+                    match self.iep.$field {
+                        Some(call) => unsafe { call($($arg),*) },
+                        None => fail_call!(mqsys::MQRC_FUNCTION_NOT_SUPPORTED; $($arg),*),
+                    }
+                }
+            )*
+        }
+    };
+}
+
+mqi_verbs!(iep_impl!(Mqi,));
+mqi_exit_verbs!(iep_impl!(MqiExit,));
+
+/// Maximum number of [`ExportedIep`] that can exist at the same time
+pub const EXPORT_SLOTS: usize = 8;
+
+/// Implementation exported in a slot, or `None` when the slot is free
+type Slot = Option<Box<dyn function::Mqi + Send + Sync>>;
+
+/// The export slots. A call through the function pointers holds the read lock of its slot until it returns.
+static EXPORTED: [RwLock<Slot>; EXPORT_SLOTS] = [
+    RwLock::new(None),
+    RwLock::new(None),
+    RwLock::new(None),
+    RwLock::new(None),
+    RwLock::new(None),
+    RwLock::new(None),
+    RwLock::new(None),
+    RwLock::new(None),
+];
+
+const ENTRY_POINTS: [fn() -> mqsys::MQIEP; EXPORT_SLOTS] = [
+    entry_points::<0>,
+    entry_points::<1>,
+    entry_points::<2>,
+    entry_points::<3>,
+    entry_points::<4>,
+    entry_points::<5>,
+    entry_points::<6>,
+    entry_points::<7>,
+];
+
+/// All [`EXPORT_SLOTS`] are in use by other [`ExportedIep`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotsExhausted;
+
+impl fmt::Display for SlotsExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "all {EXPORT_SLOTS} MQIEP export slots are in use")
+    }
+}
+
+impl Error for SlotsExhausted {}
+
+/// An `MQIEP` entry point structure whose function pointers call an [`Mqi`](crate::Mqi) implementation
+///
+/// Dropping the `ExportedIep` waits for calls in flight through its function pointers to return, so it must not be
+/// dropped by the implementation during such a call. The function pointers then fail with
+/// `MQRC_FUNCTION_NOT_SUPPORTED` until the slot is taken by a later export, whose implementation they call, so C code
+/// must not keep them beyond the drop. The exits function pointers (`MQXCLWLN_Call`, `MQXDX_Call`, `MQXEP_Call` and
+/// `MQZEP_Call`) are not populated.
+pub struct ExportedIep {
+    iep: Box<mqsys::MQIEP>,
+    slot: usize,
+}
+
+impl ExportedIep {
+    /// Export the `mq` implementation through a free slot
+    ///
+    /// # Errors
+    /// Returns [`SlotsExhausted`] when every one of the [`EXPORT_SLOTS`] is exported
+    pub fn new(mq: impl function::Mqi + Send + Sync + 'static) -> Result<Self, SlotsExhausted> {
+        let mut mq: Slot = Some(Box::new(mq));
+        let slot = EXPORTED
+            .iter()
+            .position(|exported| {
+                let mut exported = match exported.try_write() {
+                    Ok(exported) => exported,
+                    Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+                    // A call is in flight, so the slot is exported
+                    Err(TryLockError::WouldBlock) => return false,
+                };
+                exported.is_none() && {
+                    *exported = mq.take();
+                    true
+                }
+            })
+            .ok_or(SlotsExhausted)?;
+        Ok(Self {
+            iep: Box::new(ENTRY_POINTS[slot]()),
+            slot,
+        })
+    }
+
+    /// The entry point structure
+    #[must_use]
+    pub fn iep(&self) -> &mqsys::MQIEP {
+        &self.iep
+    }
+
+    /// Pointer to the entry point structure to pass to C code. It is valid until the `ExportedIep` is dropped.
+    #[must_use]
+    pub fn as_ptr(&self) -> mqsys::PMQIEP {
+        std::ptr::from_ref(&*self.iep).cast_mut()
+    }
+}
+
+impl fmt::Debug for ExportedIep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExportedIep")
+            .field("slot", &self.slot)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ExportedIep {
+    fn drop(&mut self) {
+        *EXPORTED[self.slot].write().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+/// Call the implementation exported in the `SLOT`, holding the read lock so the export is not dropped during the
+/// call. Returns `None` when the slot is free, or whether the call returned without panicking.
+#[allow(clippy::significant_drop_tightening)] // The lock is deliberately held until the call returns
+fn call_exported<const SLOT: usize>(call: impl FnOnce(&dyn function::Mqi)) -> Option<bool> {
+    let exported = EXPORTED[SLOT].read().unwrap_or_else(PoisonError::into_inner);
+    let mq = exported.as_deref()?;
+    Some(panic::catch_unwind(AssertUnwindSafe(|| call(mq))).is_ok())
+}
+
+macro_rules! trampolines {
+    ({ $($(#[$verb_attr:meta])* fn $verb:ident($($arg:ident: $type:ty),* $(,)?) = $field:ident;)* }) => {
+        /// C function calls that forward to the implementation exported in the `SLOT`
+        mod trampoline {
+            use super::*;
+
+            $(
+                pub unsafe extern "C" fn $verb<const SLOT: usize>($($arg: $type),*) {
+                    match call_exported::<SLOT>(|mq| unsafe { mq.$verb($($arg),*) }) {
+                        Some(true) => {}
+                        Some(false) => fail_call!(mqsys::MQRC_UNEXPECTED_ERROR; $($arg),*),
+                        None => fail_call!(mqsys::MQRC_FUNCTION_NOT_SUPPORTED; $($arg),*),
+                    }
+                }
+            )*
+        }
+
+        fn entry_points<const SLOT: usize>() -> mqsys::MQIEP {
+            // SAFETY: the MQIEP is plain old data with nullable function pointers
+            let mut iep: mqsys::MQIEP = unsafe { std::mem::zeroed() };
+            iep.StrucId.copy_from_slice(mqchar::from_bytes(mqsys::MQIEP_STRUC_ID.to_bytes()));
+            iep.Version = mqsys::MQIEP_VERSION_1;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            {
+                iep.StrucLength = mqsys::MQIEP_LENGTH_1 as mqsys::MQLONG;
+            }
+            $(iep.$field = Some(trampoline::$verb::<SLOT>);)*
+            iep
+        }
+    };
+}
+
+mqi_verbs!(trampolines!());
+
+#[cfg(test)]
+mod tests {
+    use std::ptr::{addr_of_mut, null_mut};
+
+    use super::*;
+    use crate::Mqi as _;
+
+    unsafe extern "C" fn connect(_: mqsys::PMQCHAR, pHconn: mqsys::PMQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        unsafe {
+            *pHconn = 42;
+            *pCompCode = mqsys::MQCC_OK;
+            *pReason = mqsys::MQRC_NONE;
+        }
+    }
+
+    fn iep() -> mqsys::MQIEP {
+        // SAFETY: the MQIEP is plain old data with nullable function pointers
+        let mut iep: mqsys::MQIEP = unsafe { std::mem::zeroed() };
+        iep.StrucId = [b'I', b'E', b'P', b' '].map(|c| mqsys::MQCHAR::from_ne_bytes([c]));
+        iep.Version = mqsys::MQIEP_VERSION_1;
+        iep.StrucLength = mqsys::MQIEP_LENGTH_1.try_into().expect("MQIEP length");
+        iep.MQCONN_Call = Some(connect);
+        iep
+    }
+
+    #[test]
+    fn call_through() {
+        let iep = iep();
+        let mq = IepMq::new(&iep).expect("valid MQIEP");
+        let mut hconn = mqsys::MQHC_DEF_HCONN;
+        let (mut cc, mut rc) = (mqsys::MQCC_UNKNOWN, mqsys::MQRC_NONE);
+
+        unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!((hconn, cc, rc), (42, mqsys::MQCC_OK, mqsys::MQRC_NONE));
+
+        unsafe { mq.MQCMIT(hconn, addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!((cc, rc), (mqsys::MQCC_FAILED, mqsys::MQRC_FUNCTION_NOT_SUPPORTED));
+    }
+
+    #[test]
+    fn exit_call_through() {
+        use crate::MqiExit as _;
+
+        unsafe extern "C" fn register(
+            _: mqsys::MQHCONFIG,
+            _: mqsys::MQLONG,
+            _: mqsys::MQLONG,
+            _: mqsys::PMQFUNC,
+            _: mqsys::PMQXEPO,
+            pCompCode: mqsys::PMQLONG,
+            pReason: mqsys::PMQLONG,
+        ) {
+            unsafe {
+                *pCompCode = mqsys::MQCC_OK;
+                *pReason = mqsys::MQRC_NONE;
+            }
+        }
+
+        let mut iep = iep();
+        iep.MQXEP_Call = Some(register);
+        let mq = IepMq::new(&iep).expect("valid MQIEP");
+        let (mut cc, mut rc) = (mqsys::MQCC_UNKNOWN, mqsys::MQRC_NONE);
+        unsafe {
+            mq.MQXEP(
+                null_mut(),
+                mqsys::MQXR_CONNECTION,
+                mqsys::MQXF_PUT,
+                null_mut(),
+                null_mut(),
+                addr_of_mut!(cc),
+                addr_of_mut!(rc),
+            );
+        }
+        assert_eq!((cc, rc), (mqsys::MQCC_OK, mqsys::MQRC_NONE));
+
+        // SAFETY: the MQDXP is plain old data
+        let mut dxp: mqsys::MQDXP = unsafe { std::mem::zeroed() };
+        unsafe { mq.MQXDX(addr_of_mut!(dxp), null_mut(), 0, null_mut(), 0, null_mut()) };
+        assert_eq!(
+            (dxp.CompCode, dxp.Reason),
+            (mqsys::MQCC_FAILED, mqsys::MQRC_FUNCTION_NOT_SUPPORTED)
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let mut iep = iep();
+        iep.StrucLength = 8;
+        assert_eq!(IepMq::new(&iep).err(), Some(InvalidIep::StrucLength(8)));
+        iep.Version = 0;
+        assert_eq!(IepMq::new(&iep).err(), Some(InvalidIep::Version(0)));
+        iep.StrucId[0] = mqsys::MQCHAR::from_ne_bytes([b'X']);
+        assert_eq!(IepMq::new(&iep).err(), Some(InvalidIep::StrucId(*b"XEP ")));
+        assert_eq!(unsafe { IepMq::from_ptr(std::ptr::null()) }.err(), Some(InvalidIep::Null));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn export_round_trip() {
+        use crate::mock::MockMq;
+
+        struct Panicking;
+
+        fn panics(_: &Panicking) -> &MockMq {
+            panic!("Mqi implementation panicked")
+        }
+
+        mqi_verbs!(forward_impl!([] function::Mqi, Panicking, |this| panics(this),));
+
+        let exported = ExportedIep::new(MockMq::new("QM1")).expect("free slot");
+        let mq = unsafe { IepMq::from_ptr(exported.as_ptr()) }.expect("valid MQIEP");
+        let mut hconn = mqsys::MQHC_DEF_HCONN;
+        let (mut cc, mut rc) = (mqsys::MQCC_UNKNOWN, mqsys::MQRC_NONE);
+        unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!((cc, rc), (mqsys::MQCC_OK, mqsys::MQRC_NONE));
+
+        let iep = *exported.iep();
+        drop(exported);
+        let mq = IepMq::new(&iep).expect("valid MQIEP");
+        unsafe { mq.MQDISC(addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!((cc, rc), (mqsys::MQCC_FAILED, mqsys::MQRC_FUNCTION_NOT_SUPPORTED));
+
+        let exported = ExportedIep::new(Panicking).expect("free slot");
+        let mq = IepMq::new(exported.iep()).expect("valid MQIEP");
+        unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!((cc, rc), (mqsys::MQCC_FAILED, mqsys::MQRC_UNEXPECTED_ERROR));
+
+        drop(exported);
+
+        // Dropped exports free their slots
+        for _ in 0..=EXPORT_SLOTS * 2 {
+            let exported = ExportedIep::new(MockMq::new("QM1")).expect("free slot");
+            let mq = IepMq::new(exported.iep()).expect("valid MQIEP");
+            unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+            assert_eq!((cc, rc), (mqsys::MQCC_OK, mqsys::MQRC_NONE));
+        }
+        let all: Vec<_> = (0..EXPORT_SLOTS)
+            .map(|_| ExportedIep::new(MockMq::new("QM1")).expect("free slot"))
+            .collect();
+        assert_eq!(ExportedIep::new(MockMq::new("QM1")).err(), Some(SlotsExhausted));
+        drop(all);
+        assert!(ExportedIep::new(MockMq::new("QM1")).is_ok());
+    }
+}
@@ -117,12 +117,63 @@ pub mod version {
 
 mod default;
 
+#[cfg(any(
+    feature = "mock",
+    feature = "tracing",
+    feature = "fault",
+    feature = "exits",
+    feature = "pcf"
+))]
+mod mqchar;
+
+#[cfg(any(feature = "mock", feature = "record"))]
+mod structs;
+
+#[cfg(all(test, feature = "mock"))]
+mod fixture;
+
+#[macro_use]
 mod function;
 #[doc(inline)]
 pub use function::*;
 
+#[cfg(any(feature = "dlopen2", feature = "libloading"))]
+pub mod discovery;
+
 #[cfg(feature = "dlopen2")]
 pub mod dlopen2;
 
+#[cfg(feature = "libloading")]
+pub mod libloading;
+
 #[cfg(feature = "link_api")]
 pub mod link;
+
+#[cfg(any(feature = "link_api", feature = "dlopen2", feature = "libloading"))]
+pub mod backend;
+
+#[cfg(feature = "exits")]
+pub mod iep;
+
+pub mod error;
+
+pub mod names;
+
+pub mod ccsid;
+
+#[cfg(feature = "pcf")]
+pub mod pcf;
+
+pub mod runtime_version;
+
+#[cfg(feature = "mock")]
+pub mod mock;
+
+#[cfg(feature = "tracing")]
+pub mod trace;
+
+#[cfg(feature = "record")]
+pub mod record;
+
+#[cfg(feature = "fault")]
+pub mod fault;
@@ -0,0 +1,209 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+/*!
+ * Dynamic loading of the MQI library using libloading
+ *
+ * [`LibloadingMq`] resolves the same MQI and MQAI function calls as the [`dlopen2`](crate::dlopen2) wrapper,
+ * for applications that already depend on `libloading`. Loading fails when any function call, other than the
+ * [`MqiExit`](crate::MqiExit) function calls, is missing from the library. The library names and installation
+ * discovery are shared with the [`dlopen2`](crate::dlopen2) wrapper through the [`discovery`](crate::discovery) module.
+ *
+ * Example
+ * -------
+ *
+ *  Dynamically load the `libmqm_r` library and issue an `MQCONN`
+ *
+ * ```no_run
+ * use std::ptr::addr_of_mut;
+ * use libmqm_sys::{lib, libloading::LibloadingMq, Mqi as _};
+ *
+ * # fn main() -> Result<(), libloading::Error> {
+ * let mq = unsafe { LibloadingMq::load_mqm_default() }?;
+ *
+ * let mut hconn = lib::MQHC_DEF_HCONN;
+ * let mut comp_code = lib::MQCC_UNKNOWN;
+ * let mut reason = lib::MQRC_NONE;
+ * let mut qmgr: [lib::MQCHAR; 48] = [32; 48]; // All spaces
+ * unsafe {
+ *    mq.MQCONN(
+ *      addr_of_mut!(qmgr).cast(),
+ *      addr_of_mut!(hconn),
+ *      addr_of_mut!(comp_code),
+ *      addr_of_mut!(reason),
+ *    );
+ * }
+ * # Ok(())
+ * # }
+ * ```
+ */
+
+use std::{ffi::OsStr, fmt, path::Path};
+
+use libloading::Library;
+
+use crate::{
+    discovery::{self, MqmLibrary},
+    function,
+};
+
+#[doc(inline)]
+pub use crate::discovery::MQM_LIB;
+
+/// Failure to load the MQ library using libloading from any of the attempted paths
+pub type DiscoveryError = discovery::DiscoveryError<libloading::Error>;
+
+macro_rules! libloading_mq {
+    (
+        { $($(#[$verb_attr:meta])* fn $verb:ident($($arg:ident: $type:ty),* $(,)?) = $field:ident;)* }
+        { $($(#[$exit_verb_attr:meta])* fn $exit_verb:ident($($exit_arg:ident: $exit_type:ty),* $(,)?) = $exit_field:ident;)* }
+        { $($(#[$ai_verb_attr:meta])* fn $ai_verb:ident($($ai_arg:ident: $ai_type:ty),* $(,)?);)* }
+    ) => {
+        /// The MQI and MQAI function calls of an MQ library loaded using `libloading`
+        pub struct LibloadingMq {
+            $($verb: unsafe extern "C" fn($($arg: $type),*),)*
+            $($exit_verb: Option<unsafe extern "C" fn($($exit_arg: $exit_type),*)>,)*
+            $($ai_verb: unsafe extern "C" fn($($ai_arg: $ai_type),*),)*
+            // Keeps the function pointers valid
+            _library: Library,
+        }
+
+        impl LibloadingMq {
+            /// Resolve the MQI and MQAI function calls from the `library`
+            ///
+            /// # Safety
+            /// Loading the dynamic library is inherently unsafe
+            ///
+            /// # Errors
+            /// Will return `Err` if the library could not be loaded or is missing a function call
+            pub unsafe fn from_library(library: Library) -> Result<Self, libloading::Error> {
+                unsafe {
+                    Ok(Self {
+                        $($verb: *library.get(concat!(stringify!($verb), "\0").as_bytes())?,)*
+                        $($exit_verb: library.get(concat!(stringify!($exit_verb), "\0").as_bytes()).ok().map(|symbol| *symbol),)*
+                        $($ai_verb: *library.get(concat!(stringify!($ai_verb), "\0").as_bytes())?,)*
+                        _library: library,
+                    })
+                }
+            }
+        }
+
+        impl function::Mqi for LibloadingMq {
+            $(
+                unsafe fn $verb(&self, $($arg: $type),*) {
+                    unsafe { (self.$verb)($($arg),*) }
+                }
+            )*
+        }
+
+        #[cfg(feature = "exits")]
+        impl function::MqiExit for LibloadingMq {
+            $(
+                unsafe fn $exit_verb(&self, $($exit_arg: $exit_type),*) {
+                    match self.$exit_verb {
+                        Some(call) => unsafe { call($($exit_arg),*) },
+                        None => fail_call!(crate::lib::MQRC_FUNCTION_NOT_SUPPORTED; $($exit_arg),*),
+                    }
+                }
+            )*
+        }
+
+        #[cfg(feature = "mqai")]
+        impl function::Mqai for LibloadingMq {
+            $(
+                unsafe fn $ai_verb(&self, $($ai_arg: $ai_type),*) {
+                    unsafe { (self.$ai_verb)($($ai_arg),*) }
+                }
+            )*
+        }
+    };
+}
+
+all_verbs!(@exits libloading_mq!());
+
+impl LibloadingMq {
+    /// Loads the MQ library from the `path`
+    ///
+    /// # Safety
+    /// Loading the dynamic library is inherently unsafe
+    ///
+    /// # Errors
+    /// Will return `Err` if the library could not be loaded or is missing a function call
+    pub unsafe fn load(path: impl AsRef<OsStr>) -> Result<Self, libloading::Error> {
+        unsafe { Self::from_library(Library::new(path)?) }
+    }
+
+    /// Loads the MQM library using the platform dependent search rules
+    ///
+    /// # Safety
+    /// Loading the dynamic library is inherently unsafe
+    ///
+    /// # Errors
+    /// Will return `Err` if the library could not be loaded or is missing a function call
+    pub unsafe fn load_mqm_default() -> Result<Self, libloading::Error> {
+        unsafe { Self::load(MQM_LIB) }
+    }
+
+    /// Loads the first of the `libraries` found in the `installations`, trying each library in an installation
+    /// before the next installation
+    ///
+    /// # Safety
+    /// Loading the dynamic library is inherently unsafe
+    ///
+    /// # Errors
+    /// Will return a [`DiscoveryError`] holding the path and error of every failed attempt
+    pub unsafe fn load_mqm_installation<P: AsRef<Path>>(
+        installations: &[P],
+        libraries: &[MqmLibrary],
+    ) -> Result<Self, DiscoveryError> {
+        discovery::load_first(discovery::installation_paths(installations, libraries), |path| unsafe {
+            Self::load(path)
+        })
+    }
+
+    /// Loads the first of the `libraries` found in the [`installations`](discovery::installations), falling back
+    /// to the platform dependent search rules
+    ///
+    /// # Safety
+    /// Loading the dynamic library is inherently unsafe
+    ///
+    /// # Errors
+    /// Will return a [`DiscoveryError`] holding the path and error of every failed attempt
+    pub unsafe fn load_mqm_discover(libraries: &[MqmLibrary]) -> Result<Self, DiscoveryError> {
+        discovery::load_first(discovery::discovery_paths(libraries), |path| unsafe { Self::load(path) })
+    }
+}
+
+impl fmt::Debug for LibloadingMq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LibloadingMq").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_library() {
+        assert!(unsafe { LibloadingMq::load("no_such_mq_library") }.is_err());
+    }
+
+    #[test]
+    fn report_attempts() {
+        let error = unsafe { LibloadingMq::load_mqm_installation(&["no_such_mq"], &[MqmLibrary::Server, MqmLibrary::Client]) }
+            .expect_err("no MQ installation");
+        let paths: Vec<_> = error.attempts.iter().map(|attempt| attempt.path.clone()).collect();
+        assert_eq!(
+            paths,
+            [MqmLibrary::Server.path("no_such_mq"), MqmLibrary::Client.path("no_such_mq")]
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn missing_function() {
+        let error = unsafe { LibloadingMq::load("libc.so.6") }.expect_err("C library has no MQI function calls");
+        assert!(error.to_string().contains("MQ"), "{error}");
+    }
+}
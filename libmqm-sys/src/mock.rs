@@ -0,0 +1,1607 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+/*!
+ * In-memory mock of a queue manager for testing code that is generic over [`Mqi`](crate::Mqi)
+ *
+ * The [`MockMq`] does not require the MQ library at compile time or run-time. It supports named
+ * local queues with priority or FIFO delivery, syncpoint, browse and the basic attribute inquiries.
+ * Model queues create temporary dynamic queues, and a command server can answer the requests put to a
+ * command queue, such as the PCF commands sent by a `pcf::PcfClient`.
+ * Function calls that are not supported complete with `MQRC_FUNCTION_NOT_SUPPORTED`.
+ *
+ * Messages are only put by the caller, so an `MQGET` never waits: `MQGMO_WAIT` and the `WaitInterval` are ignored,
+ * and the call completes with `MQRC_NO_MSG_AVAILABLE` as soon as no message matches.
+ *
+ * Example
+ * -------
+ *
+ *  Connect to the mock queue manager and attempt to open a queue that does not exist
+ *
+ * ```
+ * use std::ptr::addr_of_mut;
+ * use libmqm_sys::{lib, mock::MockMq, Mqi as _};
+ *
+ * let mq = MockMq::new("QM1").with_queue("DEV.QUEUE.1");
+ *
+ * let mut hconn = lib::MQHC_DEF_HCONN;
+ * let mut comp_code = lib::MQCC_UNKNOWN;
+ * let mut reason = lib::MQRC_NONE;
+ * let mut qmgr: [lib::MQCHAR; 48] = [32; 48]; // All spaces
+ * unsafe {
+ *    mq.MQCONN(
+ *      addr_of_mut!(qmgr).cast(),
+ *      addr_of_mut!(hconn),
+ *      addr_of_mut!(comp_code),
+ *      addr_of_mut!(reason),
+ *    );
+ * }
+ * assert_eq!(comp_code, lib::MQCC_OK);
+ * assert_eq!(mq.depth("DEV.QUEUE.1"), Some(0));
+ * ```
+ */
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fmt, ptr, slice,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use crate::{
+    function, lib as mqsys,
+    mqchar::{fill, padded, to_string as mq_str},
+    structs,
+};
+
+/// Outcome of a mock function call. `Ok` holds the reason code of a warning (or `MQRC_NONE`), `Err` holds the
+/// reason code of a failure.
+type Outcome = Result<mqsys::MQLONG, mqsys::MQLONG>;
+
+/// Definition of a local queue hosted by the [`MockMq`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueDef {
+    /// Maximum number of messages, including uncommitted messages, allowed on the queue
+    pub max_depth: mqsys::MQLONG,
+    /// Priority assigned to messages put with `MQPRI_PRIORITY_AS_Q_DEF`
+    pub default_priority: mqsys::MQLONG,
+    /// Message delivery sequence, either `MQMDS_PRIORITY` or `MQMDS_FIFO`
+    pub delivery_sequence: mqsys::MQLONG,
+}
+
+impl Default for QueueDef {
+    fn default() -> Self {
+        Self {
+            max_depth: 5000,
+            default_priority: 0,
+            delivery_sequence: mqsys::MQMDS_PRIORITY,
+        }
+    }
+}
+
+/// Visibility of a message with respect to units of work
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MsgState {
+    Available,
+    PendingPut(mqsys::MQHCONN),
+    PendingGet(mqsys::MQHCONN),
+}
+
+#[derive(Debug, Clone)]
+struct Message {
+    seq: u64,
+    md: mqsys::MQMD,
+    data: Vec<u8>,
+    state: MsgState,
+}
+
+#[derive(Debug, Default)]
+struct Queue {
+    def: QueueDef,
+    messages: VecDeque<Message>,
+}
+
+impl Queue {
+    /// Insert the message in delivery order. Messages are ordered by their put sequence and, for
+    /// `MQMDS_PRIORITY` queues, by priority first.
+    fn insert(&mut self, message: Message) {
+        let by_priority = self.def.delivery_sequence == mqsys::MQMDS_PRIORITY;
+        let position = self
+            .messages
+            .iter()
+            .position(|existing| {
+                if by_priority && existing.md.Priority != message.md.Priority {
+                    existing.md.Priority < message.md.Priority
+                } else {
+                    existing.seq > message.seq
+                }
+            })
+            .unwrap_or(self.messages.len());
+        self.messages.insert(position, message);
+    }
+}
+
+#[derive(Debug)]
+struct Handle {
+    /// Name of the opened queue, `None` when the queue manager object is opened
+    queue: Option<String>,
+    options: mqsys::MQLONG,
+    /// Put sequence of the message under the browse cursor
+    browse_cursor: Option<u64>,
+    /// The queue is a temporary dynamic queue created by this open, and is deleted when the handle is closed
+    delete_on_close: bool,
+}
+
+/// Function that answers the data of a request with the data of the replies
+type Responder = dyn Fn(&[u8]) -> Vec<Vec<u8>> + Send + Sync;
+
+/// Answers the requests put to a command queue
+struct CommandServer(Arc<Responder>);
+
+impl fmt::Debug for CommandServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CommandServer")
+    }
+}
+
+/// Outcome of a successful put
+enum Put {
+    /// The message was queued with the `MsgId`
+    Queued(mqsys::MQBYTE24),
+    /// The message is a request to the command server, which is answered after the state is unlocked
+    Request(Arc<Responder>, Box<mqsys::MQMD>, Vec<u8>),
+}
+
+#[derive(Debug, Default)]
+struct Connection {
+    handles: HashMap<mqsys::MQHOBJ, Handle>,
+    next_hobj: mqsys::MQHOBJ,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    queues: BTreeMap<String, Queue>,
+    /// Definitions of the model queues that dynamic queues are created from
+    models: BTreeMap<String, QueueDef>,
+    command_servers: BTreeMap<String, CommandServer>,
+    connections: HashMap<mqsys::MQHCONN, Connection>,
+    next_hconn: mqsys::MQHCONN,
+    next_seq: u64,
+}
+
+/// Access options that permit an `MQOPEN` of a queue
+const QUEUE_ACCESS: mqsys::MQLONG = mqsys::MQOO_INPUT_AS_Q_DEF
+    | mqsys::MQOO_INPUT_SHARED
+    | mqsys::MQOO_INPUT_EXCLUSIVE
+    | mqsys::MQOO_BROWSE
+    | mqsys::MQOO_OUTPUT
+    | mqsys::MQOO_INQUIRE;
+
+/// Open options that permit an `MQGET`
+const INPUT: mqsys::MQLONG = mqsys::MQOO_INPUT_AS_Q_DEF | mqsys::MQOO_INPUT_SHARED | mqsys::MQOO_INPUT_EXCLUSIVE;
+
+/// Message returned from the queue by an `MQGET`
+struct Retrieved {
+    md: mqsys::MQMD,
+    data: Vec<u8>,
+    reason: mqsys::MQLONG,
+}
+
+impl State {
+    fn connection(&mut self, hconn: mqsys::MQHCONN) -> Result<&mut Connection, mqsys::MQLONG> {
+        self.connections.get_mut(&hconn).ok_or(mqsys::MQRC_HCONN_ERROR)
+    }
+
+    fn handle(&mut self, hconn: mqsys::MQHCONN, hobj: mqsys::MQHOBJ) -> Result<&mut Handle, mqsys::MQLONG> {
+        self.connection(hconn)?.handles.get_mut(&hobj).ok_or(mqsys::MQRC_HOBJ_ERROR)
+    }
+
+    fn connect(&mut self) -> mqsys::MQHCONN {
+        let hconn = self.next_hconn;
+        self.next_hconn += 1;
+        self.connections.insert(
+            hconn,
+            Connection {
+                next_hobj: 1,
+                ..Connection::default()
+            },
+        );
+        hconn
+    }
+
+    fn disconnect(&mut self, hconn: mqsys::MQHCONN) -> Result<(), mqsys::MQLONG> {
+        let connection = self.connections.remove(&hconn).ok_or(mqsys::MQRC_HCONN_ERROR)?;
+        self.resolve(hconn, true);
+        for handle in connection.handles.into_values() {
+            self.release(handle);
+        }
+        Ok(())
+    }
+
+    /// Unique message identifier for the put sequence
+    fn message_id(seq: u64) -> mqsys::MQBYTE24 {
+        let mut msg_id = [0; 24];
+        msg_id[..4].copy_from_slice(b"AMQ ");
+        msg_id[16..].copy_from_slice(&seq.to_be_bytes());
+        msg_id
+    }
+
+    /// Name of a new dynamic queue. A trailing `*` of the `DynamicQName` is replaced by a unique suffix.
+    fn dynamic_name(&mut self, dynamic_name: &str) -> Result<String, mqsys::MQLONG> {
+        let name = match dynamic_name.strip_suffix('*') {
+            Some(prefix) => {
+                let seq = self.next_seq;
+                self.next_seq += 1;
+                format!("{prefix}{seq:016X}").chars().take(mqsys::MQ_Q_NAME_LENGTH).collect()
+            }
+            None => dynamic_name.to_string(),
+        };
+        if name.is_empty() || name.contains('*') {
+            Err(mqsys::MQRC_OBJECT_NAME_ERROR)
+        } else if self.queues.contains_key(&name) {
+            Err(mqsys::MQRC_OBJECT_ALREADY_EXISTS)
+        } else {
+            Ok(name)
+        }
+    }
+
+    /// Open an object, returning the object handle and the name of the dynamic queue created from a model queue
+    fn open(
+        &mut self,
+        qmgr_name: &str,
+        hconn: mqsys::MQHCONN,
+        object_type: mqsys::MQLONG,
+        object: (String, String, String),
+        options: mqsys::MQLONG,
+    ) -> Result<(mqsys::MQHOBJ, Option<String>), mqsys::MQLONG> {
+        let (object_name, object_qmgr, dynamic_name) = object;
+        self.connection(hconn)?;
+        let (queue, dynamic) = match object_type {
+            mqsys::MQOT_Q => {
+                if !object_qmgr.is_empty() && object_qmgr != qmgr_name {
+                    return Err(mqsys::MQRC_UNKNOWN_OBJECT_Q_MGR);
+                }
+                let model = self.models.get(&object_name).copied();
+                if model.is_none() && !self.queues.contains_key(&object_name) {
+                    return Err(mqsys::MQRC_UNKNOWN_OBJECT_NAME);
+                }
+                if options & QUEUE_ACCESS == 0 {
+                    return Err(mqsys::MQRC_OPTIONS_ERROR);
+                }
+                match model {
+                    Some(def) => {
+                        let name = self.dynamic_name(&dynamic_name)?;
+                        self.queues.insert(
+                            name.clone(),
+                            Queue {
+                                def,
+                                messages: VecDeque::new(),
+                            },
+                        );
+                        (Some(name), true)
+                    }
+                    None => (Some(object_name), false),
+                }
+            }
+            mqsys::MQOT_Q_MGR => {
+                if !object_name.is_empty() && object_name != qmgr_name {
+                    return Err(mqsys::MQRC_UNKNOWN_OBJECT_NAME);
+                }
+                let access = mqsys::MQOO_OUTPUT
+                    | mqsys::MQOO_INPUT_AS_Q_DEF
+                    | mqsys::MQOO_INPUT_SHARED
+                    | mqsys::MQOO_INPUT_EXCLUSIVE
+                    | mqsys::MQOO_BROWSE;
+                if options & mqsys::MQOO_INQUIRE == 0 || options & access != 0 {
+                    return Err(mqsys::MQRC_OPTIONS_ERROR);
+                }
+                (None, false)
+            }
+            _ => return Err(mqsys::MQRC_OBJECT_TYPE_ERROR),
+        };
+
+        let connection = self.connection(hconn)?;
+        let hobj = connection.next_hobj;
+        connection.next_hobj += 1;
+        connection.handles.insert(
+            hobj,
+            Handle {
+                queue: queue.clone(),
+                options,
+                browse_cursor: None,
+                delete_on_close: dynamic,
+            },
+        );
+        Ok((hobj, queue.filter(|_| dynamic)))
+    }
+
+    /// Delete the temporary dynamic queue of a closed handle
+    fn release(&mut self, handle: Handle) {
+        if let Handle {
+            queue: Some(name),
+            delete_on_close: true,
+            ..
+        } = handle
+        {
+            self.queues.remove(&name);
+        }
+    }
+
+    fn close(&mut self, hconn: mqsys::MQHCONN, hobj: mqsys::MQHOBJ) -> Result<(), mqsys::MQLONG> {
+        let handle = self.connection(hconn)?.handles.remove(&hobj).ok_or(mqsys::MQRC_HOBJ_ERROR)?;
+        self.release(handle);
+        Ok(())
+    }
+
+    /// Put a message on the opened queue, returning the message identifier
+    fn put(
+        &mut self,
+        hconn: mqsys::MQHCONN,
+        hobj: mqsys::MQHOBJ,
+        mut md: mqsys::MQMD,
+        options: mqsys::MQLONG,
+        data: Vec<u8>,
+    ) -> Result<Put, mqsys::MQLONG> {
+        let handle = self.handle(hconn, hobj)?;
+        if handle.options & mqsys::MQOO_OUTPUT == 0 {
+            return Err(mqsys::MQRC_NOT_OPEN_FOR_OUTPUT);
+        }
+        let queue_name = handle.queue.clone().ok_or(mqsys::MQRC_NOT_OPEN_FOR_OUTPUT)?;
+        let seq = self.next_seq;
+        let queue = self.queues.get_mut(&queue_name).ok_or(mqsys::MQRC_UNKNOWN_OBJECT_NAME)?;
+
+        if md.Priority == mqsys::MQPRI_PRIORITY_AS_Q_DEF {
+            md.Priority = queue.def.default_priority;
+        } else if !(0..=9).contains(&md.Priority) {
+            return Err(mqsys::MQRC_PRIORITY_ERROR);
+        }
+        if queue.messages.len() >= usize::try_from(queue.def.max_depth).unwrap_or(0) {
+            return Err(mqsys::MQRC_Q_FULL);
+        }
+        if options & mqsys::MQPMO_NEW_MSG_ID != 0 || md.MsgId == [0; 24] {
+            md.MsgId = Self::message_id(seq);
+        }
+        md.BackoutCount = 0;
+
+        if let Some(CommandServer(server)) = self.command_servers.get(&queue_name) {
+            self.next_seq += 1;
+            return Ok(Put::Request(Arc::clone(server), Box::new(md), data));
+        }
+
+        queue.insert(Message {
+            seq,
+            md,
+            data,
+            state: if options & mqsys::MQPMO_SYNCPOINT == 0 {
+                MsgState::Available
+            } else {
+                MsgState::PendingPut(hconn)
+            },
+        });
+        self.next_seq += 1;
+        Ok(Put::Queued(md.MsgId))
+    }
+
+    /// Put the replies of a command server to the `ReplyToQ` of the request with the `MsgId` of the request as the
+    /// `CorrelId`. The other fields of the MQMD are copied from the request. Replies are discarded when the reply
+    /// queue does not exist or is full.
+    fn reply(&mut self, request: &mqsys::MQMD, replies: Vec<Vec<u8>>) {
+        let reply_queue = mq_str(&request.ReplyToQ);
+        for data in replies {
+            let seq = self.next_seq;
+            self.next_seq += 1;
+            let Some(queue) = self.queues.get_mut(&reply_queue) else {
+                continue;
+            };
+            if queue.messages.len() >= usize::try_from(queue.def.max_depth).unwrap_or(0) {
+                continue;
+            }
+            let mut md = mqsys::MQMD {
+                Report: mqsys::MQRO_NONE,
+                MsgType: mqsys::MQMT_REPLY,
+                Feedback: mqsys::MQFB_NONE,
+                MsgId: Self::message_id(seq),
+                CorrelId: request.MsgId,
+                BackoutCount: 0,
+                ..*request
+            };
+            fill(&mut md.ReplyToQ, b"");
+            fill(&mut md.ReplyToQMgr, b"");
+            queue.insert(Message {
+                seq,
+                md,
+                data,
+                state: MsgState::Available,
+            });
+        }
+    }
+
+    /// Get or browse the first matching message from the opened queue
+    fn get(
+        &mut self,
+        hconn: mqsys::MQHCONN,
+        hobj: mqsys::MQHOBJ,
+        md: &mqsys::MQMD,
+        (options, match_options): (mqsys::MQLONG, mqsys::MQLONG),
+        buffer_length: usize,
+    ) -> Result<Retrieved, mqsys::MQLONG> {
+        let browse = options & (mqsys::MQGMO_BROWSE_FIRST | mqsys::MQGMO_BROWSE_NEXT) != 0;
+        let (required, not_open) = if browse {
+            (mqsys::MQOO_BROWSE, mqsys::MQRC_NOT_OPEN_FOR_BROWSE)
+        } else {
+            (INPUT, mqsys::MQRC_NOT_OPEN_FOR_INPUT)
+        };
+
+        let handle = self.handle(hconn, hobj)?;
+        if handle.options & required == 0 {
+            return Err(not_open);
+        }
+        let queue_name = handle.queue.clone().ok_or(not_open)?;
+        let cursor = if options & mqsys::MQGMO_BROWSE_NEXT == 0 {
+            None
+        } else {
+            handle.browse_cursor
+        };
+        let queue = self.queues.get_mut(&queue_name).ok_or(mqsys::MQRC_UNKNOWN_OBJECT_NAME)?;
+
+        let is_match = |message: &Message| {
+            message.state == MsgState::Available
+                && (match_options & mqsys::MQMO_MATCH_MSG_ID == 0 || md.MsgId == [0; 24] || md.MsgId == message.md.MsgId)
+                && (match_options & mqsys::MQMO_MATCH_CORREL_ID == 0
+                    || md.CorrelId == [0; 24]
+                    || md.CorrelId == message.md.CorrelId)
+        };
+        let start = cursor
+            .and_then(|seq| queue.messages.iter().position(|message| message.seq == seq))
+            .map_or(0, |position| position + 1);
+        let index = queue
+            .messages
+            .iter()
+            .skip(start)
+            .position(is_match)
+            .map(|position| position + start)
+            .ok_or(mqsys::MQRC_NO_MSG_AVAILABLE)?;
+
+        let message = &mut queue.messages[index];
+        let reason = match (
+            message.data.len() > buffer_length,
+            options & mqsys::MQGMO_ACCEPT_TRUNCATED_MSG != 0,
+        ) {
+            (false, _) => mqsys::MQRC_NONE,
+            (true, true) => mqsys::MQRC_TRUNCATED_MSG_ACCEPTED,
+            (true, false) => mqsys::MQRC_TRUNCATED_MSG_FAILED,
+        };
+        let retrieved = Retrieved {
+            md: message.md,
+            data: message.data.clone(),
+            reason,
+        };
+        if reason == mqsys::MQRC_TRUNCATED_MSG_FAILED {
+            return Ok(retrieved);
+        }
+
+        if browse {
+            let seq = message.seq;
+            self.handle(hconn, hobj)?.browse_cursor = Some(seq);
+        } else if options & mqsys::MQGMO_SYNCPOINT == 0 {
+            queue.messages.remove(index);
+        } else {
+            message.state = MsgState::PendingGet(hconn);
+        }
+        Ok(retrieved)
+    }
+
+    /// Inquire on the integer and character attributes of an opened object
+    fn inquire(
+        &mut self,
+        qmgr_name: &str,
+        hconn: mqsys::MQHCONN,
+        hobj: mqsys::MQHOBJ,
+        selectors: &[mqsys::MQLONG],
+    ) -> Result<(Vec<mqsys::MQLONG>, Vec<u8>), mqsys::MQLONG> {
+        let handle = self.handle(hconn, hobj)?;
+        if handle.options & mqsys::MQOO_INQUIRE == 0 {
+            return Err(mqsys::MQRC_NOT_OPEN_FOR_INQUIRE);
+        }
+        let queue = match handle.queue.clone() {
+            Some(name) => {
+                let queue = self.queues.get(&name).ok_or(mqsys::MQRC_UNKNOWN_OBJECT_NAME)?;
+                Some((name, queue))
+            }
+            None => None,
+        };
+
+        let mut ints = Vec::new();
+        let mut chars = Vec::new();
+        for &selector in selectors {
+            match (selector, &queue) {
+                (mqsys::MQIA_CURRENT_Q_DEPTH, Some((_, queue))) => {
+                    ints.push(mqsys::MQLONG::try_from(queue.messages.len()).unwrap_or(mqsys::MQLONG::MAX));
+                }
+                (mqsys::MQIA_MAX_Q_DEPTH, Some((_, queue))) => ints.push(queue.def.max_depth),
+                (mqsys::MQIA_DEF_PRIORITY, Some((_, queue))) => ints.push(queue.def.default_priority),
+                (mqsys::MQIA_MSG_DELIVERY_SEQUENCE, Some((_, queue))) => ints.push(queue.def.delivery_sequence),
+                (mqsys::MQIA_Q_TYPE, Some(_)) => ints.push(mqsys::MQQT_LOCAL),
+                (mqsys::MQCA_Q_NAME, Some((name, _))) => chars.extend(padded(name, mqsys::MQ_Q_NAME_LENGTH)),
+                (mqsys::MQCA_Q_MGR_NAME, _) => chars.extend(padded(qmgr_name, mqsys::MQ_Q_MGR_NAME_LENGTH)),
+                (mqsys::MQIA_FIRST..=mqsys::MQIA_LAST | mqsys::MQCA_FIRST..=mqsys::MQCA_LAST, _) => {
+                    return Err(mqsys::MQRC_SELECTOR_NOT_FOR_TYPE)
+                }
+                _ => return Err(mqsys::MQRC_SELECTOR_ERROR),
+            }
+        }
+        Ok((ints, chars))
+    }
+
+    /// Commit or back out all the pending messages of a connection
+    fn resolve(&mut self, hconn: mqsys::MQHCONN, commit: bool) {
+        for queue in self.queues.values_mut() {
+            let mut messages: Vec<_> = queue
+                .messages
+                .drain(..)
+                .filter_map(|mut message| match message.state {
+                    MsgState::PendingPut(owner) | MsgState::PendingGet(owner) if owner != hconn => Some(message),
+                    MsgState::PendingPut(_) if !commit => None,
+                    MsgState::PendingGet(_) if commit => None,
+                    state => {
+                        if matches!(state, MsgState::PendingGet(_)) {
+                            message.md.BackoutCount += 1;
+                        }
+                        message.state = MsgState::Available;
+                        Some(message)
+                    }
+                })
+                .collect();
+            messages.sort_by_key(|message| message.seq);
+            for message in messages {
+                queue.insert(message);
+            }
+        }
+    }
+
+    fn end_unit_of_work(&mut self, hconn: mqsys::MQHCONN, commit: bool) -> Result<(), mqsys::MQLONG> {
+        self.connection(hconn)?;
+        self.resolve(hconn, commit);
+        Ok(())
+    }
+}
+
+/// An in-memory queue manager implementing the [`Mqi`](function::Mqi) function calls
+#[derive(Debug)]
+pub struct MockMq {
+    qmgr_name: String,
+    state: Mutex<State>,
+}
+
+impl MockMq {
+    /// Create a mock queue manager with no queues
+    #[must_use]
+    pub fn new(qmgr_name: &str) -> Self {
+        Self {
+            qmgr_name: qmgr_name.to_string(),
+            state: Mutex::new(State {
+                next_hconn: 1,
+                ..State::default()
+            }),
+        }
+    }
+
+    /// Add a local queue with the default [`QueueDef`]
+    #[must_use]
+    pub fn with_queue(self, name: &str) -> Self {
+        self.define_queue(name, QueueDef::default());
+        self
+    }
+
+    /// Add a model queue with the default [`QueueDef`]. Opening the model queue creates a temporary dynamic queue
+    /// named by the `DynamicQName` of the MQOD, which is deleted when the queue is closed.
+    #[must_use]
+    pub fn with_model_queue(self, name: &str) -> Self {
+        self.state().models.insert(name.to_string(), QueueDef::default());
+        self
+    }
+
+    /// Add a command queue that is answered by the `server`, such as `SYSTEM.ADMIN.COMMAND.QUEUE`. The data of each
+    /// message put to the queue is passed to the server rather than queued, even within a unit of work. The replies
+    /// returned by the server are put to the `ReplyToQ` of the request with the `MsgId` of the request as their
+    /// `CorrelId`, and the `Format`, `Encoding` and `CodedCharSetId` of the request. The server is called without
+    /// holding the lock on the mock, so it may call the mock itself.
+    #[must_use]
+    pub fn with_command_server(self, name: &str, server: impl Fn(&[u8]) -> Vec<Vec<u8>> + Send + Sync + 'static) -> Self {
+        self.define_queue(name, QueueDef::default());
+        self.state()
+            .command_servers
+            .insert(name.to_string(), CommandServer(Arc::new(server)));
+        self
+    }
+
+    /// Define or replace a local queue. Any messages on a replaced queue are discarded.
+    pub fn define_queue(&self, name: &str, def: QueueDef) {
+        self.state().queues.insert(
+            name.to_string(),
+            Queue {
+                def,
+                messages: VecDeque::new(),
+            },
+        );
+    }
+
+    /// Name of the mock queue manager
+    #[must_use]
+    pub fn qmgr_name(&self) -> &str {
+        &self.qmgr_name
+    }
+
+    /// Current depth of a queue, including uncommitted messages
+    #[must_use]
+    pub fn depth(&self, queue: &str) -> Option<usize> {
+        self.state().queues.get(queue).map(|queue| queue.messages.len())
+    }
+
+    /// Data of the committed messages on a queue in delivery order
+    #[must_use]
+    pub fn messages(&self, queue: &str) -> Option<Vec<Vec<u8>>> {
+        self.state().queues.get(queue).map(|queue| {
+            queue
+                .messages
+                .iter()
+                .filter(|message| message.state == MsgState::Available)
+                .map(|message| message.data.clone())
+                .collect()
+        })
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    unsafe fn connect(&self, pQMgrName: mqsys::PMQCHAR, pHconn: mqsys::PMQHCONN) -> Outcome {
+        if !pQMgrName.is_null() {
+            let name = unsafe { mq_str(slice::from_raw_parts(pQMgrName, mqsys::MQ_Q_MGR_NAME_LENGTH)) };
+            if !name.is_empty() && name != self.qmgr_name {
+                return Err(mqsys::MQRC_Q_MGR_NAME_ERROR);
+            }
+        }
+        let hconn = self.state().connect();
+        unsafe { *pHconn = hconn };
+        Ok(mqsys::MQRC_NONE)
+    }
+
+    unsafe fn open(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pObjDesc: *mut mqsys::MQOD,
+        Options: mqsys::MQLONG,
+    ) -> Result<mqsys::MQHOBJ, mqsys::MQLONG> {
+        if pObjDesc.is_null() {
+            return Err(mqsys::MQRC_OD_ERROR);
+        }
+        let (object_type, object) = unsafe {
+            (
+                ptr::addr_of!((*pObjDesc).ObjectType).read(),
+                (
+                    mq_str(&ptr::addr_of!((*pObjDesc).ObjectName).read()),
+                    mq_str(&ptr::addr_of!((*pObjDesc).ObjectQMgrName).read()),
+                    mq_str(&ptr::addr_of!((*pObjDesc).DynamicQName).read()),
+                ),
+            )
+        };
+        let (hobj, dynamic) = self.state().open(&self.qmgr_name, Hconn, object_type, object, Options)?;
+        if let Some(name) = dynamic {
+            let mut object_name = unsafe { ptr::addr_of!((*pObjDesc).ObjectName).read() };
+            fill(&mut object_name, name.as_bytes());
+            unsafe { ptr::addr_of_mut!((*pObjDesc).ObjectName).write(object_name) };
+        }
+        Ok(hobj)
+    }
+
+    unsafe fn put(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pPutMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+    ) -> Outcome {
+        let md_ptr = pMsgDesc.cast::<mqsys::MQMD>();
+        let pmo_ptr = pPutMsgOpts.cast::<mqsys::MQPMO>();
+        if md_ptr.is_null() {
+            return Err(mqsys::MQRC_MD_ERROR);
+        }
+        if pmo_ptr.is_null() {
+            return Err(mqsys::MQRC_PMO_ERROR);
+        }
+        let data = unsafe { buffer(pBuffer, BufferLength) }?.to_vec();
+        let md = unsafe { structs::read(md_ptr) };
+        let options = unsafe { ptr::addr_of!((*pmo_ptr).Options).read() };
+
+        let put = self.state().put(Hconn, Hobj, md, options, data)?;
+        let msg_id = match put {
+            Put::Queued(msg_id) => msg_id,
+            Put::Request(server, md, data) => {
+                // The lock is released so the command server can call the mock
+                let replies = server(&data);
+                self.state().reply(&md, replies);
+                md.MsgId
+            }
+        };
+        unsafe { ptr::addr_of_mut!((*md_ptr).MsgId).write(msg_id) };
+        Ok(mqsys::MQRC_NONE)
+    }
+
+    unsafe fn get(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: *mut mqsys::MQMD,
+        pGetMsgOpts: *const mqsys::MQGMO,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+    ) -> Outcome {
+        if pMsgDesc.is_null() {
+            return Err(mqsys::MQRC_MD_ERROR);
+        }
+        if pGetMsgOpts.is_null() {
+            return Err(mqsys::MQRC_GMO_ERROR);
+        }
+        let buffer_length = usize::try_from(BufferLength).map_err(|_| mqsys::MQRC_BUFFER_LENGTH_ERROR)?;
+        if buffer_length > 0 && pBuffer.is_null() {
+            return Err(mqsys::MQRC_BUFFER_ERROR);
+        }
+        let md = unsafe { structs::read(pMsgDesc) };
+        let options = unsafe {
+            let version = ptr::addr_of!((*pGetMsgOpts).Version).read();
+            (
+                ptr::addr_of!((*pGetMsgOpts).Options).read(),
+                if version >= mqsys::MQGMO_VERSION_2 {
+                    ptr::addr_of!((*pGetMsgOpts).MatchOptions).read()
+                } else {
+                    mqsys::MQMO_MATCH_MSG_ID | mqsys::MQMO_MATCH_CORREL_ID
+                },
+            )
+        };
+
+        let Retrieved {
+            md: mut out_md,
+            data,
+            reason,
+        } = self.state().get(Hconn, Hobj, &md, options, buffer_length)?;
+        out_md.StrucId = md.StrucId;
+        out_md.Version = md.Version;
+        unsafe {
+            copy_out(&data, pBuffer.cast::<u8>(), buffer_length);
+            *pDataLength = mqsys::MQLONG::try_from(data.len()).unwrap_or(mqsys::MQLONG::MAX);
+            structs::write(pMsgDesc, &out_md);
+        }
+        Ok(reason)
+    }
+
+    unsafe fn inq(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        SelectorCount: mqsys::MQLONG,
+        pSelectors: mqsys::PMQLONG,
+        IntAttrCount: mqsys::MQLONG,
+        pIntAttrs: mqsys::PMQLONG,
+        CharAttrLength: mqsys::MQLONG,
+        pCharAttrs: mqsys::PMQCHAR,
+    ) -> Outcome {
+        let selector_count = usize::try_from(SelectorCount).map_err(|_| mqsys::MQRC_SELECTOR_COUNT_ERROR)?;
+        let int_count = usize::try_from(IntAttrCount).map_err(|_| mqsys::MQRC_INT_ATTR_COUNT_ERROR)?;
+        let char_length = usize::try_from(CharAttrLength).map_err(|_| mqsys::MQRC_CHAR_ATTR_LENGTH_ERROR)?;
+        if selector_count > 256 {
+            return Err(mqsys::MQRC_SELECTOR_COUNT_ERROR);
+        }
+        if selector_count > 0 && pSelectors.is_null() {
+            return Err(mqsys::MQRC_SELECTOR_ERROR);
+        }
+        if int_count > 0 && pIntAttrs.is_null() {
+            return Err(mqsys::MQRC_INT_ATTRS_ARRAY_ERROR);
+        }
+        if char_length > 0 && pCharAttrs.is_null() {
+            return Err(mqsys::MQRC_CHAR_ATTRS_ERROR);
+        }
+        let selectors = if selector_count == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(pSelectors, selector_count) }
+        };
+
+        let (ints, chars) = self.state().inquire(&self.qmgr_name, Hconn, Hobj, selectors)?;
+        unsafe {
+            copy_out(&ints, pIntAttrs, int_count);
+            copy_out(&chars, pCharAttrs.cast::<u8>(), char_length);
+        }
+        Ok(if ints.len() > int_count {
+            mqsys::MQRC_INT_ATTR_COUNT_TOO_SMALL
+        } else if chars.len() > char_length {
+            mqsys::MQRC_CHAR_ATTRS_TOO_SHORT
+        } else {
+            mqsys::MQRC_NONE
+        })
+    }
+}
+
+impl function::Mqi for MockMq {
+    unsafe fn MQCONNX(
+        &self,
+        pQMgrName: mqsys::PMQCHAR,
+        _pConnectOpts: mqsys::PMQCNO,
+        pHconn: mqsys::PMQHCONN,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { complete(pCompCode, pReason, self.connect(pQMgrName, pHconn)) };
+    }
+
+    unsafe fn MQCONN(
+        &self,
+        pQMgrName: mqsys::PMQCHAR,
+        pHconn: mqsys::PMQHCONN,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { complete(pCompCode, pReason, self.connect(pQMgrName, pHconn)) };
+    }
+
+    unsafe fn MQDISC(&self, pHconn: mqsys::PMQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        let outcome = self.state().disconnect(unsafe { *pHconn }).map(|()| {
+            unsafe { *pHconn = mqsys::MQHC_UNUSABLE_HCONN };
+            mqsys::MQRC_NONE
+        });
+        unsafe { complete(pCompCode, pReason, outcome) };
+    }
+
+    unsafe fn MQOPEN(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pObjDesc: mqsys::PMQVOID,
+        Options: mqsys::MQLONG,
+        pHobj: mqsys::PMQHOBJ,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let outcome = unsafe { self.open(Hconn, pObjDesc.cast(), Options) }.map(|hobj| {
+            unsafe { *pHobj = hobj };
+            mqsys::MQRC_NONE
+        });
+        unsafe { complete(pCompCode, pReason, outcome) };
+    }
+
+    unsafe fn MQPUT1(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pObjDesc: mqsys::PMQVOID,
+        pMsgDesc: mqsys::PMQVOID,
+        pPutMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let outcome = unsafe { self.open(Hconn, pObjDesc.cast(), mqsys::MQOO_OUTPUT) }.and_then(|hobj| {
+            let outcome = unsafe { self.put(Hconn, hobj, pMsgDesc, pPutMsgOpts, BufferLength, pBuffer) };
+            self.state().close(Hconn, hobj).and(outcome)
+        });
+        unsafe { complete(pCompCode, pReason, outcome) };
+    }
+
+    unsafe fn MQCLOSE(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pHobj: mqsys::PMQHOBJ,
+        _Options: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let outcome = self.state().close(Hconn, unsafe { *pHobj }).map(|()| {
+            unsafe { *pHobj = mqsys::MQHO_UNUSABLE_HOBJ };
+            mqsys::MQRC_NONE
+        });
+        unsafe { complete(pCompCode, pReason, outcome) };
+    }
+
+    unsafe fn MQCMIT(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        let outcome = self.state().end_unit_of_work(Hconn, true).map(|()| mqsys::MQRC_NONE);
+        unsafe { complete(pCompCode, pReason, outcome) };
+    }
+
+    unsafe fn MQGET(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pGetMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let outcome = unsafe {
+            self.get(
+                Hconn,
+                Hobj,
+                pMsgDesc.cast(),
+                pGetMsgOpts.cast(),
+                BufferLength,
+                pBuffer,
+                pDataLength,
+            )
+        };
+        unsafe { complete(pCompCode, pReason, outcome) };
+    }
+
+    unsafe fn MQPUT(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pPutMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let outcome = unsafe { self.put(Hconn, Hobj, pMsgDesc, pPutMsgOpts, BufferLength, pBuffer) };
+        unsafe { complete(pCompCode, pReason, outcome) };
+    }
+
+    unsafe fn MQINQ(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        SelectorCount: mqsys::MQLONG,
+        pSelectors: mqsys::PMQLONG,
+        IntAttrCount: mqsys::MQLONG,
+        pIntAttrs: mqsys::PMQLONG,
+        CharAttrLength: mqsys::MQLONG,
+        pCharAttrs: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let outcome = unsafe {
+            self.inq(
+                Hconn,
+                Hobj,
+                SelectorCount,
+                pSelectors,
+                IntAttrCount,
+                pIntAttrs,
+                CharAttrLength,
+                pCharAttrs,
+            )
+        };
+        unsafe { complete(pCompCode, pReason, outcome) };
+    }
+
+    unsafe fn MQSUB(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _pSubDesc: mqsys::PMQVOID,
+        _pHobj: mqsys::PMQHOBJ,
+        _pHsub: mqsys::PMQHOBJ,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQSUBRQ(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Hsub: mqsys::MQHOBJ,
+        _Action: mqsys::MQLONG,
+        _pSubRqOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQBEGIN(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _pBeginOptions: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQBACK(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        let outcome = self.state().end_unit_of_work(Hconn, false).map(|()| mqsys::MQRC_NONE);
+        unsafe { complete(pCompCode, pReason, outcome) };
+    }
+
+    unsafe fn MQCRTMH(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _pCrtMsgHOpts: mqsys::PMQVOID,
+        _pHmsg: mqsys::PMQHMSG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQDLTMH(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _pHmsg: mqsys::PMQHMSG,
+        _pDltMsgHOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQMHBUF(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Hmsg: mqsys::MQHMSG,
+        _pMsgHBufOpts: mqsys::PMQVOID,
+        _pName: mqsys::PMQVOID,
+        _pMsgDesc: mqsys::PMQVOID,
+        _BufferLength: mqsys::MQLONG,
+        _pBuffer: mqsys::PMQVOID,
+        _pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQBUFMH(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Hmsg: mqsys::MQHMSG,
+        _pBufMsgHOpts: mqsys::PMQVOID,
+        _pMsgDesc: mqsys::PMQVOID,
+        _BufferLength: mqsys::MQLONG,
+        _pBuffer: mqsys::PMQVOID,
+        _pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQCB(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Operation: mqsys::MQLONG,
+        _pCallbackDesc: mqsys::PMQVOID,
+        _Hobj: mqsys::MQHOBJ,
+        _pMsgDesc: mqsys::PMQVOID,
+        _pGetMsgOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQCTL(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Operation: mqsys::MQLONG,
+        _pControlOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQSET(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Hobj: mqsys::MQHOBJ,
+        _SelectorCount: mqsys::MQLONG,
+        _pSelectors: mqsys::PMQLONG,
+        _IntAttrCount: mqsys::MQLONG,
+        _pIntAttrs: mqsys::PMQLONG,
+        _CharAttrLength: mqsys::MQLONG,
+        _pCharAttrs: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQSETMP(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Hmsg: mqsys::MQHMSG,
+        _pSetPropOpts: mqsys::PMQVOID,
+        _pName: mqsys::PMQVOID,
+        _pPropDesc: mqsys::PMQVOID,
+        _Type: mqsys::MQLONG,
+        _ValueLength: mqsys::MQLONG,
+        _pValue: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQSTAT(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Type: mqsys::MQLONG,
+        _pStatus: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQINQMP(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Hmsg: mqsys::MQHMSG,
+        _pInqPropOpts: mqsys::PMQVOID,
+        _pName: mqsys::PMQVOID,
+        _pPropDesc: mqsys::PMQVOID,
+        _pType: mqsys::PMQLONG,
+        _ValueLength: mqsys::MQLONG,
+        _pValue: mqsys::PMQVOID,
+        _pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQDLTMP(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Hmsg: mqsys::MQHMSG,
+        _pDltPropOpts: mqsys::PMQVOID,
+        _pName: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQXCNVC(
+        &self,
+        _Hconn: mqsys::MQHCONN,
+        _Options: mqsys::MQLONG,
+        _SourceCCSID: mqsys::MQLONG,
+        _SourceLength: mqsys::MQLONG,
+        _pSourceBuffer: mqsys::PMQCHAR,
+        _TargetCCSID: mqsys::MQLONG,
+        _TargetLength: mqsys::MQLONG,
+        _pTargetBuffer: mqsys::PMQCHAR,
+        _pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+}
+
+#[cfg(feature = "exits")]
+impl function::MqiExit for MockMq {
+    unsafe fn MQXEP(
+        &self,
+        _Hconfig: mqsys::MQHCONFIG,
+        _ExitReason: mqsys::MQLONG,
+        _Function: mqsys::MQLONG,
+        _pEntryPoint: mqsys::PMQFUNC,
+        _pExitOpts: mqsys::PMQXEPO,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQZEP(
+        &self,
+        _Hconfig: mqsys::MQHCONFIG,
+        _Function: mqsys::MQLONG,
+        _pEntryPoint: mqsys::PMQFUNC,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQXCLWLN(
+        &self,
+        _pExitParms: mqsys::PMQWXP,
+        _CurrentRecord: mqsys::MQPTR,
+        _NextOffset: mqsys::MQLONG,
+        _pNextRecord: mqsys::PMQPTR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        unsafe { not_supported(pCompCode, pReason) };
+    }
+
+    unsafe fn MQXDX(
+        &self,
+        pDataConvExitParms: mqsys::PMQDXP,
+        _pMsgDesc: mqsys::PMQMD,
+        _InBufferLength: mqsys::MQLONG,
+        _pInBuffer: mqsys::PMQVOID,
+        _OutBufferLength: mqsys::MQLONG,
+        _pOutBuffer: mqsys::PMQVOID,
+    ) {
+        unsafe {
+            not_supported(
+                ptr::addr_of_mut!((*pDataConvExitParms).CompCode),
+                ptr::addr_of_mut!((*pDataConvExitParms).Reason),
+            );
+        }
+    }
+}
+
+/// Set the completion code and reason from the outcome of a call
+unsafe fn complete(pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG, outcome: Outcome) {
+    let (comp_code, reason) = match outcome {
+        Ok(mqsys::MQRC_NONE) => (mqsys::MQCC_OK, mqsys::MQRC_NONE),
+        Ok(reason) => (mqsys::MQCC_WARNING, reason),
+        Err(reason) => (mqsys::MQCC_FAILED, reason),
+    };
+    unsafe {
+        *pCompCode = comp_code;
+        *pReason = reason;
+    }
+}
+
+unsafe fn not_supported(pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+    unsafe { complete(pCompCode, pReason, Err(mqsys::MQRC_FUNCTION_NOT_SUPPORTED)) };
+}
+
+/// Borrow a caller supplied buffer
+unsafe fn buffer<'a>(pBuffer: mqsys::PMQVOID, BufferLength: mqsys::MQLONG) -> Result<&'a [u8], mqsys::MQLONG> {
+    match usize::try_from(BufferLength) {
+        Err(_) => Err(mqsys::MQRC_BUFFER_LENGTH_ERROR),
+        Ok(0) => Ok(&[]),
+        Ok(_) if pBuffer.is_null() => Err(mqsys::MQRC_BUFFER_ERROR),
+        Ok(length) => Ok(unsafe { slice::from_raw_parts(pBuffer.cast::<u8>(), length) }),
+    }
+}
+
+/// Copy as much of `data` as fits into a caller supplied array of `length` elements, which may be null when empty
+unsafe fn copy_out<T: Copy>(data: &[T], target: *mut T, length: usize) {
+    let count = data.len().min(length);
+    if count > 0 {
+        unsafe { ptr::copy_nonoverlapping(data.as_ptr(), target, count) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr::{addr_of_mut, null_mut};
+
+    use crate::{
+        fixture::{connect, get, gmo, md, od, open, pmo, put},
+        lib, Mqi as _,
+    };
+
+    use super::*;
+
+    #[test]
+    fn put_get_priority_order() {
+        let mq = MockMq::new("QM1").with_queue("Q1");
+        let hconn = connect(&mq);
+        let (hobj, rc) = open(&mq, hconn, "Q1", lib::MQOO_OUTPUT | lib::MQOO_INPUT_AS_Q_DEF);
+        assert_eq!(rc, lib::MQRC_NONE);
+
+        assert_eq!(put(&mq, hconn, hobj, b"low", 1, 0), lib::MQRC_NONE);
+        assert_eq!(put(&mq, hconn, hobj, b"high", 5, 0), lib::MQRC_NONE);
+        assert_eq!(put(&mq, hconn, hobj, b"low2", 1, 0), lib::MQRC_NONE);
+        assert_eq!(mq.depth("Q1"), Some(3));
+
+        assert_eq!(get(&mq, hconn, hobj, 100, 0), (lib::MQRC_NONE, b"high".to_vec()));
+        assert_eq!(get(&mq, hconn, hobj, 100, 0), (lib::MQRC_NONE, b"low".to_vec()));
+        assert_eq!(get(&mq, hconn, hobj, 100, 0), (lib::MQRC_NONE, b"low2".to_vec()));
+        assert_eq!(get(&mq, hconn, hobj, 100, 0).0, lib::MQRC_NO_MSG_AVAILABLE);
+    }
+
+    #[test]
+    fn fifo_ignores_priority() {
+        let mq = MockMq::new("QM1");
+        mq.define_queue(
+            "Q1",
+            QueueDef {
+                delivery_sequence: lib::MQMDS_FIFO,
+                ..QueueDef::default()
+            },
+        );
+        let hconn = connect(&mq);
+        let (hobj, _) = open(&mq, hconn, "Q1", lib::MQOO_OUTPUT);
+        put(&mq, hconn, hobj, b"first", 1, 0);
+        put(&mq, hconn, hobj, b"second", 9, 0);
+        assert_eq!(mq.messages("Q1"), Some(vec![b"first".to_vec(), b"second".to_vec()]));
+    }
+
+    #[test]
+    fn unknown_object_and_handles() {
+        let mq = MockMq::new("QM1").with_queue("Q1");
+        let hconn = connect(&mq);
+        assert_eq!(open(&mq, hconn, "MISSING", lib::MQOO_OUTPUT).1, lib::MQRC_UNKNOWN_OBJECT_NAME);
+        assert_eq!(open(&mq, hconn + 100, "Q1", lib::MQOO_OUTPUT).1, lib::MQRC_HCONN_ERROR);
+        assert_eq!(put(&mq, hconn, 42, b"data", 0, 0), lib::MQRC_HOBJ_ERROR);
+
+        let (hobj, _) = open(&mq, hconn, "Q1", lib::MQOO_OUTPUT);
+        assert_eq!(get(&mq, hconn, hobj, 10, 0).0, lib::MQRC_NOT_OPEN_FOR_INPUT);
+
+        let mut hconn_disc = hconn;
+        let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+        unsafe { mq.MQDISC(addr_of_mut!(hconn_disc), addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!((cc, rc, hconn_disc), (lib::MQCC_OK, lib::MQRC_NONE, lib::MQHC_UNUSABLE_HCONN));
+        assert_eq!(put(&mq, hconn, hobj, b"data", 0, 0), lib::MQRC_HCONN_ERROR);
+    }
+
+    #[test]
+    fn syncpoint_backout() {
+        let mq = MockMq::new("QM1").with_queue("Q1");
+        let hconn = connect(&mq);
+        let (hobj, _) = open(&mq, hconn, "Q1", lib::MQOO_OUTPUT | lib::MQOO_INPUT_SHARED);
+        put(&mq, hconn, hobj, b"data", 0, lib::MQPMO_SYNCPOINT);
+        assert_eq!(get(&mq, hconn, hobj, 10, 0).0, lib::MQRC_NO_MSG_AVAILABLE);
+
+        let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+        unsafe { mq.MQCMIT(hconn, addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!(
+            get(&mq, hconn, hobj, 10, lib::MQGMO_SYNCPOINT),
+            (lib::MQRC_NONE, b"data".to_vec())
+        );
+        assert_eq!(mq.messages("Q1"), Some(vec![]));
+
+        unsafe { mq.MQBACK(hconn, addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!(mq.messages("Q1"), Some(vec![b"data".to_vec()]));
+    }
+
+    #[test]
+    fn truncation_and_queue_full() {
+        let mq = MockMq::new("QM1");
+        mq.define_queue(
+            "Q1",
+            QueueDef {
+                max_depth: 1,
+                ..QueueDef::default()
+            },
+        );
+        let hconn = connect(&mq);
+        let (hobj, _) = open(&mq, hconn, "Q1", lib::MQOO_OUTPUT | lib::MQOO_INPUT_SHARED);
+        assert_eq!(put(&mq, hconn, hobj, b"message", 0, 0), lib::MQRC_NONE);
+        assert_eq!(put(&mq, hconn, hobj, b"message", 0, 0), lib::MQRC_Q_FULL);
+
+        assert_eq!(get(&mq, hconn, hobj, 3, 0).0, lib::MQRC_TRUNCATED_MSG_FAILED);
+        assert_eq!(mq.depth("Q1"), Some(1));
+        assert_eq!(
+            get(&mq, hconn, hobj, 3, lib::MQGMO_ACCEPT_TRUNCATED_MSG),
+            (lib::MQRC_TRUNCATED_MSG_ACCEPTED, b"mes".to_vec())
+        );
+        assert_eq!(mq.depth("Q1"), Some(0));
+    }
+
+    #[test]
+    fn model_queue() {
+        let mq = MockMq::new("QM1").with_model_queue("MODEL");
+        let hconn = connect(&mq);
+        let mut od = od("MODEL");
+        fill(&mut od.DynamicQName, b"APP.*");
+        let mut hobj = lib::MQHO_NONE;
+        let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+        unsafe {
+            mq.MQOPEN(
+                hconn,
+                addr_of_mut!(od).cast(),
+                lib::MQOO_INPUT_EXCLUSIVE,
+                addr_of_mut!(hobj),
+                addr_of_mut!(cc),
+                addr_of_mut!(rc),
+            );
+        };
+        assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+        let name = mq_str(&od.ObjectName);
+        assert!(name.starts_with("APP.") && name.len() > 4, "dynamic queue name {name}");
+        assert_eq!(mq.depth(&name), Some(0));
+        assert_eq!(mq.depth("MODEL"), None);
+
+        unsafe { mq.MQCLOSE(hconn, addr_of_mut!(hobj), lib::MQCO_NONE, addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+        assert_eq!(mq.depth(&name), None);
+    }
+
+    #[test]
+    fn command_server() {
+        let mq = MockMq::new("QM1")
+            .with_queue("REPLY")
+            .with_command_server("COMMAND", |request| vec![request.to_vec(), b"done".to_vec()]);
+        let hconn = connect(&mq);
+        let (command, _) = open(&mq, hconn, "COMMAND", lib::MQOO_OUTPUT);
+        let (reply, _) = open(&mq, hconn, "REPLY", lib::MQOO_INPUT_SHARED);
+
+        let mut md = md();
+        fill(&mut md.ReplyToQ, b"REPLY");
+        let mut pmo = pmo(lib::MQPMO_NEW_MSG_ID);
+        let mut data = b"request".to_vec();
+        let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+        unsafe {
+            mq.MQPUT(
+                hconn,
+                command,
+                addr_of_mut!(md).cast(),
+                addr_of_mut!(pmo).cast(),
+                7,
+                data.as_mut_ptr().cast(),
+                addr_of_mut!(cc),
+                addr_of_mut!(rc),
+            );
+        };
+        assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+        assert_eq!(mq.depth("COMMAND"), Some(0));
+        assert_eq!(mq.messages("REPLY"), Some(vec![b"request".to_vec(), b"done".to_vec()]));
+
+        let mut reply_md = self::md();
+        reply_md.CorrelId = md.MsgId;
+        let mut gmo = gmo(0);
+        gmo.MatchOptions = lib::MQMO_MATCH_CORREL_ID;
+        let mut buffer = [0u8; 16];
+        let mut data_length = 0;
+        unsafe {
+            mq.MQGET(
+                hconn,
+                reply,
+                addr_of_mut!(reply_md).cast(),
+                addr_of_mut!(gmo).cast(),
+                16,
+                buffer.as_mut_ptr().cast(),
+                addr_of_mut!(data_length),
+                addr_of_mut!(cc),
+                addr_of_mut!(rc),
+            );
+        };
+        assert_eq!((cc, rc, data_length), (lib::MQCC_OK, lib::MQRC_NONE, 7));
+        assert_eq!(reply_md.MsgType, lib::MQMT_REPLY);
+        assert_eq!(reply_md.CorrelId, md.MsgId);
+    }
+
+    #[test]
+    fn command_server_calls_mock() {
+        use std::sync::{OnceLock, Weak};
+
+        let shared: Arc<OnceLock<Weak<MockMq>>> = Arc::default();
+        let server_mock = Arc::clone(&shared);
+        let mq = Arc::new(MockMq::new("QM1").with_command_server("COMMAND", move |_| {
+            if let Some(mq) = server_mock.get().and_then(Weak::upgrade) {
+                mq.define_queue("AUDIT", QueueDef::default());
+            }
+            Vec::new()
+        }));
+        shared.set(Arc::downgrade(&mq)).expect("mock set once");
+
+        let hconn = connect(&*mq);
+        let (command, _) = open(&*mq, hconn, "COMMAND", lib::MQOO_OUTPUT);
+        assert_eq!(put(&*mq, hconn, command, b"request", 0, 0), lib::MQRC_NONE);
+        assert_eq!(mq.depth("AUDIT"), Some(0));
+    }
+
+    #[test]
+    fn open_queue_manager() {
+        let mq = MockMq::new("QM1");
+        let hconn = connect(&mq);
+        let open_qmgr = |options| {
+            let mut od = od("");
+            od.ObjectType = lib::MQOT_Q_MGR;
+            let mut hobj = lib::MQHO_NONE;
+            let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+            unsafe {
+                mq.MQOPEN(
+                    hconn,
+                    addr_of_mut!(od).cast(),
+                    options,
+                    addr_of_mut!(hobj),
+                    addr_of_mut!(cc),
+                    addr_of_mut!(rc),
+                );
+            };
+            rc
+        };
+
+        assert_eq!(open_qmgr(lib::MQOO_INQUIRE | lib::MQOO_FAIL_IF_QUIESCING), lib::MQRC_NONE);
+        assert_eq!(open_qmgr(lib::MQOO_FAIL_IF_QUIESCING), lib::MQRC_OPTIONS_ERROR);
+        assert_eq!(open_qmgr(lib::MQOO_INQUIRE | lib::MQOO_OUTPUT), lib::MQRC_OPTIONS_ERROR);
+    }
+
+    #[test]
+    fn inquire_depth() {
+        let mq = MockMq::new("QM1").with_queue("Q1");
+        let hconn = connect(&mq);
+        let (hobj, _) = open(&mq, hconn, "Q1", lib::MQOO_OUTPUT | lib::MQOO_INQUIRE);
+        put(&mq, hconn, hobj, b"data", 0, 0);
+
+        let mut selectors = [lib::MQIA_CURRENT_Q_DEPTH, lib::MQCA_Q_NAME];
+        let mut ints = [0; 1];
+        let mut chars: [lib::MQCHAR; 48] = [0; 48];
+        let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+        unsafe {
+            mq.MQINQ(
+                hconn,
+                hobj,
+                2,
+                selectors.as_mut_ptr(),
+                1,
+                ints.as_mut_ptr(),
+                48,
+                chars.as_mut_ptr(),
+                addr_of_mut!(cc),
+                addr_of_mut!(rc),
+            );
+        };
+        assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+        assert_eq!(ints, [1]);
+        assert_eq!(mq_str(&chars), "Q1");
+
+        // Nothing is copied to the empty, null, attribute arrays
+        unsafe {
+            mq.MQINQ(
+                hconn,
+                hobj,
+                2,
+                selectors.as_mut_ptr(),
+                0,
+                null_mut(),
+                0,
+                null_mut(),
+                addr_of_mut!(cc),
+                addr_of_mut!(rc),
+            );
+        };
+        assert_eq!((cc, rc), (lib::MQCC_WARNING, lib::MQRC_INT_ATTR_COUNT_TOO_SMALL));
+        unsafe {
+            mq.MQINQ(
+                hconn,
+                hobj,
+                2,
+                selectors.as_mut_ptr(),
+                1,
+                null_mut(),
+                0,
+                null_mut(),
+                addr_of_mut!(cc),
+                addr_of_mut!(rc),
+            );
+        };
+        assert_eq!((cc, rc), (lib::MQCC_FAILED, lib::MQRC_INT_ATTRS_ARRAY_ERROR));
+    }
+
+    #[test]
+    fn get_into_null_buffer() {
+        let mq = MockMq::new("QM1").with_queue("Q1");
+        let hconn = connect(&mq);
+        let (hobj, _) = open(&mq, hconn, "Q1", lib::MQOO_OUTPUT | lib::MQOO_INPUT_SHARED);
+        put(&mq, hconn, hobj, b"data", 0, 0);
+
+        let mut md = md();
+        let mut gmo = gmo(lib::MQGMO_ACCEPT_TRUNCATED_MSG);
+        let mut data_length = 0;
+        let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+        unsafe {
+            mq.MQGET(
+                hconn,
+                hobj,
+                addr_of_mut!(md).cast(),
+                addr_of_mut!(gmo).cast(),
+                0,
+                null_mut(),
+                addr_of_mut!(data_length),
+                addr_of_mut!(cc),
+                addr_of_mut!(rc),
+            );
+        };
+        assert_eq!(
+            (cc, rc, data_length),
+            (lib::MQCC_WARNING, lib::MQRC_TRUNCATED_MSG_ACCEPTED, 4)
+        );
+    }
+}
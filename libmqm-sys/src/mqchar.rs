@@ -0,0 +1,45 @@
+//! Conversion between Rust strings and the fixed length MQ character fields
+
+use std::slice;
+
+use crate::lib as mqsys;
+
+#[cfg(any(feature = "mock", feature = "tracing", feature = "fault", feature = "pcf"))]
+/// Convert a fixed length MQ character field into a `String`, trimming any trailing
+/// spaces or NUL terminator
+pub fn to_string(chars: &[mqsys::MQCHAR]) -> String {
+    let bytes = as_bytes(chars);
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).trim_end().to_string()
+}
+
+/// View a fixed length MQ character field as bytes
+pub const fn as_bytes(chars: &[mqsys::MQCHAR]) -> &[u8] {
+    // SAFETY: MQCHAR is a single byte
+    unsafe { slice::from_raw_parts(chars.as_ptr().cast::<u8>(), chars.len()) }
+}
+
+/// View bytes as a fixed length MQ character field
+#[cfg(feature = "exits")]
+pub const fn from_bytes(bytes: &[u8]) -> &[mqsys::MQCHAR] {
+    // SAFETY: MQCHAR is a single byte
+    unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<mqsys::MQCHAR>(), bytes.len()) }
+}
+
+#[cfg(feature = "mock")]
+/// Blank pad a string to a fixed length MQ character field
+pub fn padded(value: &str, length: usize) -> Vec<u8> {
+    let mut field = vec![b' '; length];
+    let bytes = value.as_bytes();
+    let copied = bytes.len().min(length);
+    field[..copied].copy_from_slice(&bytes[..copied]);
+    field
+}
+
+#[cfg(any(feature = "mock", feature = "pcf"))]
+/// Set a fixed length MQ character field to the blank padded value
+pub fn fill(field: &mut [mqsys::MQCHAR], value: &[u8]) {
+    for (c, b) in field.iter_mut().zip(value.iter().copied().chain(std::iter::repeat(b' '))) {
+        *c = mqsys::MQCHAR::from_ne_bytes([b]);
+    }
+}
@@ -0,0 +1,286 @@
+/*!
+ * Names of MQ constants without the `*_STR` functions of `cmqstrc.h`
+ *
+ * The build script generates a table of constant names for each `*_STR` group, such as `MQRC` or `MQOO`, from the
+ * bindings the crate is compiled with. The tables are available in every build, including pregenerated builds
+ * without a C compiler or MQ headers.
+ *
+ * Example
+ * -------
+ *
+ * ```
+ * use libmqm_sys::{lib, names};
+ *
+ * assert_eq!(names::name_of("MQRC", lib::MQRC_NOT_AUTHORIZED), Some("MQRC_NOT_AUTHORIZED"));
+ * assert_eq!(names::name_of("MQRC", -1), None);
+ *
+ * assert_eq!(names::value_of("MQRC", "MQRC_Q_FULL"), Ok(lib::MQRC_Q_FULL));
+ * assert_eq!(
+ *     names::parse_mask("MQGMO", "MQGMO_WAIT | MQGMO_SYNCPOINT"),
+ *     Ok(lib::MQGMO_WAIT | lib::MQGMO_SYNCPOINT)
+ * );
+ * assert!(names::value_of("MQOO", "MQGMO_WAIT").is_err());
+ *
+ * let options = lib::MQOO_OUTPUT | lib::MQOO_FAIL_IF_QUIESCING | 0x4000_0000;
+ * assert_eq!(names::decode_options("MQOO", options), ["MQOO_OUTPUT", "MQOO_FAIL_IF_QUIESCING"]);
+ * assert_eq!(names::unknown_options("MQOO", options), 0x4000_0000);
+ * ```
+ */
+
+use std::{cmp::Reverse, error::Error, fmt};
+
+use crate::lib::MQLONG;
+
+include!(concat!(env!("OUT_DIR"), "/names.rs"));
+
+/// Name or value is not a constant of the expected group
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NameError {
+    /// The text does not contain a name
+    Empty,
+    /// The name is not an MQ constant
+    Unknown(String),
+    /// The name is a constant of another group
+    WrongGroup {
+        /// Name of the constant
+        name: String,
+        /// Group that the constant belongs to
+        group: &'static str,
+    },
+}
+
+impl fmt::Display for NameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("no constant name"),
+            Self::Unknown(name) => write!(f, "{name} is not an MQ constant"),
+            Self::WrongGroup { name, group } => write!(f, "{name} is a constant of the {group} group"),
+        }
+    }
+}
+
+impl Error for NameError {}
+
+/// Entries of the group in a table that is sorted by group
+fn group<T>(table: &'static [(&'static str, &'static [T])], prefix: &str) -> Option<&'static [T]> {
+    table
+        .binary_search_by(|&(group, _)| group.cmp(prefix))
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// Name of the constant with the `value` in the `group`, where the group is the prefix of a `*_STR` function such
+/// as `MQRC` or `MQOO`. Returns `None` when the group or value is not known.
+#[must_use]
+pub fn name_of(group: &str, value: MQLONG) -> Option<&'static str> {
+    self::group(GROUPS, group).and_then(|names| {
+        names
+            .binary_search_by_key(&value, |&(value, _)| value)
+            .ok()
+            .map(|index| names[index].1)
+    })
+}
+
+/// Value of the constant in the group, or `None` when it is not in the group
+fn find_value(group: &str, name: &str) -> Option<MQLONG> {
+    self::group(VALUES, group).and_then(|values| {
+        values
+            .binary_search_by_key(&name, |&(name, _)| name)
+            .ok()
+            .map(|index| values[index].1)
+    })
+}
+
+/// Value of the constant `name` in the `group`, such as `MQRC_Q_FULL` in the `MQRC` group. Surrounding whitespace
+/// is ignored.
+///
+/// # Errors
+/// Returns a [`NameError`] when the name is empty or is not a constant of the group.
+pub fn value_of(group: &str, name: &str) -> Result<MQLONG, NameError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(NameError::Empty);
+    }
+    find_value(group, name).ok_or_else(|| {
+        VALUES
+            .iter()
+            .find(|&&(other, _)| find_value(other, name).is_some())
+            .map_or_else(
+                || NameError::Unknown(name.to_string()),
+                |&(other, _)| NameError::WrongGroup {
+                    name: name.to_string(),
+                    group: other,
+                },
+            )
+    })
+}
+
+/// Combined value of the constants in the `group` separated by `|`, such as `MQGMO_WAIT | MQGMO_SYNCPOINT`
+///
+/// # Errors
+/// Returns a [`NameError`] when any of the names is empty or is not a constant of the group.
+pub fn parse_mask(group: &str, names: &str) -> Result<MQLONG, NameError> {
+    names
+        .split('|')
+        .try_fold(0, |mask, name| value_of(group, name).map(|value| mask | value))
+}
+
+/// Canonical constants of an option mask sorted by value, and the bits that are not covered by a constant
+fn decompose(group: &str, value: MQLONG) -> (Vec<(MQLONG, &'static str)>, MQLONG) {
+    let Some(names) = self::group(GROUPS, group) else {
+        return (Vec::new(), value);
+    };
+    if value == 0 {
+        return (name_of(group, 0).map(|name| (0, name)).into_iter().collect(), 0);
+    }
+
+    // Constants with the most bits are matched first, so combined options such as MQGMO_BROWSE_HANDLE are named
+    // rather than the single bit options they contain
+    let mut candidates: Vec<_> = names
+        .iter()
+        .copied()
+        .filter(|&(option, _)| option > 0 && option & value == option)
+        .collect();
+    candidates.sort_unstable_by_key(|&(option, _)| (Reverse(option.count_ones()), option));
+
+    let mut remaining = value;
+    let mut decoded = Vec::new();
+    for (option, name) in candidates {
+        if option & remaining == option {
+            remaining &= !option;
+            decoded.push((option, name));
+        }
+    }
+    decoded.sort_unstable_by_key(|&(option, _)| option);
+    (decoded, remaining)
+}
+
+/// Names of the constants that make up the option mask `value` in the `group`, such as `MQGMO` or `MQRO`.
+///
+/// Constants that combine several bits are preferred over the single bit constants they contain. A zero mask is
+/// decoded as the zero constant of the group, such as `MQGMO_NONE`. Bits that are not covered by a constant are
+/// returned by [`unknown_options`].
+#[must_use]
+pub fn decode_options(group: &str, value: MQLONG) -> Vec<&'static str> {
+    decompose(group, value).0.into_iter().map(|(_, name)| name).collect()
+}
+
+/// Bits of the option mask `value` that are not decoded as a constant of the `group` by [`decode_options`]
+#[must_use]
+pub fn unknown_options(group: &str, value: MQLONG) -> MQLONG {
+    decompose(group, value).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lib as mqsys;
+
+    #[test]
+    fn groups_sorted() {
+        assert!(GROUPS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(GROUPS
+            .iter()
+            .all(|(_, names)| names.windows(2).all(|pair| pair[0].0 < pair[1].0)));
+        assert!(VALUES
+            .iter()
+            .map(|&(group, _)| group)
+            .eq(GROUPS.iter().map(|&(group, _)| group)));
+        assert!(VALUES
+            .iter()
+            .all(|(_, values)| values.windows(2).all(|pair| pair[0].0 < pair[1].0)));
+    }
+
+    #[test]
+    fn groups_named() {
+        // MQXC_* are only defined for z/OS
+        const EMPTY: &[&str] = &["MQXC"];
+        let empty: Vec<_> = GROUPS
+            .iter()
+            .filter(|(_, names)| names.is_empty())
+            .map(|&(group, _)| group)
+            .collect();
+        assert_eq!(empty, EMPTY);
+    }
+
+    #[test]
+    fn lookup() {
+        assert_eq!(name_of("MQCC", mqsys::MQCC_FAILED), Some("MQCC_FAILED"));
+        assert_eq!(name_of("MQRC", mqsys::MQRC_NOT_AUTHORIZED), Some("MQRC_NOT_AUTHORIZED"));
+        assert_eq!(name_of("MQOO", mqsys::MQOO_OUTPUT), Some("MQOO_OUTPUT"));
+        assert_eq!(name_of("MQCACF", 3001), Some("MQCACF_FROM_Q_NAME"));
+        assert_eq!(name_of("MQCA", 3001), None);
+        assert_eq!(name_of("MQRC", -1), None);
+        assert_eq!(name_of("MQNOTAGROUP", 0), None);
+        assert_eq!(name_of("MQS_STATUS", 3), Some("MQS_STATUS_OPEN"));
+        assert_eq!(name_of("MQRC", 6116), Some("MQRC_ZERO_LENGTH"));
+        assert_eq!(name_of("MQRFH", 32), None);
+        assert_eq!(
+            name_of("MQSUB_DURABILITY", mqsys::MQSUB_DURABLE_YES),
+            Some("MQSUB_DURABLE_YES")
+        );
+        assert_eq!(name_of("MQHO", mqsys::MQHO_UNUSABLE_HOBJ), Some("MQHO_UNUSABLE_HOBJ"));
+        assert_eq!(
+            value_of("MQRO", "MQRO_ACCEPT_UNSUP_MASK"),
+            Err(NameError::Unknown("MQRO_ACCEPT_UNSUP_MASK".to_string()))
+        );
+    }
+
+    #[test]
+    fn reverse_lookup() {
+        assert_eq!(value_of("MQOO", "MQOO_INPUT_SHARED"), Ok(mqsys::MQOO_INPUT_SHARED));
+        assert_eq!(value_of("MQOO", " MQOO_BIND_AS_Q_DEF "), Ok(0));
+        assert_eq!(value_of("MQRC", "MQRC_Q_FULL"), Ok(mqsys::MQRC_Q_FULL));
+        assert_eq!(
+            value_of("MQOO", "MQGMO_WAIT"),
+            Err(NameError::WrongGroup {
+                name: "MQGMO_WAIT".to_string(),
+                group: "MQGMO"
+            })
+        );
+        assert_eq!(
+            value_of("MQRC", "MQRC_NOT_A_REASON"),
+            Err(NameError::Unknown("MQRC_NOT_A_REASON".to_string()))
+        );
+        assert_eq!(value_of("MQRC", "  "), Err(NameError::Empty));
+    }
+
+    #[test]
+    fn mask() {
+        assert_eq!(
+            parse_mask("MQGMO", "MQGMO_WAIT | MQGMO_SYNCPOINT"),
+            Ok(mqsys::MQGMO_WAIT | mqsys::MQGMO_SYNCPOINT)
+        );
+        assert_eq!(parse_mask("MQOO", "MQOO_OUTPUT"), Ok(mqsys::MQOO_OUTPUT));
+        assert_eq!(parse_mask("MQOO", "MQOO_OUTPUT|"), Err(NameError::Empty));
+        assert!(matches!(
+            parse_mask("MQRO", "MQRO_COA|MQOO_OUTPUT"),
+            Err(NameError::WrongGroup { group: "MQOO", .. })
+        ));
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!(
+            decode_options("MQOO", mqsys::MQOO_INQUIRE | mqsys::MQOO_OUTPUT),
+            ["MQOO_OUTPUT", "MQOO_INQUIRE"]
+        );
+        assert_eq!(decode_options("MQGMO", 0), ["MQGMO_NONE"]);
+        assert_eq!(decode_options("MQRO", 0), ["MQRO_NONE"]);
+        assert_eq!(name_of("MQPMO", 0), Some("MQPMO_NONE"));
+        assert_eq!(
+            decode_options("MQGMO", mqsys::MQGMO_BROWSE_HANDLE | mqsys::MQGMO_WAIT),
+            ["MQGMO_WAIT", "MQGMO_BROWSE_HANDLE"]
+        );
+        assert_eq!(
+            decode_options("MQRO", mqsys::MQRO_EXCEPTION_WITH_FULL_DATA),
+            ["MQRO_EXCEPTION_WITH_FULL_DATA"]
+        );
+        assert_eq!(unknown_options("MQRO", mqsys::MQRO_EXCEPTION_WITH_FULL_DATA), 0);
+
+        let unknown = 1 << 30;
+        assert_eq!(unknown_options("MQOO", mqsys::MQOO_OUTPUT | unknown), unknown);
+        assert_eq!(decode_options("MQNOTAGROUP", 1), Vec::<&str>::new());
+        assert_eq!(unknown_options("MQNOTAGROUP", 1), 1);
+    }
+}
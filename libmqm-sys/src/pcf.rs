@@ -0,0 +1,1217 @@
+/*!
+ * Building and parsing of PCF messages in pure Rust
+ *
+ * [`PcfBuilder`] assembles a PCF message from an `MQCFH` header and its parameter structures, such as `MQCFIN`
+ * and `MQCFST`, without the MQ library. The `StrucLength`, `ParameterCount`, `StringLength` and
+ * `CodedCharSetId` fields are filled in, and strings are padded to a multiple of 4 bytes. The message is in the
+ * native encoding of the platform (`MQENC_NATIVE`) unless another is set by [`PcfBuilder::encoding`], and is ready
+ * to put to `SYSTEM.ADMIN.COMMAND.QUEUE` with the `MQFMT_ADMIN` format.
+ *
+ * [`PcfMessage::parse`] decodes a PCF response or event into the `MQCFH` header and a tree of [`PcfParameter`].
+ * The length and count fields of every structure are validated against the buffer, so malformed or truncated
+ * messages return a [`PcfError`].
+ *
+ * Messages from z/OS queue managers, or that arrive through channels without conversion, have big endian integers
+ * (`MQENC_INTEGER_NORMAL`) and EBCDIC strings. [`PcfMessage::parse_encoded`] honours the `Encoding` and
+ * `CodedCharSetId` of the MQMD and converts the strings to UTF-8 using the [`ccsid`](crate::ccsid) tables.
+ * [`PcfMessage::encode`] writes a message in another encoding and CCSID.
+ *
+ * [`PcfClient`] sends commands to the command server of a queue manager over any [`Mqi`](crate::Mqi) and
+ * collects all the responses to each command, with the `CompCode` and `Reason` of every response.
+ *
+ * Example
+ * -------
+ *
+ *  Inquire the current depth of the local queues starting with `APP.`
+ *
+ * ```
+ * use libmqm_sys::{lib, pcf::PcfBuilder};
+ *
+ * let message = PcfBuilder::command(lib::MQCMD_INQUIRE_Q)
+ *     .string(lib::MQCA_Q_NAME, "APP.*")
+ *     .integer(lib::MQIA_Q_TYPE, lib::MQQT_LOCAL)
+ *     .integer_list(lib::MQIACF_Q_ATTRS, &[lib::MQIA_CURRENT_Q_DEPTH])
+ *     .build();
+ *
+ * assert_eq!(message.len(), lib::MQCFH_STRUC_LENGTH + 28 + 16 + 20);
+ * ```
+ *
+ *  Read the parameters of a PCF message
+ *
+ * ```
+ * use libmqm_sys::{lib, pcf::{PcfBuilder, PcfMessage, PcfValue}};
+ *
+ * let buffer = PcfBuilder::new(lib::MQCFT_RESPONSE, lib::MQCMD_INQUIRE_Q)
+ *     .string(lib::MQCA_Q_NAME, "APP.IN")
+ *     .integer(lib::MQIA_CURRENT_Q_DEPTH, 10)
+ *     .build();
+ *
+ * let message = PcfMessage::parse(&buffer)?;
+ * assert_eq!(message.header.Command, lib::MQCMD_INQUIRE_Q);
+ * assert_eq!(message.find(lib::MQIA_CURRENT_Q_DEPTH), Some(&PcfValue::Integer(10)));
+ * # Ok::<(), libmqm_sys::pcf::PcfError>(())
+ * ```
+ *
+ *  Read an event from a z/OS queue manager
+ *
+ * ```
+ * use libmqm_sys::{lib, pcf::{PcfBuilder, PcfMessage, PcfValue}};
+ *
+ * let buffer = PcfBuilder::new(lib::MQCFT_EVENT, lib::MQCMD_Q_MGR_EVENT)
+ *     .encoding(lib::MQENC_INTEGER_NORMAL)
+ *     .string(lib::MQCA_Q_MGR_NAME, b"\xD8\xD4\xF1") // "QM1" in EBCDIC
+ *     .build();
+ *
+ * let message = PcfMessage::parse_encoded(&buffer, lib::MQENC_INTEGER_NORMAL, 500)?;
+ * assert_eq!(message.header.Command, lib::MQCMD_Q_MGR_EVENT);
+ * assert_eq!(
+ *     message.find(lib::MQCA_Q_MGR_NAME),
+ *     Some(&PcfValue::String { ccsid: 1208, value: b"QM1".to_vec() })
+ * );
+ * # Ok::<(), libmqm_sys::pcf::PcfError>(())
+ * ```
+ *
+ *  Send a command to the command server and check every response
+ *
+ * ```no_run
+ * use libmqm_sys::{error::MqCompCode, lib, pcf::{PcfBuilder, PcfClient, PcfClientError, PcfClientOptions}, Mqi};
+ *
+ * fn clear_queue(mq: &impl Mqi, hconn: lib::MQHCONN, queue: &str) -> Result<bool, PcfClientError> {
+ *     let client = PcfClient::open(mq, hconn, &PcfClientOptions::default())?;
+ *     let responses = client.execute(PcfBuilder::command(lib::MQCMD_CLEAR_Q).string(lib::MQCA_Q_NAME, queue))?;
+ *     Ok(responses.comp_code() == Ok(MqCompCode::Ok))
+ * }
+ * ```
+ */
+
+use std::{error::Error, fmt, mem};
+
+use crate::{
+    ccsid,
+    lib::{self as mqsys, MQINT64, MQLONG},
+};
+
+mod client;
+pub use client::{PcfClient, PcfClientError, PcfClientOptions, PcfResponses};
+
+/// Length of a PCF structure as an `MQLONG`
+///
+/// # Panics
+/// Panics when the length does not fit in an `MQLONG`
+fn mqlong(length: usize) -> MQLONG {
+    MQLONG::try_from(length).expect("PCF structure length exceeds MQLONG")
+}
+
+/// Byte order of the integers in a PCF message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ByteOrder {
+    /// Big endian, `MQENC_INTEGER_NORMAL`
+    Normal,
+    /// Little endian, `MQENC_INTEGER_REVERSED`
+    Reversed,
+}
+
+impl ByteOrder {
+    const NATIVE: Self = if cfg!(target_endian = "big") {
+        Self::Normal
+    } else {
+        Self::Reversed
+    };
+
+    /// Byte order of the integer encoding of an MQMD `Encoding`, which is native when it is not defined
+    const fn of(encoding: MQLONG) -> Self {
+        if encoding & mqsys::MQENC_INTEGER_REVERSED != 0 {
+            Self::Reversed
+        } else if encoding & mqsys::MQENC_INTEGER_NORMAL != 0 {
+            Self::Normal
+        } else {
+            Self::NATIVE
+        }
+    }
+
+    const fn long(self, bytes: [u8; 4]) -> MQLONG {
+        match self {
+            Self::Normal => MQLONG::from_be_bytes(bytes),
+            Self::Reversed => MQLONG::from_le_bytes(bytes),
+        }
+    }
+
+    const fn int64(self, bytes: [u8; 8]) -> MQINT64 {
+        match self {
+            Self::Normal => MQINT64::from_be_bytes(bytes),
+            Self::Reversed => MQINT64::from_le_bytes(bytes),
+        }
+    }
+
+    const fn long_bytes(self, value: MQLONG) -> [u8; 4] {
+        match self {
+            Self::Normal => value.to_be_bytes(),
+            Self::Reversed => value.to_le_bytes(),
+        }
+    }
+
+    const fn int64_bytes(self, value: MQINT64) -> [u8; 8] {
+        match self {
+            Self::Normal => value.to_be_bytes(),
+            Self::Reversed => value.to_le_bytes(),
+        }
+    }
+
+    /// MQMD `Encoding` of a message with the byte order, and the native encoding of floating point and decimals
+    const fn encoding(self) -> MQLONG {
+        let native = mqsys::MQENC_NATIVE & !(mqsys::MQENC_INTEGER_NORMAL | mqsys::MQENC_INTEGER_REVERSED);
+        match self {
+            Self::Normal => native | mqsys::MQENC_INTEGER_NORMAL,
+            Self::Reversed => native | mqsys::MQENC_INTEGER_REVERSED,
+        }
+    }
+}
+
+/// Convert the string `value` from the `from` CCSID to the `to` CCSID
+fn convert(from: MQLONG, to: MQLONG, value: &[u8]) -> Option<Vec<u8>> {
+    if from == to {
+        Some(value.to_vec())
+    } else {
+        ccsid::from_utf8(to, &ccsid::to_utf8(from, value)?)
+    }
+}
+
+/// Builder of a PCF message consisting of an `MQCFH` header followed by parameter structures
+///
+/// Strings are written with the coded character set set by [`PcfBuilder::ccsid`], which defaults to
+/// `MQCCSI_DEFAULT`. Integers are written in the byte order set by [`PcfBuilder::encoding`]. The parameter methods
+/// panic when the length of a structure exceeds an `MQLONG`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct PcfBuilder {
+    message_type: MQLONG,
+    version: MQLONG,
+    command: MQLONG,
+    msg_seq_number: MQLONG,
+    control: MQLONG,
+    comp_code: MQLONG,
+    reason: MQLONG,
+    ccsid: MQLONG,
+    byte_order: ByteOrder,
+    parameter_count: MQLONG,
+    parameters: Vec<u8>,
+}
+
+impl PcfBuilder {
+    /// New PCF message of `message_type`, such as `MQCFT_COMMAND` or `MQCFT_RESPONSE`, for the `command`
+    pub const fn new(message_type: MQLONG, command: MQLONG) -> Self {
+        Self {
+            message_type,
+            version: mqsys::MQCFH_VERSION_1,
+            command,
+            msg_seq_number: 1,
+            control: mqsys::MQCFC_LAST,
+            comp_code: mqsys::MQCC_OK,
+            reason: mqsys::MQRC_NONE,
+            ccsid: mqsys::MQCCSI_DEFAULT,
+            byte_order: ByteOrder::NATIVE,
+            parameter_count: 0,
+            parameters: Vec::new(),
+        }
+    }
+
+    /// New `MQCFT_COMMAND` PCF message for the `command`, such as `MQCMD_INQUIRE_Q`
+    pub const fn command(command: MQLONG) -> Self {
+        Self::new(mqsys::MQCFT_COMMAND, command)
+    }
+
+    /// Set the `Version` of the `MQCFH`, which defaults to `MQCFH_VERSION_1`
+    pub const fn version(mut self, version: MQLONG) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Set the `MsgSeqNumber` and `Control` of the `MQCFH`, which default to 1 and `MQCFC_LAST`
+    pub const fn sequence(mut self, msg_seq_number: MQLONG, control: MQLONG) -> Self {
+        self.msg_seq_number = msg_seq_number;
+        self.control = control;
+        self
+    }
+
+    /// Set the `CompCode` and `Reason` of the `MQCFH`, which default to `MQCC_OK` and `MQRC_NONE`
+    pub const fn completion(mut self, comp_code: MQLONG, reason: MQLONG) -> Self {
+        self.comp_code = comp_code;
+        self.reason = reason;
+        self
+    }
+
+    /// Set the `CodedCharSetId` of the string parameters that follow
+    pub const fn ccsid(mut self, ccsid: MQLONG) -> Self {
+        self.ccsid = ccsid;
+        self
+    }
+
+    /// Set the integer encoding of the message from the MQMD `Encoding`, such as `MQENC_INTEGER_NORMAL` for a z/OS
+    /// queue manager. Defaults to `MQENC_NATIVE`.
+    pub const fn encoding(mut self, encoding: MQLONG) -> Self {
+        self.byte_order = ByteOrder::of(encoding);
+        self
+    }
+
+    fn put_long(&mut self, value: MQLONG) {
+        self.parameters.extend_from_slice(&self.byte_order.long_bytes(value));
+    }
+
+    fn put_int64(&mut self, value: MQINT64) {
+        self.parameters.extend_from_slice(&self.byte_order.int64_bytes(value));
+    }
+
+    /// Write the bytes followed by nulls up to `length`
+    fn put_padded(&mut self, bytes: &[u8], length: usize) {
+        self.parameters.extend_from_slice(bytes);
+        self.parameters.resize(self.parameters.len() + length - bytes.len(), 0);
+    }
+
+    /// Write the `Type`, `StrucLength` and `Parameter` fields common to every parameter structure
+    fn put_parameter(&mut self, struc_type: MQLONG, struc_length: usize, parameter: MQLONG) {
+        self.parameter_count += 1;
+        self.put_long(struc_type);
+        self.put_long(mqlong(struc_length));
+        self.put_long(parameter);
+    }
+
+    /// Add an `MQCFIN` integer parameter
+    pub fn integer(mut self, parameter: MQLONG, value: MQLONG) -> Self {
+        self.put_parameter(mqsys::MQCFT_INTEGER, mqsys::MQCFIN_STRUC_LENGTH, parameter);
+        self.put_long(value);
+        self
+    }
+
+    /// Add an `MQCFIN64` 64-bit integer parameter
+    pub fn integer64(mut self, parameter: MQLONG, value: MQINT64) -> Self {
+        self.put_parameter(mqsys::MQCFT_INTEGER64, mqsys::MQCFIN64_STRUC_LENGTH, parameter);
+        self.put_long(0); // Reserved
+        self.put_int64(value);
+        self
+    }
+
+    /// Add an `MQCFIL` integer list parameter
+    pub fn integer_list(mut self, parameter: MQLONG, values: &[MQLONG]) -> Self {
+        let length = mqsys::MQCFIL_STRUC_LENGTH_FIXED + mem::size_of_val(values);
+        self.put_parameter(mqsys::MQCFT_INTEGER_LIST, length, parameter);
+        self.put_long(mqlong(values.len()));
+        for &value in values {
+            self.put_long(value);
+        }
+        self
+    }
+
+    /// Add an `MQCFIL64` 64-bit integer list parameter
+    pub fn integer64_list(mut self, parameter: MQLONG, values: &[MQINT64]) -> Self {
+        let length = mqsys::MQCFIL64_STRUC_LENGTH_FIXED + mem::size_of_val(values);
+        self.put_parameter(mqsys::MQCFT_INTEGER64_LIST, length, parameter);
+        self.put_long(mqlong(values.len()));
+        for &value in values {
+            self.put_int64(value);
+        }
+        self
+    }
+
+    /// Add an `MQCFST` string parameter
+    pub fn string(mut self, parameter: MQLONG, value: impl AsRef<[u8]>) -> Self {
+        let value = value.as_ref();
+        let padded = value.len().next_multiple_of(4);
+        self.put_parameter(mqsys::MQCFT_STRING, mqsys::MQCFST_STRUC_LENGTH_FIXED + padded, parameter);
+        self.put_long(self.ccsid);
+        self.put_long(mqlong(value.len()));
+        self.put_padded(value, padded);
+        self
+    }
+
+    /// Add an `MQCFSL` string list parameter. The strings are padded to the length of the longest with the space
+    /// of the CCSID, or an ASCII space when the CCSID is `MQCCSI_DEFAULT` or not supported.
+    pub fn string_list<S: AsRef<[u8]>>(mut self, parameter: MQLONG, values: &[S]) -> Self {
+        let string_length = values.iter().map(|value| value.as_ref().len()).max().unwrap_or(0);
+        let space = ccsid::from_utf8(self.ccsid, " ").map_or(b' ', |space| space[0]);
+        let padded = (values.len() * string_length).next_multiple_of(4);
+        self.put_parameter(mqsys::MQCFT_STRING_LIST, mqsys::MQCFSL_STRUC_LENGTH_FIXED + padded, parameter);
+        self.put_long(self.ccsid);
+        self.put_long(mqlong(values.len()));
+        self.put_long(mqlong(string_length));
+        let start = self.parameters.len();
+        for value in values {
+            let value = value.as_ref();
+            self.parameters.extend_from_slice(value);
+            self.parameters
+                .resize(self.parameters.len() + string_length - value.len(), space);
+        }
+        self.parameters.resize(start + padded, 0);
+        self
+    }
+
+    /// Add an `MQCFBS` byte string parameter
+    pub fn byte_string(mut self, parameter: MQLONG, value: &[u8]) -> Self {
+        let padded = value.len().next_multiple_of(4);
+        self.put_parameter(mqsys::MQCFT_BYTE_STRING, mqsys::MQCFBS_STRUC_LENGTH_FIXED + padded, parameter);
+        self.put_long(mqlong(value.len()));
+        self.put_padded(value, padded);
+        self
+    }
+
+    /// Add an `MQCFIF` integer filter parameter, where the `operator` is an `MQCFOP_*` value
+    pub fn integer_filter(mut self, parameter: MQLONG, operator: MQLONG, value: MQLONG) -> Self {
+        self.put_parameter(mqsys::MQCFT_INTEGER_FILTER, mqsys::MQCFIF_STRUC_LENGTH, parameter);
+        self.put_long(operator);
+        self.put_long(value);
+        self
+    }
+
+    /// Add an `MQCFSF` string filter parameter, where the `operator` is an `MQCFOP_*` value
+    pub fn string_filter(mut self, parameter: MQLONG, operator: MQLONG, value: impl AsRef<[u8]>) -> Self {
+        let value = value.as_ref();
+        let padded = value.len().next_multiple_of(4);
+        self.put_parameter(
+            mqsys::MQCFT_STRING_FILTER,
+            mqsys::MQCFSF_STRUC_LENGTH_FIXED + padded,
+            parameter,
+        );
+        self.put_long(operator);
+        self.put_long(self.ccsid);
+        self.put_long(mqlong(value.len()));
+        self.put_padded(value, padded);
+        self
+    }
+
+    /// Add an `MQCFBF` byte string filter parameter, where the `operator` is an `MQCFOP_*` value
+    pub fn byte_string_filter(mut self, parameter: MQLONG, operator: MQLONG, value: &[u8]) -> Self {
+        let padded = value.len().next_multiple_of(4);
+        self.put_parameter(
+            mqsys::MQCFT_BYTE_STRING_FILTER,
+            mqsys::MQCFBF_STRUC_LENGTH_FIXED + padded,
+            parameter,
+        );
+        self.put_long(operator);
+        self.put_long(mqlong(value.len()));
+        self.put_padded(value, padded);
+        self
+    }
+
+    /// Add an `MQCFGR` group parameter containing the parameters added by `build`
+    pub fn group(mut self, parameter: MQLONG, build: impl FnOnce(Self) -> Self) -> Self {
+        let mut group = Self::new(mqsys::MQCFT_NONE, 0).ccsid(self.ccsid);
+        group.byte_order = self.byte_order;
+        let group = build(group);
+        self.put_parameter(mqsys::MQCFT_GROUP, mqsys::MQCFGR_STRUC_LENGTH, parameter);
+        self.put_long(group.parameter_count);
+        self.parameters.extend_from_slice(&group.parameters);
+        self
+    }
+
+    /// The PCF message, starting with the `MQCFH` header
+    #[must_use]
+    pub fn build(self) -> Vec<u8> {
+        let mut message = Vec::with_capacity(mqsys::MQCFH_STRUC_LENGTH + self.parameters.len());
+        for field in [
+            self.message_type,
+            mqlong(mqsys::MQCFH_STRUC_LENGTH),
+            self.version,
+            self.command,
+            self.msg_seq_number,
+            self.control,
+            self.comp_code,
+            self.reason,
+            self.parameter_count,
+        ] {
+            message.extend_from_slice(&self.byte_order.long_bytes(field));
+        }
+        message.extend_from_slice(&self.parameters);
+        message
+    }
+
+    /// Add the parameter, converting its strings to the `target` CCSID. Strings that can not be converted keep
+    /// their CCSID.
+    fn parameter(self, target: MQLONG, PcfParameter { parameter, value }: &PcfParameter) -> Self {
+        let parameter = *parameter;
+        match value {
+            PcfValue::Integer(value) => self.integer(parameter, *value),
+            PcfValue::IntegerList(values) => self.integer_list(parameter, values),
+            PcfValue::Integer64(value) => self.integer64(parameter, *value),
+            PcfValue::Integer64List(values) => self.integer64_list(parameter, values),
+            PcfValue::String { ccsid, value } => match convert(*ccsid, target, value) {
+                Some(converted) => self.ccsid(target).string(parameter, converted),
+                None => self.ccsid(*ccsid).string(parameter, value),
+            },
+            PcfValue::StringList { ccsid, values } => {
+                match values
+                    .iter()
+                    .map(|value| convert(*ccsid, target, value))
+                    .collect::<Option<Vec<_>>>()
+                {
+                    Some(converted) => self.ccsid(target).string_list(parameter, &converted),
+                    None => self.ccsid(*ccsid).string_list(parameter, values),
+                }
+            }
+            PcfValue::ByteString(value) => self.byte_string(parameter, value),
+            PcfValue::IntegerFilter { operator, value } => self.integer_filter(parameter, *operator, *value),
+            PcfValue::StringFilter { operator, ccsid, value } => match convert(*ccsid, target, value) {
+                Some(converted) => self.ccsid(target).string_filter(parameter, *operator, converted),
+                None => self.ccsid(*ccsid).string_filter(parameter, *operator, value),
+            },
+            PcfValue::ByteStringFilter { operator, value } => self.byte_string_filter(parameter, *operator, value),
+            PcfValue::Group(parameters) => self.group(parameter, |group| {
+                parameters
+                    .iter()
+                    .fold(group, |group, parameter| group.parameter(target, parameter))
+            }),
+        }
+    }
+}
+
+/// Maximum nesting of `MQCFGR` groups accepted by [`PcfMessage::parse`]
+const MAX_GROUP_DEPTH: usize = 64;
+
+/// A PCF message that is malformed or truncated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PcfError {
+    /// The message ends before the structure at the offset
+    Truncated {
+        /// Offset of the structure in the message
+        offset: usize,
+    },
+    /// The `StrucLength` of the structure at the offset is not valid
+    StrucLength {
+        /// Offset of the structure in the message
+        offset: usize,
+        /// The `StrucLength` field
+        length: MQLONG,
+    },
+    /// A count or length field of the structure at the offset is negative or exceeds its `StrucLength`
+    Count {
+        /// Offset of the structure in the message
+        offset: usize,
+        /// The count or length field
+        count: MQLONG,
+    },
+    /// The `Type` of the structure at the offset is not a PCF parameter
+    UnknownType {
+        /// Offset of the structure in the message
+        offset: usize,
+        /// The `Type` field
+        struc_type: MQLONG,
+    },
+    /// The `MQCFGR` group at the offset is nested too deeply
+    GroupDepth {
+        /// Offset of the structure in the message
+        offset: usize,
+    },
+    /// The message continues after the last parameter
+    TrailingData {
+        /// Offset of the data following the last parameter
+        offset: usize,
+    },
+}
+
+impl fmt::Display for PcfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated { offset } => write!(f, "PCF message ends before the structure at offset {offset}"),
+            Self::StrucLength { offset, length } => write!(f, "PCF structure at offset {offset} has StrucLength {length}"),
+            Self::Count { offset, count } => write!(f, "PCF structure at offset {offset} has a count or length of {count}"),
+            Self::UnknownType { offset, struc_type } => {
+                write!(f, "PCF structure at offset {offset} has unknown Type {struc_type}")
+            }
+            Self::GroupDepth { offset } => write!(f, "PCF group at offset {offset} is nested too deeply"),
+            Self::TrailingData { offset } => write!(f, "PCF message continues after the last parameter at offset {offset}"),
+        }
+    }
+}
+
+impl Error for PcfError {}
+
+/// Typed value of a PCF parameter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PcfValue {
+    /// `MQCFIN` integer
+    Integer(MQLONG),
+    /// `MQCFIL` integer list
+    IntegerList(Vec<MQLONG>),
+    /// `MQCFIN64` 64-bit integer
+    Integer64(MQINT64),
+    /// `MQCFIL64` 64-bit integer list
+    Integer64List(Vec<MQINT64>),
+    /// `MQCFST` string in the coded character set
+    String {
+        /// The `CodedCharSetId` of the string
+        ccsid: MQLONG,
+        /// The string, including any trailing spaces
+        value: Vec<u8>,
+    },
+    /// `MQCFSL` string list in the coded character set
+    StringList {
+        /// The `CodedCharSetId` of the strings
+        ccsid: MQLONG,
+        /// The strings, each `StringLength` long
+        values: Vec<Vec<u8>>,
+    },
+    /// `MQCFBS` byte string
+    ByteString(Vec<u8>),
+    /// `MQCFIF` integer filter
+    IntegerFilter {
+        /// The `MQCFOP_*` operator
+        operator: MQLONG,
+        /// The filter value
+        value: MQLONG,
+    },
+    /// `MQCFSF` string filter
+    StringFilter {
+        /// The `MQCFOP_*` operator
+        operator: MQLONG,
+        /// The `CodedCharSetId` of the filter value
+        ccsid: MQLONG,
+        /// The filter value
+        value: Vec<u8>,
+    },
+    /// `MQCFBF` byte string filter
+    ByteStringFilter {
+        /// The `MQCFOP_*` operator
+        operator: MQLONG,
+        /// The filter value
+        value: Vec<u8>,
+    },
+    /// `MQCFGR` group of the parameters that follow it
+    Group(Vec<PcfParameter>),
+}
+
+/// A PCF parameter and its value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PcfParameter {
+    /// The `Parameter` field, such as `MQCA_Q_NAME`
+    pub parameter: MQLONG,
+    /// The value of the parameter
+    pub value: PcfValue,
+}
+
+/// A parsed PCF message
+#[derive(Debug, Clone)]
+pub struct PcfMessage {
+    /// The `MQCFH` header of the message
+    pub header: mqsys::MQCFH,
+    /// The parameters of the message
+    pub parameters: Vec<PcfParameter>,
+}
+
+/// Read the `MQLONG` at the offset
+fn long(order: ByteOrder, buffer: &[u8], offset: usize) -> Option<MQLONG> {
+    buffer
+        .get(offset..offset + mem::size_of::<MQLONG>())
+        .map(|bytes| order.long(bytes.try_into().expect("MQLONG length")))
+}
+
+/// Read the `MQINT64` at the offset
+fn int64(order: ByteOrder, buffer: &[u8], offset: usize) -> Option<MQINT64> {
+    buffer
+        .get(offset..offset + mem::size_of::<MQINT64>())
+        .map(|bytes| order.int64(bytes.try_into().expect("MQINT64 length")))
+}
+
+/// Fixed length of the PCF parameter structure with the `Type`
+const fn fixed_length(struc_type: MQLONG) -> Option<usize> {
+    Some(match struc_type {
+        mqsys::MQCFT_INTEGER => mqsys::MQCFIN_STRUC_LENGTH,
+        mqsys::MQCFT_INTEGER_LIST => mqsys::MQCFIL_STRUC_LENGTH_FIXED,
+        mqsys::MQCFT_INTEGER64 => mqsys::MQCFIN64_STRUC_LENGTH,
+        mqsys::MQCFT_INTEGER64_LIST => mqsys::MQCFIL64_STRUC_LENGTH_FIXED,
+        mqsys::MQCFT_STRING => mqsys::MQCFST_STRUC_LENGTH_FIXED,
+        mqsys::MQCFT_STRING_LIST => mqsys::MQCFSL_STRUC_LENGTH_FIXED,
+        mqsys::MQCFT_BYTE_STRING => mqsys::MQCFBS_STRUC_LENGTH_FIXED,
+        mqsys::MQCFT_INTEGER_FILTER => mqsys::MQCFIF_STRUC_LENGTH,
+        mqsys::MQCFT_STRING_FILTER => mqsys::MQCFSF_STRUC_LENGTH_FIXED,
+        mqsys::MQCFT_BYTE_STRING_FILTER => mqsys::MQCFBF_STRUC_LENGTH_FIXED,
+        mqsys::MQCFT_GROUP => mqsys::MQCFGR_STRUC_LENGTH,
+        _ => return None,
+    })
+}
+
+/// A PCF parameter structure that is within the message and at least its fixed length
+struct Structure<'a> {
+    order: ByteOrder,
+    offset: usize,
+    struc: &'a [u8],
+    fixed: usize,
+}
+
+impl<'a> Structure<'a> {
+    /// The `MQLONG` field at the index of the fixed part of the structure
+    fn field(&self, index: usize) -> MQLONG {
+        long(self.order, self.struc, index * mem::size_of::<MQLONG>()).expect("field within the fixed length")
+    }
+
+    /// A count or length field that must not be negative
+    fn count(&self, index: usize) -> Result<usize, PcfError> {
+        let count = self.field(index);
+        usize::try_from(count).map_err(|_| PcfError::Count {
+            offset: self.offset,
+            count,
+        })
+    }
+
+    /// The data following the fixed part of the structure of `items` elements of `size` bytes, where the number of
+    /// items is read from the field at the index
+    fn data(&self, index: usize, size: usize) -> Result<&'a [u8], PcfError> {
+        let count = self.count(index)?;
+        count
+            .checked_mul(size)
+            .and_then(|length| self.struc.get(self.fixed..self.fixed.checked_add(length)?))
+            .ok_or_else(|| PcfError::Count {
+                offset: self.offset,
+                count: self.field(index),
+            })
+    }
+}
+
+/// Byte order and string conversion of a message being parsed
+struct Decoder {
+    order: ByteOrder,
+    /// CCSID of the strings with `MQCCSI_DEFAULT`
+    ccsid: MQLONG,
+    /// Convert the strings to UTF-8
+    to_utf8: bool,
+}
+
+impl Decoder {
+    /// The string converted to UTF-8, when requested and supported
+    fn utf8(&self, ccsid: MQLONG, value: &[u8]) -> Option<Vec<u8>> {
+        let source = if ccsid == mqsys::MQCCSI_DEFAULT { self.ccsid } else { ccsid };
+        self.to_utf8
+            .then(|| ccsid::to_utf8(source, value))
+            .flatten()
+            .map(String::into_bytes)
+    }
+
+    /// The CCSID and value of a string, converted to UTF-8 when requested and supported
+    fn string(&self, ccsid: MQLONG, value: &[u8]) -> (MQLONG, Vec<u8>) {
+        self.utf8(ccsid, value)
+            .map_or_else(|| (ccsid, value.to_vec()), |converted| (mqsys::MQCCSI_APPL, converted))
+    }
+
+    /// The strings of an `MQCFSL`, which are converted together or all keep the CCSID of the structure
+    fn string_list(&self, structure: &Structure) -> Result<PcfValue, PcfError> {
+        let (ccsid, count, string_length) = (structure.field(3), structure.count(4)?, structure.count(5)?);
+        let data = structure.data(4, string_length)?;
+        if string_length == 0 {
+            // Zero length strings have no data to bound the count
+            return if count > structure.struc.len() {
+                Err(PcfError::Count {
+                    offset: structure.offset,
+                    count: structure.field(4),
+                })
+            } else {
+                Ok(PcfValue::StringList {
+                    ccsid,
+                    values: vec![Vec::new(); count],
+                })
+            };
+        }
+
+        let strings = data.chunks_exact(string_length);
+        let converted = strings
+            .clone()
+            .map(|value| self.utf8(ccsid, value))
+            .collect::<Option<Vec<_>>>();
+        Ok(converted.map_or_else(
+            || PcfValue::StringList {
+                ccsid,
+                values: strings.map(<[u8]>::to_vec).collect(),
+            },
+            |values| PcfValue::StringList {
+                ccsid: mqsys::MQCCSI_APPL,
+                values,
+            },
+        ))
+    }
+
+    /// Parse `count` parameters starting at the offset, advancing the offset past them
+    fn parse_parameters(
+        &self,
+        buffer: &[u8],
+        offset: &mut usize,
+        count: usize,
+        depth: usize,
+    ) -> Result<Vec<PcfParameter>, PcfError> {
+        (0..count).map(|_| self.parse_parameter(buffer, offset, depth)).collect()
+    }
+
+    /// Parse the parameter at the offset, advancing the offset past it and the parameters of a group
+    fn parse_parameter(&self, buffer: &[u8], offset: &mut usize, depth: usize) -> Result<PcfParameter, PcfError> {
+        let start = *offset;
+        let rest = &buffer[start..];
+        let (struc_type, struc_length) = long(self.order, rest, 0)
+            .zip(long(self.order, rest, 4))
+            .ok_or(PcfError::Truncated { offset: start })?;
+        let fixed = fixed_length(struc_type).ok_or(PcfError::UnknownType {
+            offset: start,
+            struc_type,
+        })?;
+        let length = usize::try_from(struc_length)
+            .ok()
+            .filter(|&length| length >= fixed)
+            .ok_or(PcfError::StrucLength {
+                offset: start,
+                length: struc_length,
+            })?;
+        let struc = rest.get(..length).ok_or(PcfError::Truncated { offset: start })?;
+        let structure = Structure {
+            order: self.order,
+            offset: start,
+            struc,
+            fixed,
+        };
+        *offset = start + length;
+
+        let value = match struc_type {
+            mqsys::MQCFT_INTEGER => PcfValue::Integer(structure.field(3)),
+            mqsys::MQCFT_INTEGER64 => PcfValue::Integer64(int64(self.order, struc, 16).expect("value within the fixed length")),
+            mqsys::MQCFT_INTEGER_LIST => PcfValue::IntegerList(
+                structure
+                    .data(3, mem::size_of::<MQLONG>())?
+                    .chunks_exact(mem::size_of::<MQLONG>())
+                    .filter_map(|bytes| long(self.order, bytes, 0))
+                    .collect(),
+            ),
+            mqsys::MQCFT_INTEGER64_LIST => PcfValue::Integer64List(
+                structure
+                    .data(3, mem::size_of::<MQINT64>())?
+                    .chunks_exact(mem::size_of::<MQINT64>())
+                    .filter_map(|bytes| int64(self.order, bytes, 0))
+                    .collect(),
+            ),
+            mqsys::MQCFT_STRING => {
+                let (ccsid, value) = self.string(structure.field(3), structure.data(4, 1)?);
+                PcfValue::String { ccsid, value }
+            }
+            mqsys::MQCFT_STRING_LIST => self.string_list(&structure)?,
+            mqsys::MQCFT_BYTE_STRING => PcfValue::ByteString(structure.data(3, 1)?.to_vec()),
+            mqsys::MQCFT_INTEGER_FILTER => PcfValue::IntegerFilter {
+                operator: structure.field(3),
+                value: structure.field(4),
+            },
+            mqsys::MQCFT_STRING_FILTER => {
+                let (ccsid, value) = self.string(structure.field(4), structure.data(5, 1)?);
+                PcfValue::StringFilter {
+                    operator: structure.field(3),
+                    ccsid,
+                    value,
+                }
+            }
+            mqsys::MQCFT_BYTE_STRING_FILTER => PcfValue::ByteStringFilter {
+                operator: structure.field(3),
+                value: structure.data(4, 1)?.to_vec(),
+            },
+            _ => {
+                // MQCFT_GROUP, the parameters of the group follow the MQCFGR
+                if depth >= MAX_GROUP_DEPTH {
+                    return Err(PcfError::GroupDepth { offset: start });
+                }
+                PcfValue::Group(self.parse_parameters(buffer, offset, structure.count(3)?, depth + 1)?)
+            }
+        };
+
+        Ok(PcfParameter {
+            parameter: structure.field(2),
+            value,
+        })
+    }
+}
+
+impl PcfMessage {
+    /// Parse a PCF message in the native encoding, such as a command response or an event. Strings keep their
+    /// CCSID.
+    ///
+    /// # Errors
+    /// Returns a [`PcfError`] when the message is truncated, a structure is not valid or data follows the last
+    /// parameter.
+    pub fn parse(buffer: &[u8]) -> Result<Self, PcfError> {
+        Self::parse_with(
+            buffer,
+            &Decoder {
+                order: ByteOrder::NATIVE,
+                ccsid: mqsys::MQCCSI_DEFAULT,
+                to_utf8: false,
+            },
+        )
+    }
+
+    /// Parse a PCF message with the `Encoding` and `CodedCharSetId` of its MQMD. Strings in a supported CCSID are
+    /// converted to UTF-8 and have the `MQCCSI_APPL` (1208) CCSID. Strings with `MQCCSI_DEFAULT` are in the `ccsid`
+    /// of the message.
+    ///
+    /// # Errors
+    /// Returns a [`PcfError`] when the message is truncated, a structure is not valid or data follows the last
+    /// parameter.
+    pub fn parse_encoded(buffer: &[u8], encoding: MQLONG, ccsid: MQLONG) -> Result<Self, PcfError> {
+        Self::parse_with(
+            buffer,
+            &Decoder {
+                order: ByteOrder::of(encoding),
+                ccsid,
+                to_utf8: true,
+            },
+        )
+    }
+
+    fn parse_with(buffer: &[u8], decoder: &Decoder) -> Result<Self, PcfError> {
+        let field =
+            |index: usize| long(decoder.order, buffer, index * mem::size_of::<MQLONG>()).ok_or(PcfError::Truncated { offset: 0 });
+        let header = mqsys::MQCFH {
+            Type: field(0)?,
+            StrucLength: field(1)?,
+            Version: field(2)?,
+            Command: field(3)?,
+            MsgSeqNumber: field(4)?,
+            Control: field(5)?,
+            CompCode: field(6)?,
+            Reason: field(7)?,
+            ParameterCount: field(8)?,
+        };
+
+        let mut offset = usize::try_from(header.StrucLength)
+            .ok()
+            .filter(|length| (mqsys::MQCFH_STRUC_LENGTH..=buffer.len()).contains(length))
+            .ok_or(PcfError::StrucLength {
+                offset: 0,
+                length: header.StrucLength,
+            })?;
+        let count = usize::try_from(header.ParameterCount).map_err(|_| PcfError::Count {
+            offset: 0,
+            count: header.ParameterCount,
+        })?;
+        let parameters = decoder.parse_parameters(buffer, &mut offset, count, 0)?;
+
+        if offset == buffer.len() {
+            Ok(Self { header, parameters })
+        } else {
+            Err(PcfError::TrailingData { offset })
+        }
+    }
+
+    /// Encode the message with the integer encoding of the MQMD `encoding`, converting the strings to the `ccsid`.
+    /// Strings that can not be converted keep their CCSID.
+    #[must_use]
+    pub fn encode(&self, encoding: MQLONG, ccsid: MQLONG) -> Vec<u8> {
+        let header = &self.header;
+        let builder = PcfBuilder::new(header.Type, header.Command)
+            .version(header.Version)
+            .sequence(header.MsgSeqNumber, header.Control)
+            .completion(header.CompCode, header.Reason)
+            .encoding(encoding);
+        self.parameters
+            .iter()
+            .fold(builder, |builder, parameter| builder.parameter(ccsid, parameter))
+            .build()
+    }
+
+    /// Value of the first top level parameter with the `parameter` identifier
+    #[must_use]
+    pub fn find(&self, parameter: MQLONG) -> Option<&PcfValue> {
+        self.parameters
+            .iter()
+            .find(|candidate| candidate.parameter == parameter)
+            .map(|candidate| &candidate.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    /// Read a structure from the message at the offset
+    fn read<T>(message: &[u8], offset: usize) -> T {
+        assert!(offset + mem::size_of::<T>() <= message.len(), "structure within the message");
+        unsafe { ptr::read_unaligned(message.as_ptr().add(offset).cast()) }
+    }
+
+    #[test]
+    fn header() {
+        let message = PcfBuilder::command(mqsys::MQCMD_INQUIRE_Q_MGR).build();
+        let cfh: mqsys::MQCFH = read(&message, 0);
+        assert_eq!(message.len(), mqsys::MQCFH_STRUC_LENGTH);
+        assert_eq!(
+            (cfh.Type, cfh.StrucLength, cfh.Version, cfh.Command),
+            (mqsys::MQCFT_COMMAND, 36, mqsys::MQCFH_VERSION_1, mqsys::MQCMD_INQUIRE_Q_MGR)
+        );
+        assert_eq!((cfh.MsgSeqNumber, cfh.Control, cfh.ParameterCount), (1, mqsys::MQCFC_LAST, 0));
+    }
+
+    #[test]
+    fn parameters() {
+        let message = PcfBuilder::command(mqsys::MQCMD_INQUIRE_Q)
+            .ccsid(1208)
+            .string(mqsys::MQCA_Q_NAME, "APP.*")
+            .integer(mqsys::MQIA_Q_TYPE, mqsys::MQQT_LOCAL)
+            .string_list(mqsys::MQCACF_Q_NAMES, &["A", "BCD"])
+            .integer64(MQLONG::try_from(mqsys::MQIAMO64_BROWSE_BYTES).expect("parameter fits"), -2)
+            .build();
+
+        let cfh: mqsys::MQCFH = read(&message, 0);
+        assert_eq!(cfh.ParameterCount, 4);
+
+        let cfst: mqsys::MQCFST = read(&message, 36);
+        assert_eq!((cfst.Type, cfst.StrucLength), (mqsys::MQCFT_STRING, 28));
+        assert_eq!((cfst.CodedCharSetId, cfst.StringLength), (1208, 5));
+        assert_eq!(&message[56..64], b"APP.*\0\0\0");
+
+        let cfin: mqsys::MQCFIN = read(&message, 64);
+        assert_eq!(
+            (cfin.Type, cfin.StrucLength, cfin.Value),
+            (mqsys::MQCFT_INTEGER, 16, mqsys::MQQT_LOCAL)
+        );
+
+        let cfsl: mqsys::MQCFSL = read(&message, 80);
+        assert_eq!((cfsl.StrucLength, cfsl.Count, cfsl.StringLength), (32, 2, 3));
+        assert_eq!(&message[104..112], b"A  BCD\0\0");
+
+        let cfin64: mqsys::MQCFIN64 = read(&message, 112);
+        assert_eq!(
+            (cfin64.Type, cfin64.StrucLength, cfin64.Value),
+            (mqsys::MQCFT_INTEGER64, 24, -2)
+        );
+        assert_eq!(message.len(), 136);
+    }
+
+    #[test]
+    fn group() {
+        let message = PcfBuilder::new(mqsys::MQCFT_RESPONSE, mqsys::MQCMD_INQUIRE_Q_STATUS)
+            .completion(mqsys::MQCC_FAILED, mqsys::MQRC_NOT_AUTHORIZED)
+            .group(mqsys::MQGACF_OPERATION, |group| {
+                group
+                    .integer(mqsys::MQIACF_PROCESS_ID, 1)
+                    .byte_string(mqsys::MQBACF_CONNECTION_ID, &[1; 24])
+            })
+            .integer(mqsys::MQIA_OPEN_INPUT_COUNT, 0)
+            .build();
+
+        let cfh: mqsys::MQCFH = read(&message, 0);
+        assert_eq!(
+            (cfh.CompCode, cfh.Reason, cfh.ParameterCount),
+            (mqsys::MQCC_FAILED, mqsys::MQRC_NOT_AUTHORIZED, 2)
+        );
+
+        let cfgr: mqsys::MQCFGR = read(&message, 36);
+        assert_eq!(
+            (cfgr.Type, cfgr.StrucLength, cfgr.ParameterCount),
+            (mqsys::MQCFT_GROUP, 16, 2)
+        );
+
+        let cfbs: mqsys::MQCFBS = read(&message, 68);
+        assert_eq!(
+            (cfbs.Type, cfbs.StrucLength, cfbs.StringLength),
+            (mqsys::MQCFT_BYTE_STRING, 40, 24)
+        );
+        assert_eq!(message.len(), 36 + 16 + 16 + 40 + 16);
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        let buffer = PcfBuilder::new(mqsys::MQCFT_RESPONSE, mqsys::MQCMD_INQUIRE_Q)
+            .ccsid(1208)
+            .string(mqsys::MQCA_Q_NAME, "APP.IN")
+            .integer_list(mqsys::MQIACF_Q_ATTRS, &[1, 2])
+            .integer64_list(1, &[-1, i64::MAX])
+            .string_list(mqsys::MQCACF_Q_NAMES, &["A", "BC"])
+            .string_list(mqsys::MQCACF_Q_NAMES, &["", ""])
+            .group(mqsys::MQGACF_OPERATION, |group| {
+                group.integer64(2, 5).group(3, |inner| inner.byte_string(4, &[9; 3]))
+            })
+            .integer_filter(5, mqsys::MQCFOP_GREATER, 7)
+            .string_filter(6, mqsys::MQCFOP_LIKE, "X*")
+            .byte_string_filter(7, mqsys::MQCFOP_EQUAL, &[1])
+            .build();
+
+        let message = PcfMessage::parse(&buffer).expect("valid message");
+        assert_eq!(
+            (message.header.Type, message.header.ParameterCount),
+            (mqsys::MQCFT_RESPONSE, 9)
+        );
+        assert_eq!(
+            message.find(mqsys::MQCA_Q_NAME),
+            Some(&PcfValue::String {
+                ccsid: 1208,
+                value: b"APP.IN".to_vec()
+            })
+        );
+        assert_eq!(message.find(mqsys::MQIACF_Q_ATTRS), Some(&PcfValue::IntegerList(vec![1, 2])));
+        assert_eq!(message.find(1), Some(&PcfValue::Integer64List(vec![-1, i64::MAX])));
+        assert_eq!(
+            message.parameters[3].value,
+            PcfValue::StringList {
+                ccsid: 1208,
+                values: vec![b"A ".to_vec(), b"BC".to_vec()]
+            }
+        );
+        assert_eq!(
+            message.parameters[4].value,
+            PcfValue::StringList {
+                ccsid: 1208,
+                values: vec![Vec::new(), Vec::new()]
+            }
+        );
+        assert_eq!(
+            message.find(mqsys::MQGACF_OPERATION),
+            Some(&PcfValue::Group(vec![
+                PcfParameter {
+                    parameter: 2,
+                    value: PcfValue::Integer64(5)
+                },
+                PcfParameter {
+                    parameter: 3,
+                    value: PcfValue::Group(vec![PcfParameter {
+                        parameter: 4,
+                        value: PcfValue::ByteString(vec![9; 3])
+                    }])
+                }
+            ]))
+        );
+        assert_eq!(
+            message.find(6),
+            Some(&PcfValue::StringFilter {
+                operator: mqsys::MQCFOP_LIKE,
+                ccsid: 1208,
+                value: b"X*".to_vec()
+            })
+        );
+    }
+
+    #[test]
+    fn parse_malformed() {
+        let buffer = PcfBuilder::command(mqsys::MQCMD_INQUIRE_Q)
+            .string(mqsys::MQCA_Q_NAME, "APP.IN")
+            .integer(mqsys::MQIA_Q_TYPE, mqsys::MQQT_LOCAL)
+            .build();
+        let patched = |offset: usize, value: MQLONG| {
+            let mut buffer = buffer.clone();
+            buffer[offset..offset + 4].copy_from_slice(&value.to_ne_bytes());
+            buffer
+        };
+
+        assert!(PcfMessage::parse(&buffer).is_ok());
+        assert_eq!(
+            PcfMessage::parse(&buffer[..20]).err(),
+            Some(PcfError::Truncated { offset: 0 })
+        );
+        assert_eq!(
+            PcfMessage::parse(&buffer[..buffer.len() - 4]).err(),
+            Some(PcfError::Truncated { offset: 64 })
+        );
+        assert_eq!(
+            PcfMessage::parse(&[buffer.as_slice(), &[0; 4]].concat()).err(),
+            Some(PcfError::TrailingData { offset: 80 })
+        );
+        assert_eq!(
+            PcfMessage::parse(&patched(32, 1)).err(),
+            Some(PcfError::TrailingData { offset: 64 })
+        );
+        assert_eq!(
+            PcfMessage::parse(&patched(32, 3)).err(),
+            Some(PcfError::Truncated { offset: 80 })
+        );
+        assert_eq!(
+            PcfMessage::parse(&patched(32, -1)).err(),
+            Some(PcfError::Count { offset: 0, count: -1 })
+        );
+        assert_eq!(
+            PcfMessage::parse(&patched(40, 12)).err(),
+            Some(PcfError::StrucLength { offset: 36, length: 12 })
+        );
+        assert_eq!(
+            PcfMessage::parse(&patched(52, 100)).err(),
+            Some(PcfError::Count { offset: 36, count: 100 })
+        );
+        assert_eq!(
+            PcfMessage::parse(&patched(36, 99)).err(),
+            Some(PcfError::UnknownType {
+                offset: 36,
+                struc_type: 99
+            })
+        );
+
+        // Groups nested one deeper than the maximum
+        let depth = (0..=MAX_GROUP_DEPTH).fold(Vec::new(), |inner, _| {
+            [
+                &mqsys::MQCFT_GROUP.to_ne_bytes()[..],
+                &16i32.to_ne_bytes(),
+                &1i32.to_ne_bytes(),
+                &1i32.to_ne_bytes(),
+                &inner,
+            ]
+            .concat()
+        });
+        let mut deep = PcfBuilder::command(mqsys::MQCMD_INQUIRE_Q).build();
+        deep[32..36].copy_from_slice(&1i32.to_ne_bytes());
+        deep.extend_from_slice(&depth);
+        assert!(matches!(PcfMessage::parse(&deep), Err(PcfError::GroupDepth { .. })));
+    }
+
+    #[test]
+    fn big_endian() {
+        let buffer = PcfBuilder::new(mqsys::MQCFT_EVENT, mqsys::MQCMD_Q_MGR_EVENT)
+            .encoding(mqsys::MQENC_INTEGER_NORMAL)
+            .integer(mqsys::MQIACF_REASON_QUALIFIER, 0x0102_0304)
+            .integer64_list(1, &[0x0102_0304_0506_0708])
+            .build();
+        assert_eq!(&buffer[..8], &[0, 0, 0, 7, 0, 0, 0, 36]);
+        assert_eq!(&buffer[48..52], &[1, 2, 3, 4]);
+
+        let message = PcfMessage::parse_encoded(&buffer, mqsys::MQENC_INTEGER_NORMAL, 1208).expect("valid message");
+        assert_eq!((message.header.Type, message.header.ParameterCount), (mqsys::MQCFT_EVENT, 2));
+        assert_eq!(
+            message.find(mqsys::MQIACF_REASON_QUALIFIER),
+            Some(&PcfValue::Integer(0x0102_0304))
+        );
+        assert_eq!(message.find(1), Some(&PcfValue::Integer64List(vec![0x0102_0304_0506_0708])));
+
+        let native = message.encode(mqsys::MQENC_NATIVE, 1208);
+        let reparsed = PcfMessage::parse(&native).expect("valid message");
+        assert_eq!(reparsed.parameters, message.parameters);
+        if cfg!(target_endian = "little") {
+            assert!(PcfMessage::parse(&buffer).is_err());
+        }
+    }
+
+    #[test]
+    fn ebcdic() {
+        let buffer = PcfBuilder::new(mqsys::MQCFT_EVENT, mqsys::MQCMD_Q_MGR_EVENT)
+            .encoding(mqsys::MQENC_INTEGER_NORMAL)
+            .string(mqsys::MQCA_Q_MGR_NAME, b"\xD8\xD4\xF1") // QM1 with MQCCSI_DEFAULT
+            .ccsid(37)
+            .string_list(mqsys::MQCACF_Q_NAMES, &[&b"\xC1"[..], b"\xC2\xC3"]) // A and BC
+            .ccsid(1200)
+            .string(mqsys::MQCA_Q_NAME, b"\x00Q")
+            .build();
+        assert_eq!(&buffer[84..87], b"\xC1\x40\xC2", "padded with EBCDIC spaces");
+
+        let message = PcfMessage::parse_encoded(&buffer, mqsys::MQENC_INTEGER_NORMAL, 500).expect("valid message");
+        assert_eq!(
+            message.find(mqsys::MQCA_Q_MGR_NAME),
+            Some(&PcfValue::String {
+                ccsid: 1208,
+                value: b"QM1".to_vec()
+            })
+        );
+        assert_eq!(
+            message.find(mqsys::MQCACF_Q_NAMES),
+            Some(&PcfValue::StringList {
+                ccsid: 1208,
+                values: vec![b"A ".to_vec(), b"BC".to_vec()]
+            })
+        );
+        assert_eq!(
+            message.find(mqsys::MQCA_Q_NAME),
+            Some(&PcfValue::String {
+                ccsid: 1200,
+                value: b"\x00Q".to_vec()
+            }),
+            "unsupported CCSID is not converted"
+        );
+
+        let encoded = message.encode(mqsys::MQENC_INTEGER_NORMAL, 500);
+        let reparsed = PcfMessage::parse_encoded(&encoded, mqsys::MQENC_INTEGER_NORMAL, 0).expect("valid message");
+        assert_eq!(reparsed.parameters, message.parameters);
+    }
+}
@@ -0,0 +1,458 @@
+//! PCF command and response client over the [`Mqi`] function calls
+
+use std::{
+    error::Error,
+    fmt, mem,
+    ptr::{self, addr_of_mut},
+    time::{Duration, Instant},
+};
+
+use super::{PcfBuilder, PcfError, PcfMessage};
+use crate::{
+    error::{InvalidCode, MqCompCode, MqError, MqReason},
+    function::Mqi,
+    lib::{self as mqsys, MQLONG},
+    mqchar::{fill, to_string as mq_str},
+};
+
+/// Initial length of the buffer that responses are read into. The buffer grows to fit larger responses.
+const BUFFER_LENGTH: usize = 4096;
+
+/// `MQMD` with the `StrucId`, `Version` and the defaults of `MQMD_DEFAULT` that are not zero
+fn md() -> mqsys::MQMD {
+    // SAFETY: the MQMD is plain data that is valid when zeroed
+    let mut md: mqsys::MQMD = unsafe { mem::zeroed() };
+    fill(&mut md.StrucId, mqsys::MQMD_STRUC_ID.to_bytes());
+    md.Version = mqsys::MQMD_VERSION_1;
+    md.MsgType = mqsys::MQMT_DATAGRAM;
+    md.Expiry = mqsys::MQEI_UNLIMITED;
+    md.Encoding = mqsys::MQENC_NATIVE;
+    md.CodedCharSetId = mqsys::MQCCSI_Q_MGR;
+    md.Priority = mqsys::MQPRI_PRIORITY_AS_Q_DEF;
+    md.Persistence = mqsys::MQPER_NOT_PERSISTENT;
+    fill(&mut md.Format, mqsys::MQFMT_NONE.to_bytes());
+    fill(&mut md.ReplyToQ, b"");
+    fill(&mut md.ReplyToQMgr, b"");
+    md
+}
+
+/// `MQOD` of the queue with the `StrucId` and `Version`
+fn od(name: &str) -> mqsys::MQOD {
+    // SAFETY: the MQOD is plain data that is valid when zeroed
+    let mut od: mqsys::MQOD = unsafe { mem::zeroed() };
+    fill(&mut od.StrucId, mqsys::MQOD_STRUC_ID.to_bytes());
+    od.Version = mqsys::MQOD_VERSION_1;
+    od.ObjectType = mqsys::MQOT_Q;
+    fill(&mut od.ObjectName, name.as_bytes());
+    fill(&mut od.ObjectQMgrName, b"");
+    fill(&mut od.DynamicQName, b"");
+    od
+}
+
+/// Queues and timeout of a [`PcfClient`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PcfClientOptions {
+    /// Queue that the commands are put to
+    pub command_queue: String,
+    /// Model queue that the reply queue is created from
+    pub model_queue: String,
+    /// `DynamicQName` of the reply queue, where a trailing `*` is replaced by a unique suffix
+    pub reply_queue: String,
+    /// Time to wait for all the responses to a command
+    pub timeout: Duration,
+}
+
+impl Default for PcfClientOptions {
+    fn default() -> Self {
+        Self {
+            command_queue: "SYSTEM.ADMIN.COMMAND.QUEUE".to_string(),
+            model_queue: "SYSTEM.DEFAULT.MODEL.QUEUE".to_string(),
+            reply_queue: "AMQ.*".to_string(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Responses to a PCF command in the order they were received
+#[derive(Debug, Clone, Default)]
+pub struct PcfResponses {
+    /// The responses. The last response has the `MQCFC_LAST` control unless the command timed out.
+    pub messages: Vec<PcfMessage>,
+}
+
+impl PcfResponses {
+    /// `CompCode` and `Reason` of the `MQCFH` of each response, or the [`InvalidCode`] of a response that holds
+    /// a value that is not a completion code or reason
+    pub fn completions(&self) -> impl Iterator<Item = Result<(MqCompCode, MqReason), InvalidCode>> + '_ {
+        self.messages.iter().map(|message| {
+            Ok((
+                MqCompCode::try_from(message.header.CompCode)?,
+                MqReason::try_from(message.header.Reason)?,
+            ))
+        })
+    }
+
+    /// Highest `CompCode` of the responses, or [`MqCompCode::Ok`] when there are no responses
+    ///
+    /// # Errors
+    /// Will return the first [`InvalidCode`] of the [`completions`](Self::completions)
+    pub fn comp_code(&self) -> Result<MqCompCode, InvalidCode> {
+        self.completions()
+            .map(|completion| completion.map(|(comp_code, _)| comp_code))
+            .try_fold(MqCompCode::Ok, |highest, comp_code| Ok(highest.max(comp_code?)))
+    }
+
+    /// Responses that did not complete with `MQCC_OK`
+    pub fn failures(&self) -> impl Iterator<Item = &PcfMessage> {
+        self.messages
+            .iter()
+            .filter(|message| message.header.CompCode != mqsys::MQCC_OK)
+    }
+
+    /// The last response has the `MQCFC_LAST` control
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.messages
+            .last()
+            .is_some_and(|message| message.header.Control == mqsys::MQCFC_LAST)
+    }
+}
+
+/// A PCF command that did not return all its responses
+#[derive(Debug)]
+pub enum PcfClientError {
+    /// An MQI function call failed
+    Mq(MqError),
+    /// A response is not a valid PCF message
+    Pcf(PcfError),
+    /// The timeout elapsed before the `MQCFC_LAST` response. Holds the responses that were received.
+    Timeout(PcfResponses),
+}
+
+impl fmt::Display for PcfClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mq(error) => error.fmt(f),
+            Self::Pcf(error) => error.fmt(f),
+            Self::Timeout(responses) => write!(
+                f,
+                "PCF command timed out after {} responses without the last response",
+                responses.messages.len()
+            ),
+        }
+    }
+}
+
+impl Error for PcfClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Mq(error) => Some(error),
+            Self::Pcf(error) => Some(error),
+            Self::Timeout(_) => None,
+        }
+    }
+}
+
+impl From<MqError> for PcfClientError {
+    fn from(error: MqError) -> Self {
+        Self::Mq(error)
+    }
+}
+
+impl From<PcfError> for PcfClientError {
+    fn from(error: PcfError) -> Self {
+        Self::Pcf(error)
+    }
+}
+
+/// Sends PCF commands to a command server and collects the responses.
+///
+/// The client opens the command queue for output and creates a temporary dynamic reply queue from the model
+/// queue on a connection of the [`Mqi`]. Each command is put with a new `MsgId`, and the responses with the
+/// `MsgId` as their `CorrelId` are read from the reply queue until the `MQCFC_LAST` response. Both queues are
+/// closed when the client is dropped, which deletes the reply queue. The connection is not disconnected.
+#[derive(Debug)]
+pub struct PcfClient<M: Mqi> {
+    mq: M,
+    hconn: mqsys::MQHCONN,
+    command_queue: mqsys::MQHOBJ,
+    reply_queue: mqsys::MQHOBJ,
+    reply_queue_name: String,
+    timeout: Duration,
+}
+
+impl<M: Mqi> PcfClient<M> {
+    /// Open the command queue and create the reply queue of the `options` on the `hconn` connection
+    ///
+    /// # Errors
+    /// Returns the [`MqError`] of the `MQOPEN` that failed.
+    pub fn open(mq: M, hconn: mqsys::MQHCONN, options: &PcfClientOptions) -> Result<Self, MqError> {
+        let mut client = Self {
+            mq,
+            hconn,
+            command_queue: mqsys::MQHO_NONE,
+            reply_queue: mqsys::MQHO_NONE,
+            reply_queue_name: String::new(),
+            timeout: options.timeout,
+        };
+
+        let mut command_od = od(&options.command_queue);
+        client.command_queue = client.open_queue(&mut command_od, mqsys::MQOO_OUTPUT | mqsys::MQOO_FAIL_IF_QUIESCING)?;
+
+        let mut reply_od = od(&options.model_queue);
+        fill(&mut reply_od.DynamicQName, options.reply_queue.as_bytes());
+        client.reply_queue = client.open_queue(&mut reply_od, mqsys::MQOO_INPUT_EXCLUSIVE | mqsys::MQOO_FAIL_IF_QUIESCING)?;
+        client.reply_queue_name = mq_str(&reply_od.ObjectName);
+
+        Ok(client)
+    }
+
+    /// Name of the dynamic queue that the responses are read from
+    #[must_use]
+    pub fn reply_queue(&self) -> &str {
+        &self.reply_queue_name
+    }
+
+    /// Send the `command` and wait for all of its responses. The command is put with the encoding and CCSID of the
+    /// builder, and the responses are converted to UTF-8 as by [`PcfMessage::parse_encoded`].
+    ///
+    /// # Errors
+    /// Returns a [`PcfClientError`] when the command can not be put, a response can not be read or parsed, or the
+    /// timeout elapses before the `MQCFC_LAST` response. The responses of a command that failed on the command
+    /// server are returned in the [`PcfResponses`] with their `CompCode` and `Reason`.
+    pub fn execute(&self, command: PcfBuilder) -> Result<PcfResponses, PcfClientError> {
+        let (encoding, ccsid) = (command.byte_order.encoding(), command.ccsid);
+        let msg_id = self.put(&command.build(), encoding, ccsid)?;
+        let deadline = Instant::now() + self.timeout;
+
+        let mut responses = PcfResponses::default();
+        let mut buffer = vec![0; BUFFER_LENGTH];
+        loop {
+            let wait = deadline.saturating_duration_since(Instant::now());
+            let (md, length) = match self.get(msg_id, wait, &mut buffer) {
+                Err(error) if error.reason.value() == mqsys::MQRC_NO_MSG_AVAILABLE => {
+                    return Err(PcfClientError::Timeout(responses))
+                }
+                result => result?,
+            };
+            responses
+                .messages
+                .push(PcfMessage::parse_encoded(&buffer[..length], md.Encoding, md.CodedCharSetId)?);
+            if responses.is_complete() {
+                return Ok(responses);
+            }
+        }
+    }
+
+    fn open_queue(&self, od: &mut mqsys::MQOD, options: MQLONG) -> Result<mqsys::MQHOBJ, MqError> {
+        let mut hobj = mqsys::MQHO_NONE;
+        let (mut comp_code, mut reason) = (mqsys::MQCC_UNKNOWN, mqsys::MQRC_NONE);
+        unsafe {
+            self.mq.MQOPEN(
+                self.hconn,
+                ptr::from_mut(od).cast(),
+                options,
+                addr_of_mut!(hobj),
+                addr_of_mut!(comp_code),
+                addr_of_mut!(reason),
+            );
+        }
+        MqError::check("MQOPEN", comp_code, reason).map(|_warning| hobj)
+    }
+
+    /// Put the PCF message to the command queue, returning its `MsgId`
+    fn put(&self, message: &[u8], encoding: MQLONG, ccsid: MQLONG) -> Result<mqsys::MQBYTE24, MqError> {
+        let mut md = md();
+        md.MsgType = mqsys::MQMT_REQUEST;
+        md.Report = mqsys::MQRO_PASS_DISCARD_AND_EXPIRY | mqsys::MQRO_DISCARD_MSG;
+        // Commands that are not processed within the timeout expire, along with their responses
+        md.Expiry = MQLONG::try_from(self.timeout.as_millis() / 100).map_or(mqsys::MQEI_UNLIMITED, |expiry| expiry.max(1));
+        md.Encoding = encoding;
+        md.CodedCharSetId = ccsid;
+        fill(&mut md.Format, mqsys::MQFMT_ADMIN.to_bytes());
+        fill(&mut md.ReplyToQ, self.reply_queue_name.as_bytes());
+
+        // SAFETY: the MQPMO is plain data that is valid when zeroed
+        let mut pmo: mqsys::MQPMO = unsafe { mem::zeroed() };
+        fill(&mut pmo.StrucId, mqsys::MQPMO_STRUC_ID.to_bytes());
+        pmo.Version = mqsys::MQPMO_VERSION_1;
+        pmo.Options = mqsys::MQPMO_NEW_MSG_ID | mqsys::MQPMO_NO_SYNCPOINT | mqsys::MQPMO_FAIL_IF_QUIESCING;
+        pmo.Timeout = -1;
+
+        let (mut comp_code, mut reason) = (mqsys::MQCC_UNKNOWN, mqsys::MQRC_NONE);
+        unsafe {
+            self.mq.MQPUT(
+                self.hconn,
+                self.command_queue,
+                addr_of_mut!(md).cast(),
+                addr_of_mut!(pmo).cast(),
+                MQLONG::try_from(message.len()).expect("PCF message length exceeds MQLONG"),
+                message.as_ptr().cast_mut().cast(),
+                addr_of_mut!(comp_code),
+                addr_of_mut!(reason),
+            );
+        }
+        MqError::check("MQPUT", comp_code, reason).map(|_warning| md.MsgId)
+    }
+
+    /// Wait for the next response with the `CorrelId` and read it into the buffer, growing the buffer when the
+    /// response does not fit. Returns the MQMD and length of the response.
+    fn get(&self, correl_id: mqsys::MQBYTE24, wait: Duration, buffer: &mut Vec<u8>) -> Result<(mqsys::MQMD, usize), MqError> {
+        // SAFETY: the MQGMO is plain data that is valid when zeroed
+        let mut gmo: mqsys::MQGMO = unsafe { mem::zeroed() };
+        fill(&mut gmo.StrucId, mqsys::MQGMO_STRUC_ID.to_bytes());
+        gmo.Version = mqsys::MQGMO_VERSION_2;
+        gmo.Options = mqsys::MQGMO_WAIT | mqsys::MQGMO_CONVERT | mqsys::MQGMO_NO_SYNCPOINT | mqsys::MQGMO_FAIL_IF_QUIESCING;
+        gmo.WaitInterval = MQLONG::try_from(wait.as_millis()).unwrap_or(MQLONG::MAX);
+        gmo.MatchOptions = mqsys::MQMO_MATCH_CORREL_ID;
+
+        loop {
+            let mut md = md();
+            md.CorrelId = correl_id;
+            let mut data_length = 0;
+            let (mut comp_code, mut reason) = (mqsys::MQCC_UNKNOWN, mqsys::MQRC_NONE);
+            unsafe {
+                self.mq.MQGET(
+                    self.hconn,
+                    self.reply_queue,
+                    addr_of_mut!(md).cast(),
+                    addr_of_mut!(gmo).cast(),
+                    MQLONG::try_from(buffer.len()).unwrap_or(MQLONG::MAX),
+                    buffer.as_mut_ptr().cast(),
+                    addr_of_mut!(data_length),
+                    addr_of_mut!(comp_code),
+                    addr_of_mut!(reason),
+                );
+            }
+            let length = usize::try_from(data_length).unwrap_or(0);
+            match MqError::check("MQGET", comp_code, reason)? {
+                Some(warning) if warning.value() == mqsys::MQRC_TRUNCATED_MSG_FAILED => buffer.resize(length, 0),
+                _ => return Ok((md, length.min(buffer.len()))),
+            }
+        }
+    }
+}
+
+impl<M: Mqi> Drop for PcfClient<M> {
+    fn drop(&mut self) {
+        for (hobj, options) in [
+            (&mut self.command_queue, mqsys::MQCO_NONE),
+            (&mut self.reply_queue, mqsys::MQCO_DELETE_PURGE),
+        ] {
+            if *hobj != mqsys::MQHO_NONE {
+                let (mut comp_code, mut reason) = (mqsys::MQCC_UNKNOWN, mqsys::MQRC_NONE);
+                unsafe {
+                    self.mq
+                        .MQCLOSE(self.hconn, hobj, options, addr_of_mut!(comp_code), addr_of_mut!(reason));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::{fixture::connect, mock::MockMq, pcf::PcfValue};
+
+    /// Command server that answers with a response for each of the queues, and fails the last response
+    fn inquire_q(request: &[u8]) -> Vec<Vec<u8>> {
+        let request = PcfMessage::parse(request).expect("valid PCF command");
+        assert_eq!(request.header.Type, mqsys::MQCFT_COMMAND);
+        ["APP.IN", "APP.OUT"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                PcfBuilder::new(mqsys::MQCFT_RESPONSE, request.header.Command)
+                    .sequence(MQLONG::try_from(index + 1).expect("sequence"), mqsys::MQCFC_NOT_LAST)
+                    .string(mqsys::MQCA_Q_NAME, name)
+                    .build()
+            })
+            .chain([PcfBuilder::new(mqsys::MQCFT_RESPONSE, request.header.Command)
+                .completion(mqsys::MQCC_FAILED, mqsys::MQRCCF_COMMAND_FAILED)
+                .build()])
+            .collect()
+    }
+
+    fn mock() -> MockMq {
+        MockMq::new("QM1")
+            .with_model_queue("SYSTEM.DEFAULT.MODEL.QUEUE")
+            .with_command_server("SYSTEM.ADMIN.COMMAND.QUEUE", inquire_q)
+    }
+
+    #[test]
+    fn execute() -> Result<(), PcfClientError> {
+        let mq = mock();
+        let hconn = connect(&mq);
+        let client = PcfClient::open(&mq, hconn, &PcfClientOptions::default())?;
+        assert!(client.reply_queue().starts_with("AMQ."));
+
+        let command = PcfBuilder::command(mqsys::MQCMD_INQUIRE_Q)
+            .ccsid(1208)
+            .string(mqsys::MQCA_Q_NAME, "APP.*");
+        let responses = client.execute(command)?;
+        assert!(responses.is_complete());
+        assert_eq!(
+            responses.completions().collect::<Vec<_>>(),
+            [
+                Ok((MqCompCode::Ok, MqReason::NONE)),
+                Ok((MqCompCode::Ok, MqReason::NONE)),
+                Ok((
+                    MqCompCode::Failed,
+                    MqReason::try_from(mqsys::MQRCCF_COMMAND_FAILED).expect("valid reason")
+                ))
+            ]
+        );
+        assert_eq!(responses.comp_code(), Ok(MqCompCode::Failed));
+        assert_eq!(responses.failures().count(), 1);
+        assert_eq!(
+            responses.messages[1].find(mqsys::MQCA_Q_NAME),
+            Some(&PcfValue::String {
+                ccsid: 1208,
+                value: b"APP.OUT".to_vec()
+            })
+        );
+
+        let reply_queue = client.reply_queue().to_string();
+        assert_eq!(mq.depth(&reply_queue), Some(0));
+        drop(client);
+        assert_eq!(mq.depth(&reply_queue), None);
+        Ok(())
+    }
+
+    #[test]
+    fn timeout() -> Result<(), PcfClientError> {
+        let mq = MockMq::new("QM1")
+            .with_model_queue("MODEL")
+            .with_command_server("COMMAND", |request| inquire_q(request).into_iter().take(1).collect());
+        let hconn = connect(&mq);
+        let options = PcfClientOptions {
+            command_queue: "COMMAND".to_string(),
+            model_queue: "MODEL".to_string(),
+            reply_queue: "PCF.REPLY".to_string(),
+            timeout: Duration::ZERO,
+        };
+        let client = PcfClient::open(&mq, hconn, &options)?;
+        assert_eq!(client.reply_queue(), "PCF.REPLY");
+
+        match client.execute(PcfBuilder::command(mqsys::MQCMD_INQUIRE_Q)) {
+            Err(PcfClientError::Timeout(responses)) => {
+                assert_eq!(responses.messages.len(), 1);
+                assert!(!responses.is_complete());
+            }
+            other => panic!("expected timeout, got {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn missing_queues() {
+        let mq = MockMq::new("QM1").with_queue("SYSTEM.ADMIN.COMMAND.QUEUE");
+        let hconn = connect(&mq);
+        let error = PcfClient::open(&mq, hconn, &PcfClientOptions::default()).expect_err("no model queue");
+        assert_eq!(
+            (error.verb, error.reason.value()),
+            ("MQOPEN", mqsys::MQRC_UNKNOWN_OBJECT_NAME)
+        );
+    }
+}
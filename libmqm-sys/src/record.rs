@@ -0,0 +1,1638 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+/*!
+ * Record MQI function calls and replay them without the MQ library
+ *
+ * The [`RecordingMq`] decorator records every MQI function call made to an inner implementation into a
+ * [`Session`]. Each recorded [`Call`] holds the inputs of the call and the outputs that were returned by
+ * the inner implementation, including handles, buffers, the completion code and the reason code.
+ * The known MQI structures (`MQOD`, `MQMD`, `MQGMO`, `MQPMO` and `MQCNO`) are recorded up to the length
+ * of their `Version` field.
+ *
+ * A [`Session`] can be saved to, and loaded from, a versioned binary file format. The [`ReplayMq`] plays a
+ * session back by returning the recorded outputs for each function call in turn. The scalar inputs of each
+ * call (handles, options, selectors and lengths) must match the recording, otherwise the call and every
+ * call after it fail with `MQRC_UNEXPECTED_ERROR` and the [`Divergence`] is reported.
+ *
+ * Structures referenced by the MQI structures (such as `MQSD`, `MQSTS` or the strings addressed by
+ * `MQCHARV`) are not recorded, and callback functions registered with `MQCB` are not invoked on replay.
+ *
+ * Example
+ * -------
+ *
+ *  Record a connection to a mock queue manager and replay it
+ *
+ * ```
+ * # #[cfg(feature = "mock")] {
+ * use std::ptr::{addr_of_mut, null_mut};
+ * use libmqm_sys::{lib, mock::MockMq, record::{RecordingMq, ReplayMq, Session}, Mqi};
+ *
+ * fn connect(mq: &impl Mqi) -> (lib::MQHCONN, lib::MQLONG) {
+ *     let mut hconn = lib::MQHC_DEF_HCONN;
+ *     let mut comp_code = lib::MQCC_UNKNOWN;
+ *     let mut reason = lib::MQRC_NONE;
+ *     unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(comp_code), addr_of_mut!(reason)) };
+ *     (hconn, comp_code)
+ * }
+ *
+ * let recording = RecordingMq::new(MockMq::new("QM1"));
+ * let recorded = connect(&recording);
+ *
+ * let mut file = Vec::new();
+ * recording.session().write_to(&mut file).expect("in-memory write");
+ *
+ * let replay = ReplayMq::new(Session::read_from(file.as_slice()).expect("valid session"));
+ * assert_eq!(connect(&replay), recorded);
+ * assert_eq!(replay.remaining(), 0);
+ * # }
+ * ```
+ */
+
+use std::{
+    collections::VecDeque,
+    error::Error,
+    ffi::c_void,
+    fmt,
+    io::{self, Read, Write},
+    mem, ptr, slice,
+    sync::{Mutex, MutexGuard, PoisonError},
+    vec,
+};
+
+use crate::{
+    function, lib as mqsys,
+    structs::{self, Versioned},
+};
+
+/// Version of the session file format written by [`Session::write_to`]
+pub const FORMAT_VERSION: u16 = 1;
+
+const MAGIC: &[u8; 4] = b"MQRR";
+
+/// A value passed to, or returned from, a recorded function call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// A null pointer
+    Null,
+    /// A single `MQLONG`
+    Long(mqsys::MQLONG),
+    /// A single `MQINT64`
+    Int64(mqsys::MQINT64),
+    /// An array of `MQLONG`
+    Longs(Vec<mqsys::MQLONG>),
+    /// A buffer or the bytes of a structure
+    Bytes(Vec<u8>),
+}
+
+impl Value {
+    const NULL: u8 = 0;
+    const LONG: u8 = 1;
+    const INT64: u8 = 2;
+    const LONGS: u8 = 3;
+    const BYTES: u8 = 4;
+
+    /// The replay inputs match when the scalar values are equal and the buffers are both present or both null
+    fn matches(&self, other: &Self) -> bool {
+        matches!((self, other), (Self::Bytes(_), Self::Bytes(_))) || self == other
+    }
+}
+
+/// A recorded function call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call {
+    /// Name of the MQI verb
+    pub verb: String,
+    /// Values passed to the function call
+    pub inputs: Vec<Value>,
+    /// Values returned by the function call. The last two values are the completion code and reason.
+    pub outputs: Vec<Value>,
+}
+
+/// A sequence of recorded function calls
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Session {
+    /// The function calls in the order they were made
+    pub calls: Vec<Call>,
+}
+
+impl Session {
+    /// Write the session in the versioned file format
+    ///
+    /// # Errors
+    /// Returns the error of the `writer`, or `InvalidData` when a value is too long for the file format
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        for call in &self.calls {
+            let verb = u8::try_from(call.verb.len()).map_err(|_| invalid("verb name too long"))?;
+            writer.write_all(&[verb])?;
+            writer.write_all(call.verb.as_bytes())?;
+            write_values(&mut writer, &call.inputs)?;
+            write_values(&mut writer, &call.outputs)?;
+        }
+        writer.flush()
+    }
+
+    /// Read a session from the versioned file format
+    ///
+    /// # Errors
+    /// Returns the error of the `reader`, or `InvalidData` when the file is not a session file of a supported version
+    pub fn read_from(mut reader: impl Read) -> io::Result<Self> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("not an MQI session file"));
+        }
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        if u16::from_le_bytes(version) > FORMAT_VERSION {
+            return Err(invalid("unsupported session file format version"));
+        }
+
+        let mut calls = Vec::new();
+        let mut verb_length = [0; 1];
+        while reader.read(&mut verb_length)? != 0 {
+            let verb = String::from_utf8(read_bytes(&mut reader, verb_length[0].into())?)
+                .map_err(|_| invalid("verb name is not UTF-8"))?;
+            let inputs = read_values(&mut reader)?;
+            let outputs = read_values(&mut reader)?;
+            calls.push(Call { verb, inputs, outputs });
+        }
+        Ok(Self { calls })
+    }
+}
+
+fn invalid(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn write_length(writer: &mut impl Write, length: usize) -> io::Result<()> {
+    let length = u32::try_from(length).map_err(|_| invalid("value too long"))?;
+    writer.write_all(&length.to_le_bytes())
+}
+
+fn write_values(writer: &mut impl Write, values: &[Value]) -> io::Result<()> {
+    write_length(writer, values.len())?;
+    for value in values {
+        match value {
+            Value::Null => writer.write_all(&[Value::NULL])?,
+            Value::Long(long) => {
+                writer.write_all(&[Value::LONG])?;
+                writer.write_all(&long.to_le_bytes())?;
+            }
+            Value::Int64(int64) => {
+                writer.write_all(&[Value::INT64])?;
+                writer.write_all(&int64.to_le_bytes())?;
+            }
+            Value::Longs(longs) => {
+                writer.write_all(&[Value::LONGS])?;
+                write_length(writer, longs.len())?;
+                for long in longs {
+                    writer.write_all(&long.to_le_bytes())?;
+                }
+            }
+            Value::Bytes(bytes) => {
+                writer.write_all(&[Value::BYTES])?;
+                write_length(writer, bytes.len())?;
+                writer.write_all(bytes)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut array = [0; N];
+    reader.read_exact(&mut array)?;
+    Ok(array)
+}
+
+fn read_length(reader: &mut impl Read) -> io::Result<usize> {
+    usize::try_from(u32::from_le_bytes(read_array(reader)?)).map_err(|_| invalid("value too long"))
+}
+
+/// Read a byte string without trusting the length to allocate the buffer
+fn read_bytes(reader: &mut impl Read, length: usize) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    reader.take(length as u64).read_to_end(&mut bytes)?;
+    if bytes.len() == length {
+        Ok(bytes)
+    } else {
+        Err(io::ErrorKind::UnexpectedEof.into())
+    }
+}
+
+fn read_values(reader: &mut impl Read) -> io::Result<Vec<Value>> {
+    let count = read_length(reader)?;
+    let mut values = Vec::new();
+    for _ in 0..count {
+        let [kind] = read_array(reader)?;
+        values.push(match kind {
+            Value::NULL => Value::Null,
+            Value::LONG => Value::Long(mqsys::MQLONG::from_le_bytes(read_array(reader)?)),
+            Value::INT64 => Value::Int64(mqsys::MQINT64::from_le_bytes(read_array(reader)?)),
+            Value::LONGS => {
+                let length = read_length(reader)?;
+                let bytes = read_bytes(reader, length * mem::size_of::<mqsys::MQLONG>())?;
+                Value::Longs(
+                    bytes
+                        .chunks_exact(mem::size_of::<mqsys::MQLONG>())
+                        .map(|chunk| mqsys::MQLONG::from_le_bytes(chunk.try_into().unwrap_or_default()))
+                        .collect(),
+                )
+            }
+            Value::BYTES => {
+                let length = read_length(reader)?;
+                Value::Bytes(read_bytes(reader, length)?)
+            }
+            _ => return Err(invalid("unknown value type")),
+        });
+    }
+    Ok(values)
+}
+
+/// Number of elements in a caller supplied length, treating negative lengths as empty
+fn count(length: mqsys::MQLONG) -> usize {
+    usize::try_from(length).unwrap_or(0)
+}
+
+/// Builder for the inputs of a function call
+#[derive(Debug, Default)]
+struct Inputs(Vec<Value>);
+
+impl Inputs {
+    fn long(mut self, value: mqsys::MQLONG) -> Self {
+        self.0.push(Value::Long(value));
+        self
+    }
+
+    fn int64(mut self, value: mqsys::MQINT64) -> Self {
+        self.0.push(Value::Int64(value));
+        self
+    }
+
+    unsafe fn long_at(mut self, value: *const mqsys::MQLONG) -> Self {
+        self.0
+            .push(unsafe { value.as_ref() }.map_or(Value::Null, |&long| Value::Long(long)));
+        self
+    }
+
+    unsafe fn int64_at(mut self, value: *const mqsys::MQINT64) -> Self {
+        self.0
+            .push(unsafe { value.as_ref() }.map_or(Value::Null, |&int64| Value::Int64(int64)));
+        self
+    }
+
+    unsafe fn longs(mut self, values: *const mqsys::MQLONG, length: mqsys::MQLONG) -> Self {
+        self.0.push(unsafe { longs(values, count(length)) });
+        self
+    }
+
+    unsafe fn bytes(mut self, buffer: *const c_void, length: usize) -> Self {
+        self.0.push(unsafe { bytes(buffer.cast(), length) });
+        self
+    }
+
+    unsafe fn structure<T: Versioned>(self, structure: *const T) -> Self {
+        let length = if structure.is_null() {
+            0
+        } else {
+            unsafe { structs::length(structure) }
+        };
+        unsafe { self.bytes(structure.cast(), length) }
+    }
+}
+
+unsafe fn longs(values: *const mqsys::MQLONG, length: usize) -> Value {
+    if values.is_null() {
+        Value::Null
+    } else {
+        Value::Longs(unsafe { slice::from_raw_parts(values, length) }.to_vec())
+    }
+}
+
+unsafe fn bytes(buffer: *const u8, length: usize) -> Value {
+    if buffer.is_null() {
+        Value::Null
+    } else {
+        Value::Bytes(unsafe { slice::from_raw_parts(buffer, length) }.to_vec())
+    }
+}
+
+/// Transfer of the outputs of a function call. Recording reads the outputs from the caller's storage,
+/// replaying writes the recorded outputs to the caller's storage.
+trait Transfer {
+    unsafe fn long(&mut self, value: *mut mqsys::MQLONG);
+
+    unsafe fn int64(&mut self, value: *mut mqsys::MQINT64);
+
+    unsafe fn longs(&mut self, values: *mut mqsys::MQLONG, length: mqsys::MQLONG);
+
+    /// A caller supplied buffer that is filled to the returned data length, when supplied
+    unsafe fn buffer(&mut self, buffer: *mut c_void, length: mqsys::MQLONG, pDataLength: *const mqsys::MQLONG);
+
+    /// The bytes of a structure with the offsets of its pointer fields
+    unsafe fn structure(&mut self, structure: *mut u8, length: usize, pointers: &[usize]);
+}
+
+unsafe fn structure<T: Versioned>(io: &mut dyn Transfer, structure: *mut T) {
+    let length = if structure.is_null() {
+        0
+    } else {
+        unsafe { structs::length(structure) }
+    };
+    unsafe { io.structure(structure.cast(), length, T::POINTERS) };
+}
+
+#[derive(Debug, Default)]
+struct Recorder(Vec<Value>);
+
+impl Transfer for Recorder {
+    unsafe fn long(&mut self, value: *mut mqsys::MQLONG) {
+        self.0
+            .push(unsafe { value.as_ref() }.map_or(Value::Null, |&long| Value::Long(long)));
+    }
+
+    unsafe fn int64(&mut self, value: *mut mqsys::MQINT64) {
+        self.0
+            .push(unsafe { value.as_ref() }.map_or(Value::Null, |&int64| Value::Int64(int64)));
+    }
+
+    unsafe fn longs(&mut self, values: *mut mqsys::MQLONG, length: mqsys::MQLONG) {
+        self.0.push(unsafe { longs(values, count(length)) });
+    }
+
+    unsafe fn buffer(&mut self, buffer: *mut c_void, length: mqsys::MQLONG, pDataLength: *const mqsys::MQLONG) {
+        let filled = unsafe { pDataLength.as_ref() }.map_or(length, |&data_length| data_length.min(length));
+        self.0.push(unsafe { bytes(buffer.cast(), count(filled)) });
+    }
+
+    unsafe fn structure(&mut self, structure: *mut u8, length: usize, _pointers: &[usize]) {
+        self.0.push(unsafe { bytes(structure, length) });
+    }
+}
+
+/// Writes recorded outputs to the caller's storage. `valid` is cleared when the recording does not fit.
+#[derive(Debug)]
+struct Player {
+    outputs: vec::IntoIter<Value>,
+    valid: bool,
+}
+
+impl Player {
+    fn next(&mut self) -> Option<Value> {
+        let value = self.outputs.next();
+        self.valid &= value.is_some();
+        value
+    }
+}
+
+impl Transfer for Player {
+    unsafe fn long(&mut self, value: *mut mqsys::MQLONG) {
+        match self.next() {
+            Some(Value::Long(long)) if !value.is_null() => unsafe { *value = long },
+            Some(Value::Null) => (),
+            _ => self.valid = false,
+        }
+    }
+
+    unsafe fn int64(&mut self, value: *mut mqsys::MQINT64) {
+        match self.next() {
+            Some(Value::Int64(int64)) if !value.is_null() => unsafe { *value = int64 },
+            Some(Value::Null) => (),
+            _ => self.valid = false,
+        }
+    }
+
+    unsafe fn longs(&mut self, values: *mut mqsys::MQLONG, length: mqsys::MQLONG) {
+        match self.next() {
+            Some(Value::Longs(longs)) if !values.is_null() => unsafe {
+                ptr::copy_nonoverlapping(longs.as_ptr(), values, longs.len().min(count(length)));
+            },
+            Some(Value::Null) => (),
+            _ => self.valid = false,
+        }
+    }
+
+    unsafe fn buffer(&mut self, buffer: *mut c_void, length: mqsys::MQLONG, _pDataLength: *const mqsys::MQLONG) {
+        match self.next() {
+            Some(Value::Bytes(bytes)) if !buffer.is_null() => unsafe {
+                ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.cast(), bytes.len().min(count(length)));
+            },
+            Some(Value::Null) => (),
+            _ => self.valid = false,
+        }
+    }
+
+    unsafe fn structure(&mut self, structure: *mut u8, length: usize, pointers: &[usize]) {
+        match self.next() {
+            Some(Value::Bytes(bytes)) if !structure.is_null() => {
+                let length = bytes.len().min(length);
+                // The recorded pointers are meaningless in this process so the caller's pointers are retained
+                let retained: Vec<_> = pointers
+                    .iter()
+                    .filter(|&&offset| offset + mem::size_of::<*mut c_void>() <= length)
+                    .map(|&offset| {
+                        (offset, unsafe {
+                            structure.add(offset).cast::<*mut c_void>().read_unaligned()
+                        })
+                    })
+                    .collect();
+                unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), structure, length) };
+                for (offset, pointer) in retained {
+                    unsafe { structure.add(offset).cast::<*mut c_void>().write_unaligned(pointer) };
+                }
+            }
+            Some(Value::Null) => (),
+            _ => self.valid = false,
+        }
+    }
+}
+
+/// Recording or replaying of a function call
+trait Tape {
+    /// The implementation that makes the function calls
+    type Inner;
+
+    /// Make the function call using `invoke` or the recording, and transfer the outputs of the call
+    unsafe fn tape(
+        &self,
+        verb: &'static str,
+        inputs: Inputs,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+        invoke: impl FnOnce(&Self::Inner),
+        outputs: impl FnOnce(&mut dyn Transfer),
+    );
+}
+
+/// Decorator that records the MQI function calls made to an inner implementation
+#[derive(Debug, Default)]
+pub struct RecordingMq<M> {
+    inner: M,
+    session: Mutex<Session>,
+}
+
+impl<M> RecordingMq<M> {
+    /// Record the function calls made to `inner`
+    pub fn new(inner: M) -> Self {
+        Self {
+            inner,
+            session: Mutex::default(),
+        }
+    }
+
+    /// The inner implementation
+    pub const fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// A copy of the function calls recorded so far
+    pub fn session(&self) -> Session {
+        self.recording().clone()
+    }
+
+    /// Unwrap the recorded session
+    pub fn into_session(self) -> Session {
+        self.session.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn recording(&self) -> MutexGuard<'_, Session> {
+        self.session.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<M> Tape for RecordingMq<M> {
+    type Inner = M;
+
+    unsafe fn tape(
+        &self,
+        verb: &'static str,
+        inputs: Inputs,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+        invoke: impl FnOnce(&M),
+        outputs: impl FnOnce(&mut dyn Transfer),
+    ) {
+        invoke(&self.inner);
+        let mut recorder = Recorder::default();
+        outputs(&mut recorder);
+        unsafe {
+            recorder.long(pCompCode);
+            recorder.long(pReason);
+        }
+        self.recording().calls.push(Call {
+            verb: verb.to_string(),
+            inputs: inputs.0,
+            outputs: recorder.0,
+        });
+    }
+}
+
+/// A replayed function call that did not match the recorded session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// Zero based position of the function call in the session
+    pub position: usize,
+    /// Name of the MQI verb that was called
+    pub verb: &'static str,
+    /// Name of the MQI verb that was recorded, or `None` beyond the end of the session
+    pub recorded: Option<String>,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            position,
+            verb,
+            recorded,
+        } = self;
+        match recorded {
+            Some(recorded) => write!(f, "call {position} to {verb} does not match the recorded call to {recorded}"),
+            None => write!(f, "call {position} to {verb} is beyond the end of the recorded session"),
+        }
+    }
+}
+
+impl Error for Divergence {}
+
+#[derive(Debug)]
+struct Replay {
+    calls: VecDeque<Call>,
+    position: usize,
+    divergence: Option<Divergence>,
+}
+
+impl Replay {
+    /// Take the outputs of the next recorded call when it matches the verb and inputs
+    fn next(&mut self, verb: &'static str, inputs: &[Value]) -> (usize, Option<Vec<Value>>) {
+        let position = self.position;
+        self.position += 1;
+        if self.divergence.is_some() {
+            return (position, None);
+        }
+        let outputs = match self.calls.pop_front() {
+            Some(call)
+                if call.verb == verb
+                    && call.inputs.len() == inputs.len()
+                    && call
+                        .inputs
+                        .iter()
+                        .zip(inputs)
+                        .all(|(recorded, input)| recorded.matches(input)) =>
+            {
+                Some(call.outputs)
+            }
+            call => {
+                self.diverge(position, verb, call.map(|call| call.verb));
+                None
+            }
+        };
+        (position, outputs)
+    }
+
+    fn diverge(&mut self, position: usize, verb: &'static str, recorded: Option<String>) {
+        self.divergence.get_or_insert(Divergence {
+            position,
+            verb,
+            recorded,
+        });
+    }
+}
+
+/// Implementation of [`Mqi`](crate::Mqi) that replays a recorded [`Session`]
+#[derive(Debug)]
+pub struct ReplayMq {
+    state: Mutex<Replay>,
+}
+
+impl ReplayMq {
+    /// Replay the function calls of the `session`
+    #[must_use]
+    pub fn new(session: Session) -> Self {
+        Self {
+            state: Mutex::new(Replay {
+                calls: session.calls.into(),
+                position: 0,
+                divergence: None,
+            }),
+        }
+    }
+
+    /// Number of recorded function calls that have not been replayed
+    pub fn remaining(&self) -> usize {
+        self.replay().calls.len()
+    }
+
+    /// The first function call that did not match the recorded session
+    pub fn divergence(&self) -> Option<Divergence> {
+        self.replay().divergence.clone()
+    }
+
+    fn replay(&self) -> MutexGuard<'_, Replay> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Tape for ReplayMq {
+    // Replay never makes the function calls
+    type Inner = Self;
+
+    unsafe fn tape(
+        &self,
+        verb: &'static str,
+        inputs: Inputs,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+        _invoke: impl FnOnce(&Self),
+        outputs: impl FnOnce(&mut dyn Transfer),
+    ) {
+        let (position, recorded) = self.replay().next(verb, &inputs.0);
+        if let Some(mut outputs_recorded) = recorded {
+            // The completion code and reason are the last two outputs
+            let codes = outputs_recorded.split_off(outputs_recorded.len().saturating_sub(2));
+            let mut player = Player {
+                outputs: outputs_recorded.into_iter(),
+                valid: codes.len() == 2,
+            };
+            outputs(&mut player);
+            if player.valid && player.outputs.len() == 0 {
+                player.outputs = codes.into_iter();
+                unsafe {
+                    player.long(pCompCode);
+                    player.long(pReason);
+                }
+                return;
+            }
+            self.replay().diverge(position, verb, Some(verb.to_string()));
+        }
+        unsafe {
+            *pCompCode = mqsys::MQCC_FAILED;
+            *pReason = mqsys::MQRC_UNEXPECTED_ERROR;
+        }
+    }
+}
+
+macro_rules! taped_mqi {
+    ($($header:tt)*) => {
+        $($header)* {
+            unsafe fn MQCONNX(
+                &self,
+                pQMgrName: mqsys::PMQCHAR,
+                pConnectOpts: mqsys::PMQCNO,
+                pHconn: mqsys::PMQHCONN,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default()
+                        .bytes(pQMgrName.cast(), mqsys::MQ_Q_MGR_NAME_LENGTH)
+                        .structure(pConnectOpts);
+                    self.tape(
+                        "MQCONNX",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQCONNX(pQMgrName, pConnectOpts, pHconn, pCompCode, pReason),
+                        |io| {
+                            structure(io, pConnectOpts);
+                            io.long(pHconn);
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQCONN(
+                &self,
+                pQMgrName: mqsys::PMQCHAR,
+                pHconn: mqsys::PMQHCONN,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default().bytes(pQMgrName.cast(), mqsys::MQ_Q_MGR_NAME_LENGTH);
+                    self.tape(
+                        "MQCONN",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQCONN(pQMgrName, pHconn, pCompCode, pReason),
+                        |io| io.long(pHconn),
+                    );
+                }
+            }
+
+            unsafe fn MQDISC(&self, pHconn: mqsys::PMQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+                unsafe {
+                    let inputs = Inputs::default().long_at(pHconn);
+                    self.tape(
+                        "MQDISC",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQDISC(pHconn, pCompCode, pReason),
+                        |io| io.long(pHconn),
+                    );
+                }
+            }
+
+            unsafe fn MQOPEN(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                pObjDesc: mqsys::PMQVOID,
+                Options: mqsys::MQLONG,
+                pHobj: mqsys::PMQHOBJ,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                let od = pObjDesc.cast::<mqsys::MQOD>();
+                unsafe {
+                    let inputs = Inputs::default().long(Hconn).structure(od).long(Options);
+                    self.tape(
+                        "MQOPEN",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQOPEN(Hconn, pObjDesc, Options, pHobj, pCompCode, pReason),
+                        |io| {
+                            structure(io, od);
+                            io.long(pHobj);
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQPUT1(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                pObjDesc: mqsys::PMQVOID,
+                pMsgDesc: mqsys::PMQVOID,
+                pPutMsgOpts: mqsys::PMQVOID,
+                BufferLength: mqsys::MQLONG,
+                pBuffer: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                let od = pObjDesc.cast::<mqsys::MQOD>();
+                let md = pMsgDesc.cast::<mqsys::MQMD>();
+                let pmo = pPutMsgOpts.cast::<mqsys::MQPMO>();
+                unsafe {
+                    let inputs = Inputs::default()
+                        .long(Hconn)
+                        .structure(od)
+                        .structure(md)
+                        .structure(pmo)
+                        .long(BufferLength)
+                        .bytes(pBuffer, count(BufferLength));
+                    self.tape(
+                        "MQPUT1",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQPUT1(Hconn, pObjDesc, pMsgDesc, pPutMsgOpts, BufferLength, pBuffer, pCompCode, pReason),
+                        |io| {
+                            structure(io, od);
+                            structure(io, md);
+                            structure(io, pmo);
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQCLOSE(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                pHobj: mqsys::PMQHOBJ,
+                Options: mqsys::MQLONG,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default().long(Hconn).long_at(pHobj).long(Options);
+                    self.tape(
+                        "MQCLOSE",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQCLOSE(Hconn, pHobj, Options, pCompCode, pReason),
+                        |io| io.long(pHobj),
+                    );
+                }
+            }
+
+            unsafe fn MQCMIT(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+                unsafe {
+                    self.tape(
+                        "MQCMIT",
+                        Inputs::default().long(Hconn),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQCMIT(Hconn, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQGET(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hobj: mqsys::MQHOBJ,
+                pMsgDesc: mqsys::PMQVOID,
+                pGetMsgOpts: mqsys::PMQVOID,
+                BufferLength: mqsys::MQLONG,
+                pBuffer: mqsys::PMQVOID,
+                pDataLength: mqsys::PMQLONG,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                let md = pMsgDesc.cast::<mqsys::MQMD>();
+                let gmo = pGetMsgOpts.cast::<mqsys::MQGMO>();
+                unsafe {
+                    let inputs = Inputs::default()
+                        .long(Hconn)
+                        .long(Hobj)
+                        .structure(md)
+                        .structure(gmo)
+                        .long(BufferLength);
+                    self.tape(
+                        "MQGET",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| {
+                            mq.MQGET(
+                                Hconn,
+                                Hobj,
+                                pMsgDesc,
+                                pGetMsgOpts,
+                                BufferLength,
+                                pBuffer,
+                                pDataLength,
+                                pCompCode,
+                                pReason,
+                            );
+                        },
+                        |io| {
+                            structure(io, md);
+                            structure(io, gmo);
+                            io.buffer(pBuffer, BufferLength, pDataLength);
+                            io.long(pDataLength);
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQPUT(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hobj: mqsys::MQHOBJ,
+                pMsgDesc: mqsys::PMQVOID,
+                pPutMsgOpts: mqsys::PMQVOID,
+                BufferLength: mqsys::MQLONG,
+                pBuffer: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                let md = pMsgDesc.cast::<mqsys::MQMD>();
+                let pmo = pPutMsgOpts.cast::<mqsys::MQPMO>();
+                unsafe {
+                    let inputs = Inputs::default()
+                        .long(Hconn)
+                        .long(Hobj)
+                        .structure(md)
+                        .structure(pmo)
+                        .long(BufferLength)
+                        .bytes(pBuffer, count(BufferLength));
+                    self.tape(
+                        "MQPUT",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQPUT(Hconn, Hobj, pMsgDesc, pPutMsgOpts, BufferLength, pBuffer, pCompCode, pReason),
+                        |io| {
+                            structure(io, md);
+                            structure(io, pmo);
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQINQ(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hobj: mqsys::MQHOBJ,
+                SelectorCount: mqsys::MQLONG,
+                pSelectors: mqsys::PMQLONG,
+                IntAttrCount: mqsys::MQLONG,
+                pIntAttrs: mqsys::PMQLONG,
+                CharAttrLength: mqsys::MQLONG,
+                pCharAttrs: mqsys::PMQCHAR,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default()
+                        .long(Hconn)
+                        .long(Hobj)
+                        .longs(pSelectors, SelectorCount)
+                        .long(IntAttrCount)
+                        .long(CharAttrLength);
+                    self.tape(
+                        "MQINQ",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| {
+                            mq.MQINQ(
+                                Hconn,
+                                Hobj,
+                                SelectorCount,
+                                pSelectors,
+                                IntAttrCount,
+                                pIntAttrs,
+                                CharAttrLength,
+                                pCharAttrs,
+                                pCompCode,
+                                pReason,
+                            );
+                        },
+                        |io| {
+                            io.longs(pIntAttrs, IntAttrCount);
+                            io.buffer(pCharAttrs.cast(), CharAttrLength, ptr::null());
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQSUB(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                pSubDesc: mqsys::PMQVOID,
+                pHobj: mqsys::PMQHOBJ,
+                pHsub: mqsys::PMQHOBJ,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default().long(Hconn).long_at(pHobj);
+                    self.tape(
+                        "MQSUB",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQSUB(Hconn, pSubDesc, pHobj, pHsub, pCompCode, pReason),
+                        |io| {
+                            io.long(pHobj);
+                            io.long(pHsub);
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQSUBRQ(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hsub: mqsys::MQHOBJ,
+                Action: mqsys::MQLONG,
+                pSubRqOpts: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQSUBRQ",
+                        Inputs::default().long(Hconn).long(Hsub).long(Action),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQSUBRQ(Hconn, Hsub, Action, pSubRqOpts, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQBEGIN(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                pBeginOptions: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQBEGIN",
+                        Inputs::default().long(Hconn),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQBEGIN(Hconn, pBeginOptions, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQBACK(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+                unsafe {
+                    self.tape(
+                        "MQBACK",
+                        Inputs::default().long(Hconn),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQBACK(Hconn, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQCRTMH(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                pCrtMsgHOpts: mqsys::PMQVOID,
+                pHmsg: mqsys::PMQHMSG,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQCRTMH",
+                        Inputs::default().long(Hconn),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQCRTMH(Hconn, pCrtMsgHOpts, pHmsg, pCompCode, pReason),
+                        |io| io.int64(pHmsg),
+                    );
+                }
+            }
+
+            unsafe fn MQDLTMH(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                pHmsg: mqsys::PMQHMSG,
+                pDltMsgHOpts: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default().long(Hconn).int64_at(pHmsg);
+                    self.tape(
+                        "MQDLTMH",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQDLTMH(Hconn, pHmsg, pDltMsgHOpts, pCompCode, pReason),
+                        |io| io.int64(pHmsg),
+                    );
+                }
+            }
+
+            unsafe fn MQMHBUF(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hmsg: mqsys::MQHMSG,
+                pMsgHBufOpts: mqsys::PMQVOID,
+                pName: mqsys::PMQVOID,
+                pMsgDesc: mqsys::PMQVOID,
+                BufferLength: mqsys::MQLONG,
+                pBuffer: mqsys::PMQVOID,
+                pDataLength: mqsys::PMQLONG,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                let md = pMsgDesc.cast::<mqsys::MQMD>();
+                unsafe {
+                    let inputs = Inputs::default().long(Hconn).int64(Hmsg).long(BufferLength);
+                    self.tape(
+                        "MQMHBUF",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| {
+                            mq.MQMHBUF(
+                                Hconn,
+                                Hmsg,
+                                pMsgHBufOpts,
+                                pName,
+                                pMsgDesc,
+                                BufferLength,
+                                pBuffer,
+                                pDataLength,
+                                pCompCode,
+                                pReason,
+                            );
+                        },
+                        |io| {
+                            structure(io, md);
+                            io.buffer(pBuffer, BufferLength, pDataLength);
+                            io.long(pDataLength);
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQBUFMH(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hmsg: mqsys::MQHMSG,
+                pBufMsgHOpts: mqsys::PMQVOID,
+                pMsgDesc: mqsys::PMQVOID,
+                BufferLength: mqsys::MQLONG,
+                pBuffer: mqsys::PMQVOID,
+                pDataLength: mqsys::PMQLONG,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                let md = pMsgDesc.cast::<mqsys::MQMD>();
+                unsafe {
+                    let inputs = Inputs::default()
+                        .long(Hconn)
+                        .int64(Hmsg)
+                        .structure(md)
+                        .long(BufferLength)
+                        .bytes(pBuffer, count(BufferLength));
+                    self.tape(
+                        "MQBUFMH",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| {
+                            mq.MQBUFMH(
+                                Hconn,
+                                Hmsg,
+                                pBufMsgHOpts,
+                                pMsgDesc,
+                                BufferLength,
+                                pBuffer,
+                                pDataLength,
+                                pCompCode,
+                                pReason,
+                            );
+                        },
+                        |io| {
+                            structure(io, md);
+                            io.long(pDataLength);
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQCB(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Operation: mqsys::MQLONG,
+                pCallbackDesc: mqsys::PMQVOID,
+                Hobj: mqsys::MQHOBJ,
+                pMsgDesc: mqsys::PMQVOID,
+                pGetMsgOpts: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQCB",
+                        Inputs::default().long(Hconn).long(Operation).long(Hobj),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQCB(Hconn, Operation, pCallbackDesc, Hobj, pMsgDesc, pGetMsgOpts, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQCTL(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Operation: mqsys::MQLONG,
+                pControlOpts: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQCTL",
+                        Inputs::default().long(Hconn).long(Operation),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQCTL(Hconn, Operation, pControlOpts, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQSET(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hobj: mqsys::MQHOBJ,
+                SelectorCount: mqsys::MQLONG,
+                pSelectors: mqsys::PMQLONG,
+                IntAttrCount: mqsys::MQLONG,
+                pIntAttrs: mqsys::PMQLONG,
+                CharAttrLength: mqsys::MQLONG,
+                pCharAttrs: mqsys::PMQCHAR,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default()
+                        .long(Hconn)
+                        .long(Hobj)
+                        .longs(pSelectors, SelectorCount)
+                        .longs(pIntAttrs, IntAttrCount)
+                        .bytes(pCharAttrs.cast(), count(CharAttrLength));
+                    self.tape(
+                        "MQSET",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| {
+                            mq.MQSET(
+                                Hconn,
+                                Hobj,
+                                SelectorCount,
+                                pSelectors,
+                                IntAttrCount,
+                                pIntAttrs,
+                                CharAttrLength,
+                                pCharAttrs,
+                                pCompCode,
+                                pReason,
+                            );
+                        },
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQSETMP(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hmsg: mqsys::MQHMSG,
+                pSetPropOpts: mqsys::PMQVOID,
+                pName: mqsys::PMQVOID,
+                pPropDesc: mqsys::PMQVOID,
+                Type: mqsys::MQLONG,
+                ValueLength: mqsys::MQLONG,
+                pValue: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default()
+                        .long(Hconn)
+                        .int64(Hmsg)
+                        .long(Type)
+                        .long(ValueLength)
+                        .bytes(pValue, count(ValueLength));
+                    self.tape(
+                        "MQSETMP",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| {
+                            mq.MQSETMP(
+                                Hconn,
+                                Hmsg,
+                                pSetPropOpts,
+                                pName,
+                                pPropDesc,
+                                Type,
+                                ValueLength,
+                                pValue,
+                                pCompCode,
+                                pReason,
+                            );
+                        },
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQSTAT(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Type: mqsys::MQLONG,
+                pStatus: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQSTAT",
+                        Inputs::default().long(Hconn).long(Type),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQSTAT(Hconn, Type, pStatus, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQINQMP(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hmsg: mqsys::MQHMSG,
+                pInqPropOpts: mqsys::PMQVOID,
+                pName: mqsys::PMQVOID,
+                pPropDesc: mqsys::PMQVOID,
+                pType: mqsys::PMQLONG,
+                ValueLength: mqsys::MQLONG,
+                pValue: mqsys::PMQVOID,
+                pDataLength: mqsys::PMQLONG,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default()
+                        .long(Hconn)
+                        .int64(Hmsg)
+                        .long_at(pType)
+                        .long(ValueLength);
+                    self.tape(
+                        "MQINQMP",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| {
+                            mq.MQINQMP(
+                                Hconn,
+                                Hmsg,
+                                pInqPropOpts,
+                                pName,
+                                pPropDesc,
+                                pType,
+                                ValueLength,
+                                pValue,
+                                pDataLength,
+                                pCompCode,
+                                pReason,
+                            );
+                        },
+                        |io| {
+                            io.long(pType);
+                            io.buffer(pValue, ValueLength, pDataLength);
+                            io.long(pDataLength);
+                        },
+                    );
+                }
+            }
+
+            unsafe fn MQDLTMP(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Hmsg: mqsys::MQHMSG,
+                pDltPropOpts: mqsys::PMQVOID,
+                pName: mqsys::PMQVOID,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQDLTMP",
+                        Inputs::default().long(Hconn).int64(Hmsg),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQDLTMP(Hconn, Hmsg, pDltPropOpts, pName, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQXCNVC(
+                &self,
+                Hconn: mqsys::MQHCONN,
+                Options: mqsys::MQLONG,
+                SourceCCSID: mqsys::MQLONG,
+                SourceLength: mqsys::MQLONG,
+                pSourceBuffer: mqsys::PMQCHAR,
+                TargetCCSID: mqsys::MQLONG,
+                TargetLength: mqsys::MQLONG,
+                pTargetBuffer: mqsys::PMQCHAR,
+                pDataLength: mqsys::PMQLONG,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    let inputs = Inputs::default()
+                        .long(Hconn)
+                        .long(Options)
+                        .long(SourceCCSID)
+                        .long(SourceLength)
+                        .bytes(pSourceBuffer.cast(), count(SourceLength))
+                        .long(TargetCCSID)
+                        .long(TargetLength);
+                    self.tape(
+                        "MQXCNVC",
+                        inputs,
+                        pCompCode,
+                        pReason,
+                        |mq| {
+                            mq.MQXCNVC(
+                                Hconn,
+                                Options,
+                                SourceCCSID,
+                                SourceLength,
+                                pSourceBuffer,
+                                TargetCCSID,
+                                TargetLength,
+                                pTargetBuffer,
+                                pDataLength,
+                                pCompCode,
+                                pReason,
+                            );
+                        },
+                        |io| {
+                            io.buffer(pTargetBuffer.cast(), TargetLength, pDataLength);
+                            io.long(pDataLength);
+                        },
+                    );
+                }
+            }
+        }
+    };
+}
+
+taped_mqi!(impl<M: function::Mqi> function::Mqi for RecordingMq<M>);
+taped_mqi!(impl function::Mqi for ReplayMq);
+
+#[cfg(feature = "exits")]
+macro_rules! taped_mqi_exit {
+    ($($header:tt)*) => {
+        $($header)* {
+            unsafe fn MQXEP(
+                &self,
+                Hconfig: mqsys::MQHCONFIG,
+                ExitReason: mqsys::MQLONG,
+                Function: mqsys::MQLONG,
+                pEntryPoint: mqsys::PMQFUNC,
+                pExitOpts: mqsys::PMQXEPO,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQXEP",
+                        Inputs::default().long(ExitReason).long(Function),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQXEP(Hconfig, ExitReason, Function, pEntryPoint, pExitOpts, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQZEP(
+                &self,
+                Hconfig: mqsys::MQHCONFIG,
+                Function: mqsys::MQLONG,
+                pEntryPoint: mqsys::PMQFUNC,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQZEP",
+                        Inputs::default().long(Function),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQZEP(Hconfig, Function, pEntryPoint, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQXCLWLN(
+                &self,
+                pExitParms: mqsys::PMQWXP,
+                CurrentRecord: mqsys::MQPTR,
+                NextOffset: mqsys::MQLONG,
+                pNextRecord: mqsys::PMQPTR,
+                pCompCode: mqsys::PMQLONG,
+                pReason: mqsys::PMQLONG,
+            ) {
+                unsafe {
+                    self.tape(
+                        "MQXCLWLN",
+                        Inputs::default().long(NextOffset),
+                        pCompCode,
+                        pReason,
+                        |mq| mq.MQXCLWLN(pExitParms, CurrentRecord, NextOffset, pNextRecord, pCompCode, pReason),
+                        |_| (),
+                    );
+                }
+            }
+
+            unsafe fn MQXDX(
+                &self,
+                pDataConvExitParms: mqsys::PMQDXP,
+                pMsgDesc: mqsys::PMQMD,
+                InBufferLength: mqsys::MQLONG,
+                pInBuffer: mqsys::PMQVOID,
+                OutBufferLength: mqsys::MQLONG,
+                pOutBuffer: mqsys::PMQVOID,
+            ) {
+                unsafe {
+                    let pDataLength = ptr::addr_of_mut!((*pDataConvExitParms).DataLength);
+                    let inputs = Inputs::default()
+                        .long(InBufferLength)
+                        .bytes(pInBuffer, count(InBufferLength))
+                        .long(OutBufferLength);
+                    self.tape(
+                        "MQXDX",
+                        inputs,
+                        ptr::addr_of_mut!((*pDataConvExitParms).CompCode),
+                        ptr::addr_of_mut!((*pDataConvExitParms).Reason),
+                        |mq| {
+                            mq.MQXDX(
+                                pDataConvExitParms,
+                                pMsgDesc,
+                                InBufferLength,
+                                pInBuffer,
+                                OutBufferLength,
+                                pOutBuffer,
+                            );
+                        },
+                        |io| {
+                            io.buffer(pOutBuffer, OutBufferLength, pDataLength);
+                            io.long(pDataLength);
+                        },
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "exits")]
+taped_mqi_exit!(impl<M: function::MqiExit> function::MqiExit for RecordingMq<M>);
+#[cfg(feature = "exits")]
+taped_mqi_exit!(impl function::MqiExit for ReplayMq);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_format() {
+        let session = Session {
+            calls: vec![Call {
+                verb: "MQGET".to_string(),
+                inputs: vec![Value::Long(1), Value::Int64(-2), Value::Null],
+                outputs: vec![Value::Longs(vec![3, 4]), Value::Bytes(b"data".to_vec())],
+            }],
+        };
+        let mut file = Vec::new();
+        session.write_to(&mut file).expect("in-memory write");
+        assert_eq!(Session::read_from(file.as_slice()).expect("valid session"), session);
+
+        file.truncate(file.len() - 1);
+        assert!(Session::read_from(file.as_slice()).is_err());
+        assert!(Session::read_from(&b"MQRR\xff\xff"[..]).is_err());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn record_and_replay() {
+        use crate::{
+            fixture::{get, open, put, try_connect},
+            lib,
+            mock::MockMq,
+            Mqi,
+        };
+
+        /// Put and get a message returning the message data and the reason of each call
+        fn put_get(mq: &impl Mqi) -> (Vec<u8>, Vec<lib::MQLONG>) {
+            let (hconn, connect_rc) = try_connect(mq);
+            let (hobj, open_rc) = open(mq, hconn, "QQ", lib::MQOO_OUTPUT | lib::MQOO_INPUT_AS_Q_DEF);
+            let put_rc = put(mq, hconn, hobj, b"hello", 0, 0);
+            let (get_rc, data) = get(mq, hconn, hobj, 16, 0);
+            (data, vec![connect_rc, open_rc, put_rc, get_rc])
+        }
+
+        let recording = RecordingMq::new(MockMq::new("QM1").with_queue("QQ"));
+        let recorded = put_get(&recording);
+        assert_eq!(recorded, (b"hello".to_vec(), vec![lib::MQRC_NONE; 4]));
+
+        let session = recording.into_session();
+        let replay = ReplayMq::new(session.clone());
+        assert_eq!(put_get(&replay), recorded);
+        assert_eq!(replay.remaining(), 0);
+        assert_eq!(replay.divergence(), None);
+
+        // Connect again beyond the end of the recording
+        let (_, reasons) = put_get(&replay);
+        assert_eq!(reasons[0], lib::MQRC_UNEXPECTED_ERROR);
+        assert_eq!(
+            replay.divergence(),
+            Some(Divergence {
+                position: 4,
+                verb: "MQCONN",
+                recorded: None
+            })
+        );
+
+        // Open the queue with different options
+        let mut diverging = session;
+        diverging.calls[1].inputs[2] = Value::Long(lib::MQOO_OUTPUT);
+        let replay = ReplayMq::new(diverging);
+        assert_eq!(put_get(&replay).1[1..], [lib::MQRC_UNEXPECTED_ERROR; 3]);
+        assert_eq!(replay.divergence().map(|divergence| divergence.position), Some(1));
+    }
+}
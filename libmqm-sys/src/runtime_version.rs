@@ -0,0 +1,460 @@
+/*!
+ * Run-time check of the MQ client library version
+ *
+ * The bindings are generated from the client version in [`CLIENT_BUILD_VERSION`](crate::version::CLIENT_BUILD_VERSION),
+ * and the `mqc_*` features declare the minimum client version the application requires. A library older than
+ * either can reject the newer structure versions with reason codes such as `MQRC_OD_ERROR` long after startup.
+ *
+ * The version of an installation is determined by running its `dspmqver` command, the same way as the build. Client
+ * only and redistributable installations without `dspmqver` fall back to the version in the software identification
+ * tags of their `swidtag` folder, and fail with [`VersionError::Unavailable`] when neither is present.
+ *
+ * [`verify_library`] checks the installation that holds a loaded library rather than one chosen up front. With
+ * the `link_api` feature, [`verify_linked`] checks the library the dynamic linker chose for the compile time linked
+ * MQI functions, and with the `dlopen2` feature, [`verify_loaded`] checks the library a
+ * [`MqmContainer`](crate::dlopen2::MqmContainer) actually loaded. Both use [`library_path`] to find the library
+ * that provides an MQI function.
+ *
+ * Example
+ * -------
+ *
+ *  Fail fast when the MQ installation does not meet the `mqc_*` feature minimum
+ *
+ * ```no_run
+ * use libmqm_sys::runtime_version::{self, Requirement};
+ *
+ * # fn main() -> Result<(), runtime_version::VersionError> {
+ * let version = runtime_version::verify_installation("/opt/mqm", Requirement::Features)?;
+ * println!("MQ client version {version}");
+ * # Ok(())
+ * # }
+ * ```
+ */
+
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    str::FromStr,
+};
+
+use crate::version;
+
+/// An MQ version, such as `9.4.1.0`, ordered by version, release, modification and fix pack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MqVersion(u32);
+
+impl MqVersion {
+    /// The version from its version, release, modification and fix pack levels
+    #[must_use]
+    pub const fn new(version: u8, release: u8, modification: u8, fix: u8) -> Self {
+        Self(u32::from_be_bytes([version, release, modification, fix]))
+    }
+
+    /// The version from its integer form, as used by `CLIENT_BUILD_VERSION_INT`
+    #[must_use]
+    pub const fn from_int(version: u32) -> Self {
+        Self(version)
+    }
+
+    /// The integer form of the version
+    #[must_use]
+    pub const fn to_int(self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for MqVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [version, release, modification, fix] = self.0.to_be_bytes();
+        write!(f, "{version}.{release}.{modification}.{fix}")
+    }
+}
+
+/// The version is not in the `V.R.M.F` form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVersionError(pub String);
+
+impl fmt::Display for ParseVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\" is not an MQ version", self.0)
+    }
+}
+
+impl Error for ParseVersionError {}
+
+impl FromStr for MqVersion {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut levels = s.trim().split('.').map(str::parse::<u8>);
+        match (levels.next(), levels.next(), levels.next(), levels.next(), levels.next()) {
+            (Some(Ok(version)), Some(Ok(release)), Some(Ok(modification)), Some(Ok(fix)), None) => {
+                Ok(Self::new(version, release, modification, fix))
+            }
+            _ => Err(ParseVersionError(s.to_string())),
+        }
+    }
+}
+
+/// The client version the bindings were generated from
+pub const BUILD_VERSION: MqVersion = MqVersion::from_int(version::CLIENT_BUILD_VERSION_INT);
+
+include!(concat!(env!("OUT_DIR"), "/feature_minimum.rs"));
+
+/// A minimum client version required by the crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Requirement {
+    /// The minimum declared by the highest enabled `mqc_*` feature
+    Features,
+    /// The [`BUILD_VERSION`] the bindings were generated from
+    Build,
+}
+
+impl Requirement {
+    /// The minimum client version, or `None` when there is no requirement
+    #[must_use]
+    pub const fn minimum(self) -> Option<MqVersion> {
+        match self {
+            Self::Features => match FEATURE_MINIMUM {
+                Some((_, version)) => Some(version),
+                None => None,
+            },
+            Self::Build => Some(BUILD_VERSION),
+        }
+    }
+}
+
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self, FEATURE_MINIMUM) {
+            (Self::Features, Some((feature, _))) => write!(f, "feature {feature}"),
+            (Self::Features, None) => f.write_str("no mqc feature"),
+            (Self::Build, _) => f.write_str("build version"),
+        }
+    }
+}
+
+/// The run-time client version is older than a [`Requirement`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionMismatch {
+    /// The client version found at run-time
+    pub runtime: MqVersion,
+    /// The minimum client version
+    pub required: MqVersion,
+    /// The source of the minimum client version
+    pub requirement: Requirement,
+}
+
+impl fmt::Display for VersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "MQ client version {} is older than {} required by the {}",
+            self.runtime, self.required, self.requirement
+        )
+    }
+}
+
+impl Error for VersionMismatch {}
+
+/// Failure to determine or verify the run-time client version
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum VersionError {
+    /// The `dspmqver` command could not be run
+    Command(PathBuf, io::Error),
+    /// The installation has neither a `dspmqver` command nor a software identification tag holding its version
+    Unavailable(PathBuf),
+    /// The `dspmqver` output does not include a version
+    Output(PathBuf, String),
+    /// The version does not meet the requirement
+    Mismatch(VersionMismatch),
+    /// The library is not within an MQ installation
+    Installation(PathBuf),
+    /// The path of the linked or loaded library could not be determined
+    Library(io::Error),
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Command(path, error) => write!(f, "failed to run {}: {error}", path.display()),
+            Self::Unavailable(installation) => write!(
+                f,
+                "the version of the MQ installation {} is unknown: it has no dspmqver command or swidtag version",
+                installation.display()
+            ),
+            Self::Output(path, output) => write!(f, "no version in the output of {}: {output}", path.display()),
+            Self::Mismatch(mismatch) => mismatch.fmt(f),
+            Self::Installation(library) => write!(f, "{} is not within an MQ installation", library.display()),
+            Self::Library(error) => write!(f, "failed to find the MQ library: {error}"),
+        }
+    }
+}
+
+impl Error for VersionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Command(_, error) | Self::Library(error) => Some(error),
+            Self::Mismatch(mismatch) => Some(mismatch),
+            Self::Output(..) | Self::Installation(_) | Self::Unavailable(_) => None,
+        }
+    }
+}
+
+impl From<VersionMismatch> for VersionError {
+    fn from(mismatch: VersionMismatch) -> Self {
+        Self::Mismatch(mismatch)
+    }
+}
+
+/// Check the `runtime` client version meets the `requirement`
+///
+/// # Errors
+/// Returns a [`VersionMismatch`] when the `runtime` version is older than the requirement
+pub fn check(runtime: MqVersion, requirement: Requirement) -> Result<(), VersionMismatch> {
+    match requirement.minimum() {
+        Some(required) if runtime < required => Err(VersionMismatch {
+            runtime,
+            required,
+            requirement,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Extract the version from the output of the `dspmqver` command
+#[must_use]
+pub fn parse_dspmqver(output: &str) -> Option<MqVersion> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("Version:"))
+        .find_map(|version| version.parse().ok())
+}
+
+/// Extract the version from the `version` attribute of a software identification tag
+#[must_use]
+pub fn parse_swidtag(tag: &str) -> Option<MqVersion> {
+    tag.split(" version=\"")
+        .skip(1)
+        .find_map(|attribute| attribute.split('"').next()?.parse().ok())
+}
+
+/// The client version of the MQ `installation`, from its `dspmqver` command or, when the installation has no
+/// `dspmqver`, from the software identification tags in its `swidtag` folder
+///
+/// # Errors
+/// Returns a [`VersionError`] when `dspmqver` cannot be run or does not report a version, or
+/// [`VersionError::Unavailable`] when the installation has neither `dspmqver` nor a tagged version
+pub fn installation_version(installation: impl AsRef<Path>) -> Result<MqVersion, VersionError> {
+    let installation = installation.as_ref();
+    let dspmqver = installation.join(if cfg!(windows) { "bin64/dspmqver.exe" } else { "bin/dspmqver" });
+    if !dspmqver.is_file() {
+        return swidtag_version(installation).ok_or_else(|| VersionError::Unavailable(installation.to_path_buf()));
+    }
+    let output = Command::new(&dspmqver)
+        .output()
+        .map_err(|error| VersionError::Command(dspmqver.clone(), error))?;
+    let output = String::from_utf8_lossy(&output.stdout);
+    parse_dspmqver(&output).ok_or_else(|| VersionError::Output(dspmqver, output.trim().to_string()))
+}
+
+/// The highest version in the `*.swidtag` files of the `swidtag` folder of the `installation`
+fn swidtag_version(installation: &Path) -> Option<MqVersion> {
+    fs::read_dir(installation.join("swidtag"))
+        .ok()?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "swidtag"))
+        .filter_map(|path| parse_swidtag(&fs::read_to_string(path).ok()?))
+        .max()
+}
+
+/// Determine the client version of the MQ `installation` and check it meets the `requirement`
+///
+/// # Errors
+/// Returns a [`VersionError`] when the version cannot be determined or does not meet the requirement
+pub fn verify_installation(installation: impl AsRef<Path>, requirement: Requirement) -> Result<MqVersion, VersionError> {
+    let version = installation_version(installation)?;
+    check(version, requirement)?;
+    Ok(version)
+}
+
+/// The MQ installation holding the `library`, such as `/opt/mqm` for `/opt/mqm/lib64/libmqm_r.so`
+#[must_use]
+pub fn library_installation(library: impl AsRef<Path>) -> Option<PathBuf> {
+    library.as_ref().parent()?.parent().map(Path::to_path_buf)
+}
+
+/// Determine the client version of the installation holding the loaded `library` and check it meets the
+/// `requirement`
+///
+/// # Errors
+/// Returns a [`VersionError`] when the `library` is not within an installation, or the version cannot be
+/// determined or does not meet the requirement
+pub fn verify_library(library: impl AsRef<Path>, requirement: Requirement) -> Result<MqVersion, VersionError> {
+    let library = library.as_ref();
+    let installation = library_installation(library).ok_or_else(|| VersionError::Installation(library.to_path_buf()))?;
+    verify_installation(installation, requirement)
+}
+
+/// Determine the client version of the library loaded by the `mq` container and check it meets the `requirement`
+///
+/// # Errors
+/// Returns a [`VersionError`] when the path of the loaded library cannot be determined, or its version cannot be
+/// determined or does not meet the requirement
+#[cfg(feature = "dlopen2")]
+pub fn verify_loaded(mq: &crate::dlopen2::MqWrapper, requirement: Requirement) -> Result<MqVersion, VersionError> {
+    verify_library(mq.library_path().map_err(VersionError::Library)?, requirement)
+}
+
+/// Determine the client version of the library the dynamic linker chose for the compile time linked MQI functions
+/// and check it meets the `requirement`
+///
+/// # Errors
+/// Returns a [`VersionError`] when the path of the linked library cannot be determined, or its version cannot be
+/// determined or does not meet the requirement
+#[cfg(feature = "link_api")]
+pub fn verify_linked(requirement: Requirement) -> Result<MqVersion, VersionError> {
+    // SAFETY: MQCONNX is linked from the MQ library
+    let library = unsafe { library_path(crate::lib::MQCONNX as *const ()) }.map_err(VersionError::Library)?;
+    verify_library(library, requirement)
+}
+
+/// Path of the dynamic library providing the `function`, such as the address of a linked or loaded MQI function
+///
+/// # Safety
+/// The `function` must be an address within a loaded library
+///
+/// # Errors
+/// Returns an error when the address is not within a loaded library
+#[cfg(all(unix, any(feature = "dlopen2", feature = "link_api")))]
+pub unsafe fn library_path(function: *const ()) -> Result<PathBuf, io::Error> {
+    use std::{ffi::CStr, mem, os::unix::ffi::OsStrExt as _};
+
+    // SAFETY: Dl_info is plain old data so the all zero value is valid
+    let mut info: libc::Dl_info = unsafe { mem::zeroed() };
+    if unsafe { libc::dladdr(function.cast(), &mut info) } == 0 || info.dli_fname.is_null() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "address is not within a loaded library",
+        ));
+    }
+    let name = unsafe { CStr::from_ptr(info.dli_fname) };
+    Ok(PathBuf::from(std::ffi::OsStr::from_bytes(name.to_bytes())))
+}
+
+/// Path of the dynamic library providing the `function`, such as the address of a linked or loaded MQI function
+///
+/// # Safety
+/// The `function` must be an address within a loaded library
+///
+/// # Errors
+/// Returns the operating system error when the address is not within a loaded library
+#[cfg(all(windows, any(feature = "dlopen2", feature = "link_api")))]
+pub unsafe fn library_path(function: *const ()) -> Result<PathBuf, io::Error> {
+    use std::{
+        ffi::{c_void, OsString},
+        os::windows::ffi::OsStringExt as _,
+        ptr,
+    };
+
+    const GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT: u32 = 0x2;
+    const GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS: u32 = 0x4;
+    const MAX_PATH_LENGTH: u32 = 32_768;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetModuleHandleExW(flags: u32, module_name: *const u16, module: *mut *mut c_void) -> i32;
+        fn GetModuleFileNameW(module: *mut c_void, file_name: *mut u16, size: u32) -> u32;
+    }
+
+    let mut module = ptr::null_mut();
+    let flags = GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT;
+    if unsafe { GetModuleHandleExW(flags, function.cast(), &mut module) } == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let mut name = vec![0_u16; MAX_PATH_LENGTH as usize];
+    let length = unsafe { GetModuleFileNameW(module, name.as_mut_ptr(), MAX_PATH_LENGTH) };
+    if length == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    name.truncate(length as usize);
+    Ok(PathBuf::from(OsString::from_wide(&name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version() {
+        assert_eq!("9.4.1.0".parse(), Ok(MqVersion::new(9, 4, 1, 0)));
+        assert_eq!(MqVersion::new(9, 3, 0, 25).to_string(), "9.3.0.25");
+        assert!("9.4.1".parse::<MqVersion>().is_err());
+        assert_eq!(BUILD_VERSION.to_string(), version::CLIENT_BUILD_VERSION);
+        assert_eq!(
+            parse_dspmqver("Name:        IBM MQ\nVersion:     9.3.0.17\nLevel:       p930-017-240212\n"),
+            Some(MqVersion::new(9, 3, 0, 17))
+        );
+    }
+
+    #[test]
+    fn mismatch() {
+        let old = MqVersion::new(8, 0, 0, 0);
+        assert_eq!(
+            check(old, Requirement::Build),
+            Err(VersionMismatch {
+                runtime: old,
+                required: BUILD_VERSION,
+                requirement: Requirement::Build,
+            })
+        );
+        assert_eq!(check(BUILD_VERSION, Requirement::Build), Ok(()));
+        assert_eq!(check(old, Requirement::Features).is_err(), FEATURE_MINIMUM.is_some());
+    }
+
+    #[test]
+    fn feature_minimum() {
+        // Every mqc feature enables mqc_9_2_0_0
+        assert_eq!(FEATURE_MINIMUM.is_some(), cfg!(feature = "mqc_9_2_0_0"));
+        if let Some((feature, version)) = FEATURE_MINIMUM {
+            assert_eq!(
+                feature.strip_prefix("mqc_").map(|levels| levels.replace('_', ".")),
+                Some(version.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn missing_dspmqver() {
+        let installation = Path::new(env!("CARGO_MANIFEST_DIR")).join("no_such_mq");
+        assert!(matches!(
+            installation_version(&installation),
+            Err(VersionError::Unavailable(path)) if path == installation
+        ));
+        assert_eq!(
+            parse_swidtag(r#"<SoftwareIdentity name="IBM MQ" tagId="ibm.com-IBM MQ-9.3.0" version="9.3.0.17">"#),
+            Some(MqVersion::new(9, 3, 0, 17))
+        );
+        assert_eq!(parse_swidtag(r#"<SoftwareIdentity name="IBM MQ">"#), None);
+    }
+
+    #[cfg(all(unix, any(feature = "link_api", feature = "dlopen2")))]
+    #[test]
+    fn library_of_address() {
+        // libc provides dladdr itself
+        let library = unsafe { library_path(libc::dladdr as *const ()) }.expect("libc to be loaded");
+        assert!(library.to_string_lossy().contains("libc"), "{}", library.display());
+        assert!(unsafe { library_path(std::ptr::null()) }.is_err());
+    }
+
+    #[test]
+    fn library_outside_installation() {
+        assert!(matches!(
+            verify_library("libmqm_r.so", Requirement::Build),
+            Err(VersionError::Installation(library)) if library == Path::new("libmqm_r.so")
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+//! MQI structures whose valid length depends on their `Version` field
+
+use std::{mem, ptr};
+
+use crate::lib as mqsys;
+
+/// An MQI structure that is only valid up to the length of its `Version`
+pub trait Versioned: Copy {
+    /// Length of the structure for each version, starting at version 1
+    const LENGTHS: &'static [usize];
+
+    /// Offsets of the pointer fields within the structure
+    #[cfg(feature = "record")]
+    const POINTERS: &'static [usize] = &[];
+
+    /// Read the `Version` field of the structure
+    unsafe fn version(this: *const Self) -> mqsys::MQLONG;
+}
+
+macro_rules! versioned {
+    ($struc:ty, [$($length:expr),+] $(, [$($pointer:expr),*])?) => {
+        impl Versioned for $struc {
+            const LENGTHS: &'static [usize] = &[$($length),+];
+            $(#[cfg(feature = "record")]
+            const POINTERS: &'static [usize] = &[$($pointer),*];)?
+
+            unsafe fn version(this: *const Self) -> mqsys::MQLONG {
+                unsafe { ptr::addr_of!((*this).Version).read() }
+            }
+        }
+    };
+}
+
+versioned!(mqsys::MQMD, [mqsys::MQMD_LENGTH_1, mqsys::MQMD_LENGTH_2]);
+versioned!(
+    mqsys::MQGMO,
+    [
+        mqsys::MQGMO_LENGTH_1,
+        mqsys::MQGMO_LENGTH_2,
+        mqsys::MQGMO_LENGTH_3,
+        mqsys::MQGMO_LENGTH_4
+    ]
+);
+versioned!(
+    mqsys::MQPMO,
+    [mqsys::MQPMO_LENGTH_1, mqsys::MQPMO_LENGTH_2, mqsys::MQPMO_LENGTH_3],
+    [
+        mem::offset_of!(mqsys::MQPMO, PutMsgRecPtr),
+        mem::offset_of!(mqsys::MQPMO, ResponseRecPtr)
+    ]
+);
+versioned!(
+    mqsys::MQOD,
+    [
+        mqsys::MQOD_LENGTH_1,
+        mqsys::MQOD_LENGTH_2,
+        mqsys::MQOD_LENGTH_3,
+        mqsys::MQOD_LENGTH_4
+    ],
+    [
+        mem::offset_of!(mqsys::MQOD, ObjectRecPtr),
+        mem::offset_of!(mqsys::MQOD, ResponseRecPtr),
+        mem::offset_of!(mqsys::MQOD, ObjectString) + mem::offset_of!(mqsys::MQCHARV, VSPtr),
+        mem::offset_of!(mqsys::MQOD, SelectionString) + mem::offset_of!(mqsys::MQCHARV, VSPtr),
+        mem::offset_of!(mqsys::MQOD, ResObjectString) + mem::offset_of!(mqsys::MQCHARV, VSPtr)
+    ]
+);
+versioned!(
+    mqsys::MQCNO,
+    [
+        mqsys::MQCNO_LENGTH_1,
+        mqsys::MQCNO_LENGTH_2,
+        mqsys::MQCNO_LENGTH_3,
+        mqsys::MQCNO_LENGTH_4,
+        mqsys::MQCNO_LENGTH_5,
+        mqsys::MQCNO_LENGTH_6,
+        mqsys::MQCNO_LENGTH_7,
+        mqsys::MQCNO_LENGTH_8
+    ],
+    [
+        mem::offset_of!(mqsys::MQCNO, ClientConnPtr),
+        mem::offset_of!(mqsys::MQCNO, SSLConfigPtr),
+        mem::offset_of!(mqsys::MQCNO, SecurityParmsPtr),
+        mem::offset_of!(mqsys::MQCNO, CCDTUrlPtr),
+        mem::offset_of!(mqsys::MQCNO, BalanceParmsPtr)
+    ]
+);
+
+/// Length of the structure that is valid for the version. Unknown versions are limited to the known lengths.
+pub fn version_length<T: Versioned>(version: mqsys::MQLONG) -> usize {
+    let index = usize::try_from(version).unwrap_or(0).clamp(1, T::LENGTHS.len()) - 1;
+    T::LENGTHS[index]
+}
+
+/// Length of the structure that is valid for the `Version` of the structure
+pub unsafe fn length<T: Versioned>(this: *const T) -> usize {
+    version_length::<T>(unsafe { T::version(this) })
+}
+
+/// Read a structure without reading beyond the length of the caller's structure version
+#[cfg(feature = "mock")]
+pub unsafe fn read<T: Versioned>(src: *const T) -> T {
+    // SAFETY: the versioned MQI structures are plain old data so the all zero value is valid
+    let mut value: T = unsafe { mem::zeroed() };
+    unsafe { ptr::copy_nonoverlapping(src.cast::<u8>(), ptr::addr_of_mut!(value).cast::<u8>(), length(src)) };
+    value
+}
+
+/// Write a structure without writing beyond the length of the structure version
+#[cfg(feature = "mock")]
+pub unsafe fn write<T: Versioned>(dst: *mut T, value: &T) {
+    unsafe { ptr::copy_nonoverlapping(ptr::from_ref(value).cast::<u8>(), dst.cast::<u8>(), length(value)) };
+}
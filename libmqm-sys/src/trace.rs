@@ -0,0 +1,1906 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+/*!
+ * Tracing of MQI and MQAI function calls using [`tracing`](https://docs.rs/tracing)
+ *
+ * Each function call made through [`TracingMq`] is executed within a `DEBUG` span named after the verb.
+ * The span carries the decoded inputs of the call such as handles, object names, option masks and buffer lengths.
+ * An event with the resulting completion code and reason is emitted when the call returns.
+ *
+ * Constant names are resolved using the [`names`](crate::names) tables. Values without a name are traced as
+ * numbers.
+ *
+ * Example
+ * -------
+ *
+ *  Trace the MQI calls made to a mock queue manager
+ *
+ * ```
+ * # #[cfg(feature = "mock")] {
+ * use std::ptr::addr_of_mut;
+ * use libmqm_sys::{lib, mock::MockMq, trace::TracingMq, Mqi as _};
+ *
+ * let mq = TracingMq::new(MockMq::new("QM1"));
+ *
+ * let mut hconn = lib::MQHC_DEF_HCONN;
+ * let mut comp_code = lib::MQCC_UNKNOWN;
+ * let mut reason = lib::MQRC_NONE;
+ * let mut qmgr: [lib::MQCHAR; 48] = [32; 48]; // All spaces
+ * unsafe {
+ *    mq.MQCONN(
+ *      addr_of_mut!(qmgr).cast(),
+ *      addr_of_mut!(hconn),
+ *      addr_of_mut!(comp_code),
+ *      addr_of_mut!(reason),
+ *    );
+ * }
+ * assert_eq!(comp_code, lib::MQCC_OK);
+ * # }
+ * ```
+ */
+
+use std::{fmt, ptr, slice};
+
+use tracing::{debug_span, field};
+
+use crate::{function, lib as mqsys, mqchar, names};
+
+/// Groups of MQ constants that are decoded when tracing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Group {
+    CompCode,
+    Reason,
+    OpenOptions,
+    CloseOptions,
+    GetOptions,
+    PutOptions,
+    ConnectOptions,
+    SubOptions,
+}
+
+impl Group {
+    /// Prefix of the constants in the group
+    const fn prefix(self) -> &'static str {
+        match self {
+            Self::CompCode => "MQCC",
+            Self::Reason => "MQRC",
+            Self::OpenOptions => "MQOO",
+            Self::CloseOptions => "MQCO",
+            Self::GetOptions => "MQGMO",
+            Self::PutOptions => "MQPMO",
+            Self::ConnectOptions => "MQCNO",
+            Self::SubOptions => "MQSO",
+        }
+    }
+
+    /// Name of a single constant value in the group
+    fn name(self, value: mqsys::MQLONG) -> Option<&'static str> {
+        names::name_of(self.prefix(), value)
+    }
+}
+
+/// Display a constant by name, falling back to the numeric value
+struct ConstName(Group, mqsys::MQLONG);
+
+impl fmt::Display for ConstName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(group, value) = *self;
+        match group.name(value) {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Display an option mask as the names of its options, followed by any unknown bits
+struct OptionNames(Group, mqsys::MQLONG);
+
+impl fmt::Display for OptionNames {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(group, value) = *self;
+        if value == 0 {
+            return fmt::Display::fmt(&ConstName(group, value), f);
+        }
+        let mut separator = "";
+        for name in names::decode_options(group.prefix(), value) {
+            write!(f, "{separator}{name}")?;
+            separator = "|";
+        }
+        match names::unknown_options(group.prefix(), value) {
+            0 => Ok(()),
+            unknown => write!(f, "{separator}{unknown:#x}"),
+        }
+    }
+}
+
+/// Read a fixed length character field from a structure without reading beyond the field
+unsafe fn field_str<const N: usize>(field: *const [mqsys::MQCHAR; N]) -> String {
+    if field.is_null() {
+        String::new()
+    } else {
+        mqchar::to_string(&unsafe { field.read() })
+    }
+}
+
+unsafe fn object_name(pObjDesc: mqsys::PMQVOID) -> String {
+    let od = pObjDesc.cast::<mqsys::MQOD>();
+    if od.is_null() {
+        String::new()
+    } else {
+        unsafe { field_str(ptr::addr_of!((*od).ObjectName)) }
+    }
+}
+
+unsafe fn qmgr_name(pQMgrName: mqsys::PMQCHAR) -> String {
+    if pQMgrName.is_null() {
+        String::new()
+    } else {
+        mqchar::to_string(unsafe { slice::from_raw_parts(pQMgrName, mqsys::MQ_Q_MGR_NAME_LENGTH) })
+    }
+}
+
+/// Read the `Options` field that is common to the start of the option structures
+unsafe fn struct_options<T>(pOpts: *const T, options: unsafe fn(*const T) -> *const mqsys::MQLONG) -> mqsys::MQLONG {
+    if pOpts.is_null() {
+        0
+    } else {
+        unsafe { options(pOpts).read() }
+    }
+}
+
+unsafe fn gmo_options(pGetMsgOpts: mqsys::PMQVOID) -> mqsys::MQLONG {
+    unsafe { struct_options(pGetMsgOpts.cast::<mqsys::MQGMO>(), |gmo| ptr::addr_of!((*gmo).Options)) }
+}
+
+unsafe fn pmo_options(pPutMsgOpts: mqsys::PMQVOID) -> mqsys::MQLONG {
+    unsafe { struct_options(pPutMsgOpts.cast::<mqsys::MQPMO>(), |pmo| ptr::addr_of!((*pmo).Options)) }
+}
+
+/// Emit the completion code and reason of a call as an event
+unsafe fn completed(pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+    let (comp_code, reason) = unsafe { (*pCompCode, *pReason) };
+    let comp_code_name = ConstName(Group::CompCode, comp_code);
+    let reason_name = ConstName(Group::Reason, reason);
+    match comp_code {
+        mqsys::MQCC_OK => tracing::debug!(comp_code = %comp_code_name, reason = %reason_name, "completed"),
+        mqsys::MQCC_WARNING => tracing::info!(comp_code = %comp_code_name, reason = %reason_name, "completed"),
+        _ => tracing::warn!(comp_code = %comp_code_name, reason = %reason_name, "completed"),
+    }
+}
+
+unsafe fn record_output(span: &tracing::Span, name: &str, pLength: mqsys::PMQLONG) {
+    if !pLength.is_null() {
+        span.record(name, unsafe { *pLength });
+    }
+}
+
+/// Decorator that traces the MQI and MQAI function calls made to an inner implementation
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingMq<M> {
+    inner: M,
+}
+
+impl<M> TracingMq<M> {
+    /// Trace the function calls made to `inner`
+    pub const fn new(inner: M) -> Self {
+        Self { inner }
+    }
+
+    /// The inner implementation
+    pub const fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Unwrap the inner implementation
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+}
+
+impl<M: function::Mqi> function::Mqi for TracingMq<M> {
+    unsafe fn MQCONNX(
+        &self,
+        pQMgrName: mqsys::PMQCHAR,
+        pConnectOpts: mqsys::PMQCNO,
+        pHconn: mqsys::PMQHCONN,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let options = unsafe { struct_options(pConnectOpts, |cno| ptr::addr_of!((*cno).Options)) };
+        let span = debug_span!(
+            "MQCONNX",
+            qmgr = %unsafe { qmgr_name(pQMgrName) },
+            options = %OptionNames(Group::ConnectOptions, options),
+            hconn = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.MQCONNX(pQMgrName, pConnectOpts, pHconn, pCompCode, pReason);
+            record_output(&span, "hconn", pHconn);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQCONN(
+        &self,
+        pQMgrName: mqsys::PMQCHAR,
+        pHconn: mqsys::PMQHCONN,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!("MQCONN", qmgr = %unsafe { qmgr_name(pQMgrName) }, hconn = field::Empty).entered();
+        unsafe {
+            self.inner.MQCONN(pQMgrName, pHconn, pCompCode, pReason);
+            record_output(&span, "hconn", pHconn);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQDISC(&self, pHconn: mqsys::PMQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        let _span = debug_span!("MQDISC", hconn = unsafe { pHconn.as_ref() }.copied()).entered();
+        unsafe {
+            self.inner.MQDISC(pHconn, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQOPEN(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pObjDesc: mqsys::PMQVOID,
+        Options: mqsys::MQLONG,
+        pHobj: mqsys::PMQHOBJ,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "MQOPEN",
+            hconn = Hconn,
+            object = %unsafe { object_name(pObjDesc) },
+            options = %OptionNames(Group::OpenOptions, Options),
+            hobj = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.MQOPEN(Hconn, pObjDesc, Options, pHobj, pCompCode, pReason);
+            record_output(&span, "hobj", pHobj);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQPUT1(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pObjDesc: mqsys::PMQVOID,
+        pMsgDesc: mqsys::PMQVOID,
+        pPutMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "MQPUT1",
+            hconn = Hconn,
+            object = %unsafe { object_name(pObjDesc) },
+            options = %OptionNames(Group::PutOptions, unsafe { pmo_options(pPutMsgOpts) }),
+            buffer_length = BufferLength
+        )
+        .entered();
+        unsafe {
+            self.inner.MQPUT1(
+                Hconn,
+                pObjDesc,
+                pMsgDesc,
+                pPutMsgOpts,
+                BufferLength,
+                pBuffer,
+                pCompCode,
+                pReason,
+            );
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQCLOSE(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pHobj: mqsys::PMQHOBJ,
+        Options: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "MQCLOSE",
+            hconn = Hconn,
+            hobj = unsafe { pHobj.as_ref() }.copied(),
+            options = %OptionNames(Group::CloseOptions, Options)
+        )
+        .entered();
+        unsafe {
+            self.inner.MQCLOSE(Hconn, pHobj, Options, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQCMIT(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        let _span = debug_span!("MQCMIT", hconn = Hconn).entered();
+        unsafe {
+            self.inner.MQCMIT(Hconn, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQGET(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pGetMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "MQGET",
+            hconn = Hconn,
+            hobj = Hobj,
+            options = %OptionNames(Group::GetOptions, unsafe { gmo_options(pGetMsgOpts) }),
+            buffer_length = BufferLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.MQGET(
+                Hconn,
+                Hobj,
+                pMsgDesc,
+                pGetMsgOpts,
+                BufferLength,
+                pBuffer,
+                pDataLength,
+                pCompCode,
+                pReason,
+            );
+            record_output(&span, "data_length", pDataLength);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQPUT(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pPutMsgOpts: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "MQPUT",
+            hconn = Hconn,
+            hobj = Hobj,
+            options = %OptionNames(Group::PutOptions, unsafe { pmo_options(pPutMsgOpts) }),
+            buffer_length = BufferLength
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .MQPUT(Hconn, Hobj, pMsgDesc, pPutMsgOpts, BufferLength, pBuffer, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQINQ(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        SelectorCount: mqsys::MQLONG,
+        pSelectors: mqsys::PMQLONG,
+        IntAttrCount: mqsys::MQLONG,
+        pIntAttrs: mqsys::PMQLONG,
+        CharAttrLength: mqsys::MQLONG,
+        pCharAttrs: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "MQINQ",
+            hconn = Hconn,
+            hobj = Hobj,
+            selector_count = SelectorCount,
+            int_attr_count = IntAttrCount,
+            char_attr_length = CharAttrLength
+        )
+        .entered();
+        unsafe {
+            self.inner.MQINQ(
+                Hconn,
+                Hobj,
+                SelectorCount,
+                pSelectors,
+                IntAttrCount,
+                pIntAttrs,
+                CharAttrLength,
+                pCharAttrs,
+                pCompCode,
+                pReason,
+            );
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQSUB(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pSubDesc: mqsys::PMQVOID,
+        pHobj: mqsys::PMQHOBJ,
+        pHsub: mqsys::PMQHOBJ,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let options = unsafe { struct_options(pSubDesc.cast::<mqsys::MQSD>(), |sd| ptr::addr_of!((*sd).Options)) };
+        let span = debug_span!(
+            "MQSUB",
+            hconn = Hconn,
+            options = %OptionNames(Group::SubOptions, options),
+            hsub = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.MQSUB(Hconn, pSubDesc, pHobj, pHsub, pCompCode, pReason);
+            record_output(&span, "hsub", pHsub);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQSUBRQ(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hsub: mqsys::MQHOBJ,
+        Action: mqsys::MQLONG,
+        pSubRqOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQSUBRQ", hconn = Hconn, hsub = Hsub, action = Action).entered();
+        unsafe {
+            self.inner.MQSUBRQ(Hconn, Hsub, Action, pSubRqOpts, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQBEGIN(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pBeginOptions: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQBEGIN", hconn = Hconn).entered();
+        unsafe {
+            self.inner.MQBEGIN(Hconn, pBeginOptions, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQBACK(&self, Hconn: mqsys::MQHCONN, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        let _span = debug_span!("MQBACK", hconn = Hconn).entered();
+        unsafe {
+            self.inner.MQBACK(Hconn, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQCRTMH(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pCrtMsgHOpts: mqsys::PMQVOID,
+        pHmsg: mqsys::PMQHMSG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!("MQCRTMH", hconn = Hconn, hmsg = field::Empty).entered();
+        unsafe {
+            self.inner.MQCRTMH(Hconn, pCrtMsgHOpts, pHmsg, pCompCode, pReason);
+            if let Some(hmsg) = pHmsg.as_ref() {
+                span.record("hmsg", hmsg);
+            }
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQDLTMH(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        pHmsg: mqsys::PMQHMSG,
+        pDltMsgHOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQDLTMH", hconn = Hconn, hmsg = unsafe { pHmsg.as_ref() }.copied()).entered();
+        unsafe {
+            self.inner.MQDLTMH(Hconn, pHmsg, pDltMsgHOpts, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQMHBUF(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pMsgHBufOpts: mqsys::PMQVOID,
+        pName: mqsys::PMQVOID,
+        pMsgDesc: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "MQMHBUF",
+            hconn = Hconn,
+            hmsg = Hmsg,
+            buffer_length = BufferLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.MQMHBUF(
+                Hconn,
+                Hmsg,
+                pMsgHBufOpts,
+                pName,
+                pMsgDesc,
+                BufferLength,
+                pBuffer,
+                pDataLength,
+                pCompCode,
+                pReason,
+            );
+            record_output(&span, "data_length", pDataLength);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQBUFMH(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pBufMsgHOpts: mqsys::PMQVOID,
+        pMsgDesc: mqsys::PMQVOID,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "MQBUFMH",
+            hconn = Hconn,
+            hmsg = Hmsg,
+            buffer_length = BufferLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.MQBUFMH(
+                Hconn,
+                Hmsg,
+                pBufMsgHOpts,
+                pMsgDesc,
+                BufferLength,
+                pBuffer,
+                pDataLength,
+                pCompCode,
+                pReason,
+            );
+            record_output(&span, "data_length", pDataLength);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQCB(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Operation: mqsys::MQLONG,
+        pCallbackDesc: mqsys::PMQVOID,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pGetMsgOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQCB", hconn = Hconn, operation = Operation, hobj = Hobj).entered();
+        unsafe {
+            self.inner.MQCB(
+                Hconn,
+                Operation,
+                pCallbackDesc,
+                Hobj,
+                pMsgDesc,
+                pGetMsgOpts,
+                pCompCode,
+                pReason,
+            );
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQCTL(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Operation: mqsys::MQLONG,
+        pControlOpts: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQCTL", hconn = Hconn, operation = Operation).entered();
+        unsafe {
+            self.inner.MQCTL(Hconn, Operation, pControlOpts, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQSET(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        SelectorCount: mqsys::MQLONG,
+        pSelectors: mqsys::PMQLONG,
+        IntAttrCount: mqsys::MQLONG,
+        pIntAttrs: mqsys::PMQLONG,
+        CharAttrLength: mqsys::MQLONG,
+        pCharAttrs: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "MQSET",
+            hconn = Hconn,
+            hobj = Hobj,
+            selector_count = SelectorCount,
+            int_attr_count = IntAttrCount,
+            char_attr_length = CharAttrLength
+        )
+        .entered();
+        unsafe {
+            self.inner.MQSET(
+                Hconn,
+                Hobj,
+                SelectorCount,
+                pSelectors,
+                IntAttrCount,
+                pIntAttrs,
+                CharAttrLength,
+                pCharAttrs,
+                pCompCode,
+                pReason,
+            );
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQSETMP(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pSetPropOpts: mqsys::PMQVOID,
+        pName: mqsys::PMQVOID,
+        pPropDesc: mqsys::PMQVOID,
+        Type: mqsys::MQLONG,
+        ValueLength: mqsys::MQLONG,
+        pValue: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "MQSETMP",
+            hconn = Hconn,
+            hmsg = Hmsg,
+            r#type = Type,
+            value_length = ValueLength
+        )
+        .entered();
+        unsafe {
+            self.inner.MQSETMP(
+                Hconn,
+                Hmsg,
+                pSetPropOpts,
+                pName,
+                pPropDesc,
+                Type,
+                ValueLength,
+                pValue,
+                pCompCode,
+                pReason,
+            );
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQSTAT(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Type: mqsys::MQLONG,
+        pStatus: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQSTAT", hconn = Hconn, r#type = Type).entered();
+        unsafe {
+            self.inner.MQSTAT(Hconn, Type, pStatus, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQINQMP(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pInqPropOpts: mqsys::PMQVOID,
+        pName: mqsys::PMQVOID,
+        pPropDesc: mqsys::PMQVOID,
+        pType: mqsys::PMQLONG,
+        ValueLength: mqsys::MQLONG,
+        pValue: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "MQINQMP",
+            hconn = Hconn,
+            hmsg = Hmsg,
+            value_length = ValueLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.MQINQMP(
+                Hconn,
+                Hmsg,
+                pInqPropOpts,
+                pName,
+                pPropDesc,
+                pType,
+                ValueLength,
+                pValue,
+                pDataLength,
+                pCompCode,
+                pReason,
+            );
+            record_output(&span, "data_length", pDataLength);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQDLTMP(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hmsg: mqsys::MQHMSG,
+        pDltPropOpts: mqsys::PMQVOID,
+        pName: mqsys::PMQVOID,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQDLTMP", hconn = Hconn, hmsg = Hmsg).entered();
+        unsafe {
+            self.inner.MQDLTMP(Hconn, Hmsg, pDltPropOpts, pName, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQXCNVC(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Options: mqsys::MQLONG,
+        SourceCCSID: mqsys::MQLONG,
+        SourceLength: mqsys::MQLONG,
+        pSourceBuffer: mqsys::PMQCHAR,
+        TargetCCSID: mqsys::MQLONG,
+        TargetLength: mqsys::MQLONG,
+        pTargetBuffer: mqsys::PMQCHAR,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "MQXCNVC",
+            hconn = Hconn,
+            options = Options,
+            source_ccsid = SourceCCSID,
+            source_length = SourceLength,
+            target_ccsid = TargetCCSID,
+            target_length = TargetLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.MQXCNVC(
+                Hconn,
+                Options,
+                SourceCCSID,
+                SourceLength,
+                pSourceBuffer,
+                TargetCCSID,
+                TargetLength,
+                pTargetBuffer,
+                pDataLength,
+                pCompCode,
+                pReason,
+            );
+            record_output(&span, "data_length", pDataLength);
+            completed(pCompCode, pReason);
+        }
+    }
+}
+
+#[cfg(feature = "exits")]
+impl<M: function::MqiExit> function::MqiExit for TracingMq<M> {
+    unsafe fn MQXEP(
+        &self,
+        Hconfig: mqsys::MQHCONFIG,
+        ExitReason: mqsys::MQLONG,
+        Function: mqsys::MQLONG,
+        pEntryPoint: mqsys::PMQFUNC,
+        pExitOpts: mqsys::PMQXEPO,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQXEP", exit_reason = ExitReason, function = Function).entered();
+        unsafe {
+            self.inner
+                .MQXEP(Hconfig, ExitReason, Function, pEntryPoint, pExitOpts, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQZEP(
+        &self,
+        Hconfig: mqsys::MQHCONFIG,
+        Function: mqsys::MQLONG,
+        pEntryPoint: mqsys::PMQFUNC,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQZEP", function = Function).entered();
+        unsafe {
+            self.inner.MQZEP(Hconfig, Function, pEntryPoint, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQXCLWLN(
+        &self,
+        pExitParms: mqsys::PMQWXP,
+        CurrentRecord: mqsys::MQPTR,
+        NextOffset: mqsys::MQLONG,
+        pNextRecord: mqsys::PMQPTR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("MQXCLWLN", next_offset = NextOffset).entered();
+        unsafe {
+            self.inner
+                .MQXCLWLN(pExitParms, CurrentRecord, NextOffset, pNextRecord, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn MQXDX(
+        &self,
+        pDataConvExitParms: mqsys::PMQDXP,
+        pMsgDesc: mqsys::PMQMD,
+        InBufferLength: mqsys::MQLONG,
+        pInBuffer: mqsys::PMQVOID,
+        OutBufferLength: mqsys::MQLONG,
+        pOutBuffer: mqsys::PMQVOID,
+    ) {
+        let span = debug_span!(
+            "MQXDX",
+            in_buffer_length = InBufferLength,
+            out_buffer_length = OutBufferLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.MQXDX(
+                pDataConvExitParms,
+                pMsgDesc,
+                InBufferLength,
+                pInBuffer,
+                OutBufferLength,
+                pOutBuffer,
+            );
+            record_output(&span, "data_length", ptr::addr_of_mut!((*pDataConvExitParms).DataLength));
+            completed(
+                ptr::addr_of_mut!((*pDataConvExitParms).CompCode),
+                ptr::addr_of_mut!((*pDataConvExitParms).Reason),
+            );
+        }
+    }
+}
+
+#[cfg(feature = "mqai")]
+impl<M: function::Mqai> function::Mqai for TracingMq<M> {
+    unsafe fn mqCreateBag(
+        &self,
+        Options: mqsys::MQLONG,
+        pBag: mqsys::PMQHBAG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!("mqCreateBag", options = Options, bag = field::Empty).entered();
+        unsafe {
+            self.inner.mqCreateBag(Options, pBag, pCompCode, pReason);
+            record_output(&span, "bag", pBag);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqClearBag(&self, Bag: mqsys::MQHBAG, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        let _span = debug_span!("mqClearBag", bag = Bag).entered();
+        unsafe {
+            self.inner.mqClearBag(Bag, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqDeleteBag(&self, pBag: mqsys::PMQHBAG, pCompCode: mqsys::PMQLONG, pReason: mqsys::PMQLONG) {
+        let _span = debug_span!("mqDeleteBag", bag = unsafe { pBag.as_ref() }.copied()).entered();
+        unsafe {
+            self.inner.mqDeleteBag(pBag, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqGetBag(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pGetMsgOpts: mqsys::PMQVOID,
+        Bag: mqsys::MQHBAG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqGetBag",
+            hconn = Hconn,
+            hobj = Hobj,
+            options = %OptionNames(Group::GetOptions, unsafe { gmo_options(pGetMsgOpts) }),
+            bag = Bag
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqGetBag(Hconn, Hobj, pMsgDesc, pGetMsgOpts, Bag, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqPutBag(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Hobj: mqsys::MQHOBJ,
+        pMsgDesc: mqsys::PMQVOID,
+        pPutMsgOpts: mqsys::PMQVOID,
+        Bag: mqsys::MQHBAG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqPutBag",
+            hconn = Hconn,
+            hobj = Hobj,
+            options = %OptionNames(Group::PutOptions, unsafe { pmo_options(pPutMsgOpts) }),
+            bag = Bag
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqPutBag(Hconn, Hobj, pMsgDesc, pPutMsgOpts, Bag, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqTruncateBag(
+        &self,
+        Bag: mqsys::MQHBAG,
+        ItemCount: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqTruncateBag", bag = Bag, item_count = ItemCount).entered();
+        unsafe {
+            self.inner.mqTruncateBag(Bag, ItemCount, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqAddInquiry(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqAddInquiry", bag = Bag, selector = Selector).entered();
+        unsafe {
+            self.inner.mqAddInquiry(Bag, Selector, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqDeleteItem(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqDeleteItem", bag = Bag, selector = Selector, item_index = ItemIndex).entered();
+        unsafe {
+            self.inner.mqDeleteItem(Bag, Selector, ItemIndex, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqAddInteger(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemValue: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqAddInteger", bag = Bag, selector = Selector, value = ItemValue).entered();
+        unsafe {
+            self.inner.mqAddInteger(Bag, Selector, ItemValue, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqAddIntegerFilter(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemValue: mqsys::MQLONG,
+        Operator: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqAddIntegerFilter",
+            bag = Bag,
+            selector = Selector,
+            value = ItemValue,
+            operator = Operator
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqAddIntegerFilter(Bag, Selector, ItemValue, Operator, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqAddInteger64(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemValue: mqsys::MQINT64,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqAddInteger64", bag = Bag, selector = Selector, value = ItemValue).entered();
+        unsafe {
+            self.inner.mqAddInteger64(Bag, Selector, ItemValue, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqAddString(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqAddString", bag = Bag, selector = Selector, buffer_length = BufferLength).entered();
+        unsafe {
+            self.inner
+                .mqAddString(Bag, Selector, BufferLength, pBuffer, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqAddStringFilter(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQCHAR,
+        Operator: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqAddStringFilter",
+            bag = Bag,
+            selector = Selector,
+            buffer_length = BufferLength,
+            operator = Operator
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqAddStringFilter(Bag, Selector, BufferLength, pBuffer, Operator, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqAddByteString(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQBYTE,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqAddByteString",
+            bag = Bag,
+            selector = Selector,
+            buffer_length = BufferLength
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqAddByteString(Bag, Selector, BufferLength, pBuffer, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqAddByteStringFilter(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQBYTE,
+        Operator: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqAddByteStringFilter",
+            bag = Bag,
+            selector = Selector,
+            buffer_length = BufferLength,
+            operator = Operator
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqAddByteStringFilter(Bag, Selector, BufferLength, pBuffer, Operator, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqSetInteger(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        ItemValue: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqSetInteger",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            value = ItemValue
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqSetInteger(Bag, Selector, ItemIndex, ItemValue, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqSetIntegerFilter(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        ItemValue: mqsys::MQLONG,
+        Operator: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqSetIntegerFilter",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            value = ItemValue,
+            operator = Operator
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqSetIntegerFilter(Bag, Selector, ItemIndex, ItemValue, Operator, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqSetInteger64(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        ItemValue: mqsys::MQINT64,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqSetInteger64",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            value = ItemValue
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqSetInteger64(Bag, Selector, ItemIndex, ItemValue, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqAddBag(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemValue: mqsys::MQHBAG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqAddBag", bag = Bag, selector = Selector, nested_bag = ItemValue).entered();
+        unsafe {
+            self.inner.mqAddBag(Bag, Selector, ItemValue, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqSetString(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqSetString",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            buffer_length = BufferLength
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqSetString(Bag, Selector, ItemIndex, BufferLength, pBuffer, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqSetStringFilter(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQCHAR,
+        Operator: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqSetStringFilter",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            buffer_length = BufferLength,
+            operator = Operator
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqSetStringFilter(Bag, Selector, ItemIndex, BufferLength, pBuffer, Operator, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqSetByteString(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQBYTE,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqSetByteString",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            buffer_length = BufferLength
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqSetByteString(Bag, Selector, ItemIndex, BufferLength, pBuffer, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqSetByteStringFilter(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQBYTE,
+        Operator: mqsys::MQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqSetByteStringFilter",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            buffer_length = BufferLength,
+            operator = Operator
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqSetByteStringFilter(Bag, Selector, ItemIndex, BufferLength, pBuffer, Operator, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqInquireInteger(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        pItemValue: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "mqInquireInteger",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            value = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqInquireInteger(Bag, Selector, ItemIndex, pItemValue, pCompCode, pReason);
+            record_output(&span, "value", pItemValue);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqInquireIntegerFilter(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        pItemValue: mqsys::PMQLONG,
+        pOperator: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqInquireIntegerFilter",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqInquireIntegerFilter(Bag, Selector, ItemIndex, pItemValue, pOperator, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqInquireInteger64(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        pItemValue: mqsys::PMQINT64,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "mqInquireInteger64",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            value = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqInquireInteger64(Bag, Selector, ItemIndex, pItemValue, pCompCode, pReason);
+            if let Some(value) = pItemValue.as_ref() {
+                span.record("value", value);
+            }
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqInquireByteString(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQBYTE,
+        pByteStringLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "mqInquireByteString",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            buffer_length = BufferLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.mqInquireByteString(
+                Bag,
+                Selector,
+                ItemIndex,
+                BufferLength,
+                pBuffer,
+                pByteStringLength,
+                pCompCode,
+                pReason,
+            );
+            record_output(&span, "data_length", pByteStringLength);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqInquireString(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQCHAR,
+        pStringLength: mqsys::PMQLONG,
+        pCodedCharSetId: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "mqInquireString",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            buffer_length = BufferLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.mqInquireString(
+                Bag,
+                Selector,
+                ItemIndex,
+                BufferLength,
+                pBuffer,
+                pStringLength,
+                pCodedCharSetId,
+                pCompCode,
+                pReason,
+            );
+            record_output(&span, "data_length", pStringLength);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqInquireStringFilter(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQCHAR,
+        pStringLength: mqsys::PMQLONG,
+        pCodedCharSetId: mqsys::PMQLONG,
+        pOperator: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "mqInquireStringFilter",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            buffer_length = BufferLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.mqInquireStringFilter(
+                Bag,
+                Selector,
+                ItemIndex,
+                BufferLength,
+                pBuffer,
+                pStringLength,
+                pCodedCharSetId,
+                pOperator,
+                pCompCode,
+                pReason,
+            );
+            record_output(&span, "data_length", pStringLength);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqInquireByteStringFilter(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQBYTE,
+        pByteStringLength: mqsys::PMQLONG,
+        pOperator: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "mqInquireByteStringFilter",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            buffer_length = BufferLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner.mqInquireByteStringFilter(
+                Bag,
+                Selector,
+                ItemIndex,
+                BufferLength,
+                pBuffer,
+                pByteStringLength,
+                pOperator,
+                pCompCode,
+                pReason,
+            );
+            record_output(&span, "data_length", pByteStringLength);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqInquireBag(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        pItemValue: mqsys::PMQHBAG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "mqInquireBag",
+            bag = Bag,
+            selector = Selector,
+            item_index = ItemIndex,
+            nested_bag = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqInquireBag(Bag, Selector, ItemIndex, pItemValue, pCompCode, pReason);
+            record_output(&span, "nested_bag", pItemValue);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqCountItems(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        pItemCount: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!("mqCountItems", bag = Bag, selector = Selector, item_count = field::Empty).entered();
+        unsafe {
+            self.inner.mqCountItems(Bag, Selector, pItemCount, pCompCode, pReason);
+            record_output(&span, "item_count", pItemCount);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqExecute(
+        &self,
+        Hconn: mqsys::MQHCONN,
+        Command: mqsys::MQLONG,
+        OptionsBag: mqsys::MQHBAG,
+        AdminBag: mqsys::MQHBAG,
+        ResponseBag: mqsys::MQHBAG,
+        AdminQ: mqsys::MQHOBJ,
+        ResponseQ: mqsys::MQHOBJ,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!(
+            "mqExecute",
+            hconn = Hconn,
+            command = Command,
+            admin_bag = AdminBag,
+            response_bag = ResponseBag
+        )
+        .entered();
+        unsafe {
+            self.inner.mqExecute(
+                Hconn,
+                Command,
+                OptionsBag,
+                AdminBag,
+                ResponseBag,
+                AdminQ,
+                ResponseQ,
+                pCompCode,
+                pReason,
+            );
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqBagToBuffer(
+        &self,
+        OptionsBag: mqsys::MQHBAG,
+        DataBag: mqsys::MQHBAG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        pDataLength: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let span = debug_span!(
+            "mqBagToBuffer",
+            bag = DataBag,
+            buffer_length = BufferLength,
+            data_length = field::Empty
+        )
+        .entered();
+        unsafe {
+            self.inner
+                .mqBagToBuffer(OptionsBag, DataBag, BufferLength, pBuffer, pDataLength, pCompCode, pReason);
+            record_output(&span, "data_length", pDataLength);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqBufferToBag(
+        &self,
+        OptionsBag: mqsys::MQHBAG,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQVOID,
+        DataBag: mqsys::MQHBAG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqBufferToBag", bag = DataBag, buffer_length = BufferLength).entered();
+        unsafe {
+            self.inner
+                .mqBufferToBag(OptionsBag, BufferLength, pBuffer, DataBag, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqInquireItemInfo(
+        &self,
+        Bag: mqsys::MQHBAG,
+        Selector: mqsys::MQLONG,
+        ItemIndex: mqsys::MQLONG,
+        pOutSelector: mqsys::PMQLONG,
+        pItemType: mqsys::PMQLONG,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqInquireItemInfo", bag = Bag, selector = Selector, item_index = ItemIndex).entered();
+        unsafe {
+            self.inner
+                .mqInquireItemInfo(Bag, Selector, ItemIndex, pOutSelector, pItemType, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqPad(
+        &self,
+        pString: mqsys::PMQCHAR,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqPad", buffer_length = BufferLength).entered();
+        unsafe {
+            self.inner.mqPad(pString, BufferLength, pBuffer, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+
+    unsafe fn mqTrim(
+        &self,
+        BufferLength: mqsys::MQLONG,
+        pBuffer: mqsys::PMQCHAR,
+        pString: mqsys::PMQCHAR,
+        pCompCode: mqsys::PMQLONG,
+        pReason: mqsys::PMQLONG,
+    ) {
+        let _span = debug_span!("mqTrim", buffer_length = BufferLength).entered();
+        unsafe {
+            self.inner.mqTrim(BufferLength, pBuffer, pString, pCompCode, pReason);
+            completed(pCompCode, pReason);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use std::{
+        fmt::Write as _,
+        mem,
+        ptr::{addr_of_mut, null_mut},
+        sync::{Arc, Mutex, MutexGuard, PoisonError},
+    };
+
+    use tracing::span;
+
+    use crate::{
+        fixture::{connect, open},
+        lib,
+        mock::MockMq,
+        Mqi as _,
+    };
+
+    use super::*;
+
+    /// Subscriber that captures each span and event as its name or level followed by its fields
+    struct Capture(Arc<Mutex<Vec<String>>>);
+
+    impl Capture {
+        fn lines(&self) -> MutexGuard<'_, Vec<String>> {
+            self.0.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+
+    struct Fields(String);
+
+    impl field::Visit for Fields {
+        fn record_debug(&mut self, field: &field::Field, value: &dyn fmt::Debug) {
+            write!(self.0, " {}={value:?}", field.name()).expect("write to string");
+        }
+    }
+
+    impl tracing::Subscriber for Capture {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            let mut fields = Fields(span.metadata().name().to_string());
+            span.record(&mut fields);
+            let mut lines = self.lines();
+            lines.push(fields.0);
+            span::Id::from_u64(lines.len().try_into().expect("span count to fit u64"))
+        }
+
+        fn record(&self, span: &span::Id, values: &span::Record<'_>) {
+            let index = usize::try_from(span.into_u64()).expect("span id to fit usize") - 1;
+            let mut fields = Fields(mem::take(&mut self.lines()[index]));
+            values.record(&mut fields);
+            self.lines()[index] = fields.0;
+        }
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let mut fields = Fields(event.metadata().level().to_string());
+            event.record(&mut fields);
+            self.lines().push(fields.0);
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn forwards_to_inner() {
+        let mq = TracingMq::new(MockMq::new("QM1"));
+        let mut hconn = lib::MQHC_DEF_HCONN;
+        let (mut cc, mut rc) = (lib::MQCC_UNKNOWN, lib::MQRC_NONE);
+        unsafe { mq.MQCONN(null_mut(), addr_of_mut!(hconn), addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!((cc, rc), (lib::MQCC_OK, lib::MQRC_NONE));
+
+        let mut unknown = hconn + 1;
+        unsafe { mq.MQDISC(addr_of_mut!(unknown), addr_of_mut!(cc), addr_of_mut!(rc)) };
+        assert_eq!((cc, rc), (lib::MQCC_FAILED, lib::MQRC_HCONN_ERROR));
+    }
+
+    #[test]
+    fn captures_decoded_calls() {
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let mq = TracingMq::new(MockMq::new("QM1").with_queue("Q1"));
+        tracing::subscriber::with_default(Capture(Arc::clone(&lines)), || {
+            let hconn = connect(&mq);
+            for name in ["Q1", "MISSING"] {
+                open(&mq, hconn, name, lib::MQOO_OUTPUT | lib::MQOO_FAIL_IF_QUIESCING);
+            }
+        });
+
+        assert_eq!(
+            *lines.lock().unwrap_or_else(PoisonError::into_inner),
+            [
+                "MQCONN qmgr= hconn=1",
+                "DEBUG message=completed comp_code=MQCC_OK reason=MQRC_NONE",
+                "MQOPEN hconn=1 object=Q1 options=MQOO_OUTPUT|MQOO_FAIL_IF_QUIESCING hobj=1",
+                "DEBUG message=completed comp_code=MQCC_OK reason=MQRC_NONE",
+                "MQOPEN hconn=1 object=MISSING options=MQOO_OUTPUT|MQOO_FAIL_IF_QUIESCING hobj=0",
+                "WARN message=completed comp_code=MQCC_FAILED reason=MQRC_UNKNOWN_OBJECT_NAME",
+            ]
+        );
+    }
+
+    #[test]
+    fn options_display() {
+        let options = OptionNames(Group::OpenOptions, lib::MQOO_OUTPUT | lib::MQOO_INQUIRE).to_string();
+        let reason = ConstName(Group::Reason, lib::MQRC_NONE).to_string();
+        assert_eq!(options, "MQOO_OUTPUT|MQOO_INQUIRE");
+        let unknown = OptionNames(Group::OpenOptions, lib::MQOO_OUTPUT | 1 << 30).to_string();
+        assert_eq!(unknown, "MQOO_OUTPUT|0x40000000");
+        assert_eq!(reason, "MQRC_NONE");
+    }
+}